@@ -0,0 +1,56 @@
+// benches/shanten.rs
+//
+// 向听数计算的性能基准
+// 使用固定种子洗出的随机手牌，统计每次计算的平均耗时。
+// 运行方式: cargo bench --bench shanten
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use majiang_core::hand::{shanten, standard_shanten, Hand};
+use majiang_core::wall::{builder, WallConfig};
+
+/// 参与测试的手牌数
+const HANDS: u64 = 2_000;
+
+/// 每手牌重复计算的次数
+const ROUNDS: usize = 100;
+
+/// 生成固定种子的随机手牌
+fn sample_hands(size: usize) -> Vec<Hand> {
+    (0..HANDS)
+        .filter_map(|seed| {
+            let tiles = builder::create_shuffled_tiles(WallConfig::Riichi, Some(seed)).ok()?;
+            Hand::from_tiles(&tiles[..size]).ok()
+        })
+        .collect()
+}
+
+/// 运行一组基准并打印平均耗时
+fn run(name: &str, hands: &[Hand], f: impl Fn(&Hand) -> i8) {
+    let start = Instant::now();
+    let mut total = 0i64;
+    for _ in 0..ROUNDS {
+        for hand in hands {
+            total += i64::from(f(black_box(hand)));
+        }
+    }
+    let elapsed = start.elapsed();
+    let calls = (hands.len() * ROUNDS) as u128;
+    println!(
+        "{:<24} {:>8} 次  平均 {:>6} ns/次  (校验和 {})",
+        name,
+        calls,
+        elapsed.as_nanos() / calls.max(1),
+        total
+    );
+}
+
+fn main() {
+    let hands13 = sample_hands(13);
+    let hands14 = sample_hands(14);
+
+    run("shanten/13张", &hands13, shanten);
+    run("shanten/14张", &hands14, shanten);
+    run("standard_shanten/14张", &hands14, |hand| standard_shanten(&hand.tile_counts(), 0));
+}
@@ -0,0 +1,10 @@
+// src/action/mod.rs
+//
+// 玩家动作模块的入口文件
+// 定义一局中玩家可以执行的动作，并根据玩家能看到的信息判断哪些动作合法
+
+mod types;
+mod validation;
+
+pub use types::{Action, AbortiveDrawKind};
+pub use validation::{ActionOptions, PlayerView, Situation, WinShape, legal_actions, is_legal, may_react, exchange_candidates};
@@ -0,0 +1,148 @@
+// src/action/types.rs
+//
+// 玩家动作的类型定义
+// 一局中玩家能做的每一件事(换牌、定缺、摸牌、打牌、鸣牌、立直、和牌、拔花、流局宣言、放弃)
+// 都表示为一个`Action`。动作只描述"做什么"，是否合法由`validation`模块判断。
+
+use std::fmt;
+
+use crate::meld::KanType;
+use crate::tile::{Suit, Tile};
+
+/// 途中流局的种类
+///
+/// 九种九牌由玩家宣言，其余由状态机在条件满足时自动判定。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbortiveDrawKind {
+    /// 九种九牌：第一巡摸牌后手中有九种以上幺九牌
+    NineTerminals,
+    /// 四风连打：无人鸣牌的第一巡四家打出同一种风牌
+    FourWinds,
+    /// 四家立直：四家都立直且第四家的宣言牌无人荣和
+    FourRiichi,
+    /// 四杠散了：两家以上合计开杠四次后的打牌无人荣和
+    FourKans,
+    /// 三家和：三家同时宣言荣和
+    TripleRon,
+}
+
+impl fmt::Display for AbortiveDrawKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AbortiveDrawKind::NineTerminals => write!(f, "九种九牌"),
+            AbortiveDrawKind::FourWinds => write!(f, "四风连打"),
+            AbortiveDrawKind::FourRiichi => write!(f, "四家立直"),
+            AbortiveDrawKind::FourKans => write!(f, "四杠散了"),
+            AbortiveDrawKind::TripleRon => write!(f, "三家和"),
+        }
+    }
+}
+
+/// 玩家动作
+///
+/// # 示例
+/// ```
+/// use majiang_core::action::Action;
+/// use majiang_core::meld::KanType;
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let tile = Tile::Suit(Suit::Dot, 7);
+/// let discard = Action::Discard { tile, tsumogiri: true };
+/// let kan = Action::Kan { tile, kan_type: KanType::Closed };
+/// assert!(discard.is_discard());
+/// assert!(kan.is_call());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// 从牌墙摸一张牌
+    Draw,
+    /// 打出一张牌，`tsumogiri`表示打出的是刚摸到的牌(摸切)
+    Discard { tile: Tile, tsumogiri: bool },
+    /// 吃上家打出的`tile`，`combination`为组成的顺子(含`tile`)，
+    /// 取自`get_possible_chi_combinations`
+    Chi { tile: Tile, combination: [Tile; 3] },
+    /// 碰他家打出的牌
+    Pon { tile: Tile },
+    /// 杠：明杠的`tile`为他家打出的牌，暗杠和加杠为自己手中的牌
+    Kan { tile: Tile, kan_type: KanType },
+    /// 立直并打出一张牌
+    Riichi { tile: Tile, tsumogiri: bool },
+    /// 自摸和
+    Tsumo,
+    /// 荣和他家打出(或加杠)的牌
+    Ron { tile: Tile },
+    /// 亮出一张花牌
+    DeclareFlower { tile: Tile },
+    /// 拔北(三人麻将)
+    Kita,
+    /// 宣言途中流局
+    AbortiveDraw(AbortiveDrawKind),
+    /// 换三张：交出同一花色的三张牌(四川麻将)
+    ExchangeTiles { tiles: [Tile; 3] },
+    /// 定缺：选择本局不要的花色(四川麻将)
+    ChooseVoid { suit: Suit },
+    /// 放弃鸣牌或荣和
+    Pass,
+}
+
+impl Action {
+    /// 是否为打牌(包括立直宣言牌)
+    pub fn is_discard(&self) -> bool {
+        matches!(self, Action::Discard { .. } | Action::Riichi { .. })
+    }
+
+    /// 是否为鸣牌(吃、碰、杠)
+    pub fn is_call(&self) -> bool {
+        matches!(self, Action::Chi { .. } | Action::Pon { .. } | Action::Kan { .. })
+    }
+
+    /// 是否为和牌
+    pub fn is_win(&self) -> bool {
+        matches!(self, Action::Tsumo | Action::Ron { .. })
+    }
+
+    /// 动作涉及的牌，没有具体牌的动作返回`None`
+    pub fn tile(&self) -> Option<Tile> {
+        match *self {
+            Action::Discard { tile, .. }
+            | Action::Chi { tile, .. }
+            | Action::Pon { tile }
+            | Action::Kan { tile, .. }
+            | Action::Riichi { tile, .. }
+            | Action::Ron { tile }
+            | Action::DeclareFlower { tile } => Some(tile),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::Draw => write!(f, "摸牌"),
+            Action::Discard { tile, tsumogiri: true } => write!(f, "摸切{}", tile),
+            Action::Discard { tile, tsumogiri: false } => write!(f, "打{}", tile),
+            Action::Chi { combination, .. } => {
+                write!(f, "吃{}{}{}", combination[0], combination[1], combination[2])
+            }
+            Action::Pon { tile } => write!(f, "碰{}", tile),
+            Action::Kan { tile, kan_type } => {
+                let name = match kan_type {
+                    KanType::Open => "明杠",
+                    KanType::Closed => "暗杠",
+                    KanType::Added => "加杠",
+                };
+                write!(f, "{}{}", name, tile)
+            }
+            Action::Riichi { tile, .. } => write!(f, "立直打{}", tile),
+            Action::Tsumo => write!(f, "自摸"),
+            Action::Ron { tile } => write!(f, "荣和{}", tile),
+            Action::DeclareFlower { tile } => write!(f, "补花{}", tile),
+            Action::Kita => write!(f, "拔北"),
+            Action::AbortiveDraw(kind) => write!(f, "{}", kind),
+            Action::ExchangeTiles { tiles } => write!(f, "换{}{}{}", tiles[0], tiles[1], tiles[2]),
+            Action::ChooseVoid { suit } => write!(f, "定缺{}", suit),
+            Action::Pass => write!(f, "过"),
+        }
+    }
+}
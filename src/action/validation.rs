@@ -0,0 +1,421 @@
+// src/action/validation.rs
+//
+// 动作合法性判断
+// 根据某位玩家能看到的信息(自己的手牌、当前需要应对的局面、场况和规则选项)
+// 列出他此刻可以执行的全部动作。机器人和界面只需从列表中选择，无需自行推导规则。
+//
+// 这里只检查牌形和时机上的合法性。和牌是否满足役(番)的要求由具体规则判断。
+
+use strum::IntoEnumIterator;
+
+use crate::action::types::{AbortiveDrawKind, Action};
+use crate::hand::{is_winning_shape, kan_keeps_waits, shanten, tenpai_discards, Hand};
+use crate::meld::{can_form_kan, can_form_pon, get_possible_chi_combinations, KanType, MeldType};
+use crate::tile::{Suit, Tile, Wind};
+
+/// 九种九牌所需的最少幺九牌种类数
+const NINE_TERMINALS_MIN_KINDS: usize = 9;
+
+/// 玩家当前需要应对的局面
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Situation {
+    /// 轮到自己摸牌
+    Draw,
+    /// 轮到自己打牌。`after_call`表示刚吃或碰，此时只能打牌
+    Turn { after_call: bool },
+    /// 座位为`from`的玩家打出了`tile`
+    Discard { tile: Tile, from: u8 },
+    /// 座位为`from`的玩家加杠了`tile`，可以抢杠
+    AddedKan { tile: Tile, from: u8 },
+    /// 配牌后选出换三张的牌
+    Exchange,
+    /// 选择定缺的花色
+    ChooseVoid,
+}
+
+/// 和牌形判定：手牌(不含和牌张)加入和牌张后是否构成和牌形
+///
+/// 默认为`hand::is_winning_shape`，有特殊牌形的规则(如国标麻将的全不靠、组合龙)通过
+/// `RuleSet::win_shape`提供自己的判定。
+pub type WinShape = fn(&Hand, Tile) -> bool;
+
+/// 影响动作合法性的规则选项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionOptions {
+    /// 玩家人数
+    pub player_count: u8,
+    /// 是否允许吃
+    pub allow_chi: bool,
+    /// 是否有立直
+    pub allow_riichi: bool,
+    /// 立直时牌墙中至少需要剩余的牌数
+    pub riichi_min_wall: usize,
+    /// 是否有拔北(三人麻将)
+    pub allow_kita: bool,
+    /// 是否允许宣言途中流局(九种九牌)
+    pub allow_abortive_draws: bool,
+    /// 一局中最多的杠数
+    pub max_kans: usize,
+}
+
+impl Default for ActionOptions {
+    /// 四人立直麻将的选项
+    fn default() -> Self {
+        Self {
+            player_count: 4,
+            allow_chi: true,
+            allow_riichi: true,
+            riichi_min_wall: 4,
+            allow_kita: false,
+            allow_abortive_draws: true,
+            max_kans: 4,
+        }
+    }
+}
+
+/// 一位玩家判断动作合法性所需的信息
+#[derive(Debug, Clone, Copy)]
+pub struct PlayerView<'a> {
+    /// 自己的座位
+    pub seat: u8,
+    /// 自己的手牌
+    pub hand: &'a Hand,
+    /// 当前需要应对的局面
+    pub situation: Situation,
+    /// 自己是否已经立直
+    pub in_riichi: bool,
+    /// 是否为自己未被鸣牌打断的第一巡
+    pub first_turn: bool,
+    /// 牌墙中剩余可摸的牌数
+    pub wall_remaining: usize,
+    /// 场上已有的杠数
+    pub kan_count: usize,
+    /// 定缺的花色(四川麻将)
+    pub void_suit: Option<Suit>,
+    /// 规则选项
+    pub options: ActionOptions,
+    /// 和牌形判定
+    pub win_shape: WinShape,
+}
+
+impl<'a> PlayerView<'a> {
+    /// 创建玩家视角，其余场况取默认值(未立直、非第一巡、无杠、四人立直麻将选项和牌形)
+    pub fn new(seat: u8, hand: &'a Hand, situation: Situation, wall_remaining: usize) -> Self {
+        Self {
+            seat,
+            hand,
+            situation,
+            in_riichi: false,
+            first_turn: false,
+            wall_remaining,
+            kan_count: 0,
+            void_suit: None,
+            options: ActionOptions::default(),
+            win_shape: is_winning_shape,
+        }
+    }
+}
+
+/// 列出玩家此刻可以执行的全部动作
+///
+/// 结果的顺序是确定的：和牌类动作在前，其次是流局宣言、拔花拔北、杠、立直、打牌，
+/// 应对他家打牌时`Pass`总在最后。
+///
+/// # 示例
+/// ```
+/// use majiang_core::action::{legal_actions, Action, PlayerView, Situation};
+/// use majiang_core::hand::Hand;
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// // 上家(座位0)打出3万，座位1可以荣和、吃或放弃
+/// let hand: Hand = "12m456p789s11122z".parse().unwrap();
+/// let tile = Tile::Suit(Suit::Character, 3);
+/// let view = PlayerView::new(1, &hand, Situation::Discard { tile, from: 0 }, 50);
+/// let actions = legal_actions(&view);
+///
+/// assert_eq!(actions[0], Action::Ron { tile });
+/// assert!(actions.iter().any(|a| matches!(a, Action::Chi { .. })));
+/// assert_eq!(actions.last(), Some(&Action::Pass));
+/// ```
+pub fn legal_actions(view: &PlayerView) -> Vec<Action> {
+    let actions = situation_actions(view);
+    match view.void_suit {
+        Some(suit) => restrict_to_void(view.hand, suit, actions),
+        None => actions,
+    }
+}
+
+/// 玩家能否应对他家打出(或加杠)的牌，不列出动作的粗略判断
+///
+/// 返回`false`时`legal_actions`只有`Pass`(或为空)；返回`true`时仍以`legal_actions`为准。
+/// 其他局面总是返回`true`。
+pub fn may_react(view: &PlayerView) -> bool {
+    let (tile, from, added_kan) = match view.situation {
+        Situation::Discard { tile, from } => (tile, from, false),
+        Situation::AddedKan { tile, from } => (tile, from, true),
+        _ => return true,
+    };
+    if from == view.seat {
+        return false;
+    }
+    if can_win_with(view, tile) {
+        return true;
+    }
+    if added_kan || view.wall_remaining == 0 || view.in_riichi || tile.is_joker() {
+        return false;
+    }
+    let player_count = view.options.player_count.max(1);
+    let is_left = (view.seat + player_count - 1) % player_count == from;
+    view.hand.count_of(tile) >= 2 || (view.options.allow_chi && is_left)
+}
+
+/// 检查某个动作此刻是否合法
+pub fn is_legal(view: &PlayerView, action: &Action) -> bool {
+    legal_actions(view).contains(action)
+}
+
+/// 不考虑定缺时的全部动作
+fn situation_actions(view: &PlayerView) -> Vec<Action> {
+    match view.situation {
+        Situation::Draw if view.wall_remaining > 0 => vec![Action::Draw],
+        Situation::Draw => Vec::new(),
+        Situation::Turn { after_call } => turn_actions(view, after_call),
+        Situation::Discard { tile, from } => discard_reactions(view, tile, from),
+        Situation::AddedKan { tile, from } => {
+            if from == view.seat {
+                return Vec::new();
+            }
+            let mut actions = Vec::new();
+            if can_win_with(view, tile) {
+                actions.push(Action::Ron { tile });
+            }
+            actions.push(Action::Pass);
+            actions
+        }
+        Situation::Exchange => {
+            exchange_candidates(view.hand).into_iter().map(|tiles| Action::ExchangeTiles { tiles }).collect()
+        }
+        Situation::ChooseVoid => Suit::iter().map(|suit| Action::ChooseVoid { suit }).collect(),
+    }
+}
+
+/// 可以换出的三张牌(换三张)：暗手中同一花色的任意三张，按牌的顺序排列且不重复
+///
+/// # 示例
+/// ```
+/// use majiang_core::action::exchange_candidates;
+/// use majiang_core::hand::Hand;
+///
+/// // 只有万子和筒子各有三张以上：万子1种组合，筒子4张中选3张有2种组合
+/// let hand: Hand = "111m1122p12s".parse().unwrap();
+/// assert_eq!(exchange_candidates(&hand).len(), 3);
+/// ```
+pub fn exchange_candidates(hand: &Hand) -> Vec<[Tile; 3]> {
+    let tiles = hand.all_concealed_tiles();
+    let mut candidates = Vec::new();
+    for suit in Suit::iter() {
+        let mut same: Vec<Tile> =
+            tiles.iter().copied().filter(|t| t.suit_and_number().is_some_and(|(s, _)| s == suit)).collect();
+        same.sort_by_key(|t| t.to_id());
+        for i in 0..same.len() {
+            for j in i + 1..same.len() {
+                for k in j + 1..same.len() {
+                    let triple = [same[i], same[j], same[k]];
+                    if !candidates.contains(&triple) {
+                        candidates.push(triple);
+                    }
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// 定缺的限制：不能碰、杠或和缺门的牌；手中还有缺门的牌时不能和牌，且只能打缺门的牌
+///
+/// # 示例
+/// ```
+/// use majiang_core::action::{legal_actions, Action, PlayerView, Situation};
+/// use majiang_core::hand::Hand;
+/// use majiang_core::tile::{Suit, Tile};
+///
+/// // 定缺条子，手中还剩一张1条
+/// let mut hand: Hand = "123m456m789p1s11p".parse().unwrap();
+/// hand.draw(Tile::Suit(Suit::Dot, 5)).unwrap();
+/// let mut view = PlayerView::new(0, &hand, Situation::Turn { after_call: false }, 50);
+/// view.void_suit = Some(Suit::Bamboo);
+/// let tile = Tile::Suit(Suit::Bamboo, 1);
+/// assert_eq!(legal_actions(&view), [Action::Discard { tile, tsumogiri: false }]);
+/// ```
+fn restrict_to_void(hand: &Hand, void_suit: Suit, actions: Vec<Action>) -> Vec<Action> {
+    let is_void = |tile: Tile| tile.suit_and_number().is_some_and(|(suit, _)| suit == void_suit);
+    let holding = hand.all_concealed_tiles().into_iter().any(is_void);
+    actions
+        .into_iter()
+        .filter(|action| match *action {
+            Action::Tsumo => !holding,
+            Action::Ron { tile } => !holding && !is_void(tile),
+            Action::Discard { tile, .. } => !holding || is_void(tile),
+            Action::Pon { tile } | Action::Kan { tile, .. } => !is_void(tile),
+            _ => true,
+        })
+        .collect()
+}
+
+/// 自己回合中的动作
+fn turn_actions(view: &PlayerView, after_call: bool) -> Vec<Action> {
+    let hand = view.hand;
+    let mut actions = Vec::new();
+
+    // 摸到花牌时必须先补花
+    if let Some(drawn) = hand.drawn().filter(|t| t.is_flower()) {
+        return vec![Action::DeclareFlower { tile: drawn }];
+    }
+
+    if !after_call {
+        if can_tsumo(view) {
+            actions.push(Action::Tsumo);
+        }
+        if view.first_turn && view.options.allow_abortive_draws && has_nine_terminals(hand) {
+            actions.push(Action::AbortiveDraw(AbortiveDrawKind::NineTerminals));
+        }
+        if view.options.allow_kita && view.wall_remaining > 0 && can_kita(hand, view.in_riichi) {
+            actions.push(Action::Kita);
+        }
+        if view.wall_remaining > 0 && view.kan_count < view.options.max_kans {
+            actions.extend(self_kans(view));
+        }
+    }
+
+    let discards = discard_candidates(hand, view.in_riichi);
+    // 向听数大于0时打出任何牌都不听牌，不必逐张检查
+    if !after_call && can_declare_riichi(view) && shanten(hand) <= 0 {
+        let candidates = tenpai_discards(hand);
+        for &(tile, tsumogiri) in &discards {
+            if candidates.contains(&tile) {
+                actions.push(Action::Riichi { tile, tsumogiri });
+            }
+        }
+    }
+    actions.extend(discards.into_iter().map(|(tile, tsumogiri)| Action::Discard { tile, tsumogiri }));
+    actions
+}
+
+/// 应对他家打牌的动作
+fn discard_reactions(view: &PlayerView, tile: Tile, from: u8) -> Vec<Action> {
+    if from == view.seat {
+        return Vec::new();
+    }
+    let hand = view.hand;
+    let mut actions = Vec::new();
+
+    if can_win_with(view, tile) {
+        actions.push(Action::Ron { tile });
+    }
+
+    // 河底牌不能鸣，立直后也不能鸣牌
+    if view.wall_remaining > 0 && !view.in_riichi && !tile.is_joker() {
+        let concealed = hand.concealed_tiles();
+        if view.kan_count < view.options.max_kans && can_form_kan(&concealed, tile, false) {
+            actions.push(Action::Kan { tile, kan_type: KanType::Open });
+        }
+        if can_form_pon(&concealed, tile) {
+            actions.push(Action::Pon { tile });
+        }
+        let player_count = view.options.player_count.max(1);
+        let is_left = (view.seat + player_count - 1) % player_count == from;
+        if view.options.allow_chi && is_left {
+            for combination in get_possible_chi_combinations(&concealed, tile) {
+                if let Ok(combination) = <[Tile; 3]>::try_from(combination) {
+                    actions.push(Action::Chi { tile, combination });
+                }
+            }
+        }
+    }
+
+    actions.push(Action::Pass);
+    actions
+}
+
+/// 暗杠和加杠
+///
+/// 立直后只能暗杠刚摸到的牌，且杠后听牌不能改变
+fn self_kans(view: &PlayerView) -> Vec<Action> {
+    let hand = view.hand;
+    let mut actions = Vec::new();
+
+    for (id, &count) in hand.tile_counts().iter().enumerate() {
+        let Some(tile) = Tile::from_id(id as u8) else { continue };
+        if count == 4 {
+            if view.in_riichi && !kan_keeps_waits(hand, tile) {
+                continue;
+            }
+            actions.push(Action::Kan { tile, kan_type: KanType::Closed });
+        }
+    }
+
+    if !view.in_riichi {
+        for meld in hand.melds().iter().filter(|m| m.meld_type == MeldType::Pon) {
+            let tile = meld.get_key_tile().to_normal();
+            if hand.count_of(tile) > 0 {
+                actions.push(Action::Kan { tile, kan_type: KanType::Added });
+            }
+        }
+    }
+    actions
+}
+
+/// 可以打出的牌：暗手中每种不同的牌(红五单独列出)，以及摸牌(摸切)
+///
+/// 立直后只能摸切
+fn discard_candidates(hand: &Hand, in_riichi: bool) -> Vec<(Tile, bool)> {
+    let mut candidates = Vec::new();
+    if !in_riichi {
+        let mut tiles = hand.concealed_tiles();
+        tiles.dedup();
+        candidates.extend(tiles.into_iter().map(|t| (t, false)));
+    }
+    candidates.extend(hand.drawn().map(|t| (t, true)));
+    candidates
+}
+
+/// 立直的前提：门前清、尚未立直、牌墙中至少还有`riichi_min_wall`张牌
+fn can_declare_riichi(view: &PlayerView) -> bool {
+    view.options.allow_riichi
+        && !view.in_riichi
+        && view.hand.is_closed()
+        && view.wall_remaining >= view.options.riichi_min_wall
+}
+
+/// 能否拔北：手中有北，立直后只能拔刚摸到的北
+fn can_kita(hand: &Hand, in_riichi: bool) -> bool {
+    let north = Tile::Wind(Wind::North);
+    if in_riichi {
+        hand.drawn() == Some(north)
+    } else {
+        hand.count_of(north) > 0
+    }
+}
+
+/// 手牌加上他家打出(或加杠)的`tile`后是否和牌(只看牌形)，打出的百搭不能荣和
+fn can_win_with(view: &PlayerView, tile: Tile) -> bool {
+    view.hand.concealed_count() % 3 == 1 && !tile.is_joker() && (view.win_shape)(view.hand, tile)
+}
+
+/// 摸到的牌能否自摸和牌(只看牌形)
+fn can_tsumo(view: &PlayerView) -> bool {
+    view.hand.drawn().is_some_and(|drawn| {
+        let mut before = view.hand.clone();
+        before.discard(drawn).is_ok() && (view.win_shape)(&before, drawn)
+    })
+}
+
+/// 手中(含摸牌)是否有九种以上幺九牌
+fn has_nine_terminals(hand: &Hand) -> bool {
+    hand.tile_counts()
+        .iter()
+        .enumerate()
+        .filter(|&(id, &count)| count > 0 && Tile::from_id(id as u8).is_some_and(|t| t.is_terminal_or_honor()))
+        .count()
+        >= NINE_TERMINALS_MIN_KINDS
+}
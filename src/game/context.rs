@@ -0,0 +1,206 @@
+// src/game/context.rs
+//
+// 和牌时的场况信息
+// 役种和番种的判定除了手牌本身，还需要大量场况：自风与场风、宝牌与里宝牌指示牌、
+// 海底/河底、岭上开花、抢杠、天和/地和/人和、一发等。`GameState`在和牌时自动构建
+// `WinContext`，计分器只需读取，不必自己追踪这些状态。
+
+use crate::game::flow::wind_at;
+use crate::game::state::GameState;
+use crate::hand::Hand;
+use crate::tile::{Tile, Wind};
+
+/// 和牌时的场况
+///
+/// `hand`为和牌前的手牌(自摸时已去掉和牌张)，与`win_tile`一起构成完整的和牌。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::WinContext;
+/// use majiang_core::hand::Hand;
+/// use majiang_core::tile::{Tile, Suit, Wind};
+///
+/// let hand: Hand = "123m456p789s23m11z".parse().unwrap();
+/// let mut context = WinContext::new(hand, Tile::Suit(Suit::Character, 4), None);
+/// context.seat_wind = Wind::South;
+/// assert!(context.is_tsumo());
+/// assert!(!context.is_dealer());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WinContext {
+    /// 和牌者座位
+    pub seat: u8,
+    /// 玩家人数
+    pub player_count: u8,
+    /// 放铳者座位，自摸时为`None`
+    pub from: Option<u8>,
+    /// 和牌张
+    pub win_tile: Tile,
+    /// 和牌前的手牌(含副露和花牌)
+    pub hand: Hand,
+    /// 自风
+    pub seat_wind: Wind,
+    /// 场风
+    pub round_wind: Wind,
+    /// 宝牌指示牌
+    pub dora_indicators: Vec<Tile>,
+    /// 里宝牌指示牌(与已翻开的宝牌指示牌数量相同)
+    pub ura_dora_indicators: Vec<Tile>,
+    /// 本场数
+    pub honba: u8,
+    /// 是否立直
+    pub riichi: bool,
+    /// 是否为两立直(第一巡未被打断时立直)
+    pub double_riichi: bool,
+    /// 是否一发
+    pub ippatsu: bool,
+    /// 海底摸月：摸最后一张牌自摸
+    pub haitei: bool,
+    /// 河底捞鱼：荣和最后一张打出的牌
+    pub houtei: bool,
+    /// 岭上开花：杠后补牌自摸
+    pub rinshan: bool,
+    /// 抢杠：荣和他家加杠的牌
+    pub chankan: bool,
+    /// 天和：庄家第一次摸牌自摸
+    pub tenhou: bool,
+    /// 地和：子家第一次摸牌自摸(第一巡未被打断)
+    pub chiihou: bool,
+    /// 人和：子家第一次摸牌前荣和(第一巡未被打断)
+    pub renhou: bool,
+    /// 和绝张：和牌张的另外三张已在河中或副露中亮明
+    pub last_of_kind: bool,
+}
+
+impl WinContext {
+    /// 创建只有手牌信息的场况，其余取默认值(四人东场东家、无宝牌、无特殊和牌条件)
+    ///
+    /// 主要用于直接计算某手牌的役种；对局中的场况由`GameState`自动构建。
+    pub fn new(hand: Hand, win_tile: Tile, from: Option<u8>) -> Self {
+        Self {
+            seat: 0,
+            player_count: 4,
+            from,
+            win_tile,
+            hand,
+            seat_wind: Wind::East,
+            round_wind: Wind::East,
+            dora_indicators: Vec::new(),
+            ura_dora_indicators: Vec::new(),
+            honba: 0,
+            riichi: false,
+            double_riichi: false,
+            ippatsu: false,
+            haitei: false,
+            houtei: false,
+            rinshan: false,
+            chankan: false,
+            tenhou: false,
+            chiihou: false,
+            renhou: false,
+            last_of_kind: false,
+        }
+    }
+
+    /// 是否自摸
+    pub fn is_tsumo(&self) -> bool {
+        self.from.is_none()
+    }
+
+    /// 和牌者是否为庄家
+    pub fn is_dealer(&self) -> bool {
+        self.seat_wind == Wind::East
+    }
+
+    /// 庄家的座位，由和牌者的座位和自风推算
+    pub fn dealer_seat(&self) -> u8 {
+        let offset = match self.seat_wind {
+            Wind::East => 0,
+            Wind::South => 1,
+            Wind::West => 2,
+            Wind::North => 3,
+        };
+        let count = self.player_count.max(1);
+        (self.seat % count + count - offset % count) % count
+    }
+}
+
+impl GameState {
+    /// 某位玩家的自风
+    pub fn seat_wind(&self, seat: u8) -> Wind {
+        let count = self.config.options.player_count;
+        wind_at((seat % count + count - self.config.dealer) % count)
+    }
+
+    /// 和牌时构建场况
+    ///
+    /// 必须在和牌张从牌墙或河中取出之后、本局结束之前调用。
+    pub(crate) fn build_win_context(&self, seat: u8, from: Option<u8>, win_tile: Tile, chankan: bool) -> WinContext {
+        let player = &self.players[seat as usize];
+        let mut hand = player.hand.clone();
+        if from.is_none() {
+            // 自摸时手牌中的摸牌就是和牌张
+            let _ = hand.discard(win_tile);
+        }
+
+        let dora_indicators: Vec<Tile> = self
+            .wall
+            .get_dora_indicators()
+            .map(|tiles| tiles.into_iter().copied().collect())
+            .unwrap_or_default();
+        let ura_dora_indicators = self
+            .wall
+            .get_uradora_indicators()
+            .map(|tiles| tiles.into_iter().copied().take(dora_indicators.len()).collect())
+            .unwrap_or_default();
+
+        let last_tile = self.wall.is_empty();
+        let first_turn = self.uninterrupted && player.discards.is_empty();
+        let is_dealer = seat == self.config.dealer;
+
+        WinContext {
+            seat,
+            player_count: self.config.options.player_count,
+            from,
+            win_tile,
+            hand,
+            seat_wind: self.seat_wind(seat),
+            round_wind: self.config.round_wind,
+            dora_indicators,
+            ura_dora_indicators,
+            honba: self.config.honba,
+            riichi: player.riichi,
+            double_riichi: player.double_riichi,
+            ippatsu: player.ippatsu,
+            haitei: from.is_none() && last_tile && !self.rinshan,
+            houtei: from.is_some() && last_tile && !chankan,
+            rinshan: from.is_none() && self.rinshan,
+            chankan,
+            tenhou: from.is_none() && first_turn && is_dealer,
+            chiihou: from.is_none() && first_turn && !is_dealer,
+            renhou: from.is_some() && first_turn && !is_dealer,
+            last_of_kind: self.visible_count(win_tile, from.is_some() && !chankan) == 3,
+        }
+    }
+
+    /// 桌面上已亮明的某种牌的张数(河中未被取走的牌和所有副露)
+    ///
+    /// `ron_discard`为真时，最后打出的那张和牌张不计入。
+    fn visible_count(&self, tile: Tile, ron_discard: bool) -> usize {
+        let count = self
+            .players
+            .iter()
+            .flat_map(|p| {
+                let discards = p.discards.iter().filter(|d| !d.claimed).map(|d| d.tile);
+                let melds = p.hand.melds().iter().flat_map(|m| m.tiles.iter().copied());
+                discards.chain(melds).collect::<Vec<_>>()
+            })
+            .filter(|t| t.is_same_kind(&tile))
+            .count();
+        if ron_discard {
+            count.saturating_sub(1)
+        } else {
+            count
+        }
+    }
+}
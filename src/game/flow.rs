@@ -0,0 +1,930 @@
+// src/game/flow.rs
+//
+// 一局游戏的状态转移
+// `GameState::apply`是推进状态的唯一入口：先检查动作在当前阶段对该玩家是否合法，
+// 再执行动作并返回发生的事件。
+//
+// 阶段的转移：
+// Dealing --庄家摸牌(配牌)--> WaitingDiscard --打牌/加杠--> WaitingCalls
+// 规则有换三张或定缺时：Dealing --> Exchanging --> ChoosingVoid --> WaitingDiscard
+// WaitingCalls --无人宣言--> 下家的WaitingDiscard(牌墙摸完时荒牌流局)
+// WaitingCalls --吃碰杠--> 鸣牌者的WaitingDiscard
+// 自摸、荣和、途中流局和荒牌流局 --> RoundEnd
+// 血战到底时和牌者退出，下一位未和牌的玩家摸牌，直到只剩一家未和牌或荒牌才进入RoundEnd
+// 四风连打、四家立直和四杠散了在打出的牌无人荣和时成立，三家和在三家宣言荣和时成立。
+// 立直同样在宣言牌无人荣和时才成立，宣言牌被荣和时不计立直、不支付供托。
+//
+// 只需要`Pass`的玩家会被自动放弃，无人能应对时不进入等待。
+//
+// 多局组成的一场比赛由`Match`管理：场风与局数、连庄、本场数、立直供托、
+// 击飞与西入，以及比赛结束时的顺位和马点(uma/oka)调整。
+
+use crate::action::{may_react, AbortiveDrawKind, Action};
+use crate::errors::{MajiangError, MajiangResult};
+use crate::game::state::{Event, GameConfig, GameState, PendingClaim, Phase, RoundOutcome, TurnStep};
+use crate::game::turn::ClaimArbiter;
+use crate::hand::{winning_tiles, Hand};
+use crate::meld::KanType;
+use crate::player::DiscardRecord;
+use crate::rules::{DoraTiming, FlowerHandling};
+use crate::tile::{Tile, Wind};
+use crate::wall::{DeadWallConfig, ReplacementSource};
+
+/// 配牌张数
+const INITIAL_HAND_SIZE: usize = 13;
+
+impl GameState {
+    /// 执行一位玩家的动作，返回发生的事件
+    ///
+    /// `Dealing`阶段只接受庄家的`Draw`，它会向所有玩家发配牌；
+    /// 之后庄家还需要再`Draw`一次摸第一张牌。
+    ///
+    /// # 错误
+    /// 本局已结束时返回`InvalidState`；动作不在该玩家此刻的合法动作中时返回`InvalidAction`
+    pub fn apply(&mut self, seat: u8, action: Action) -> MajiangResult<Vec<Event>> {
+        if self.phase == Phase::RoundEnd {
+            return Err(MajiangError::InvalidState("本局已经结束".to_string()));
+        }
+        if !self.legal_actions(seat).contains(&action) {
+            return Err(MajiangError::InvalidAction(format!("座位{}此时不能{}", seat, action)));
+        }
+
+        let mut events = Vec::new();
+        match self.phase {
+            Phase::Dealing => self.deal(&mut events)?,
+            Phase::Exchanging => self.submit_exchange(seat, action, &mut events)?,
+            Phase::ChoosingVoid => self.choose_void(seat, action, &mut events)?,
+            Phase::WaitingDiscard => self.apply_turn(seat, action, &mut events)?,
+            Phase::WaitingCalls => self.apply_response(seat, action, &mut events)?,
+            Phase::RoundEnd => {}
+        }
+        Ok(events)
+    }
+
+    /// 向所有玩家发配牌，从庄家开始
+    ///
+    /// 规则需要补花时，配牌中的花牌在发完配牌后从庄家起按座位顺序亮出并补牌。
+    fn deal(&mut self, events: &mut Vec<Event>) -> MajiangResult<()> {
+        let count = self.config.options.player_count;
+        let replace_flowers = self.rules.flower_handling() == FlowerHandling::Replace;
+        let mut dealt_flowers = Vec::new();
+        for offset in 0..count {
+            let seat = (self.config.dealer + offset) % count;
+            let tiles = self.wall.deal_initial_hand(INITIAL_HAND_SIZE)?;
+            let (flowers, others): (Vec<Tile>, Vec<Tile>) =
+                tiles.iter().partition(|t| replace_flowers && t.is_flower());
+            self.players[seat as usize].hand = Hand::from_tiles(&others)?;
+            dealt_flowers.push((seat, flowers));
+            events.push(Event::Dealt { seat, tiles });
+        }
+        self.wall.start_game();
+        if let Ok(indicators) = self.wall.get_dora_indicators() {
+            events.extend(indicators.into_iter().map(|&indicator| Event::DoraRevealed { indicator }));
+        }
+        let source = self.config.replacement_source;
+        for (seat, flowers) in dealt_flowers {
+            let hand = &mut self.players[seat as usize].hand;
+            if !self.rules.replace_dealt_flowers(&mut self.wall, hand, seat, &flowers, source, events)? {
+                return Err(MajiangError::NotEnoughTiles);
+            }
+        }
+
+        self.current = self.config.dealer;
+        self.step = TurnStep::NeedsDraw;
+        self.phase = if self.rules.tile_exchange().is_some() {
+            Phase::Exchanging
+        } else if self.rules.chooses_void_suit() {
+            Phase::ChoosingVoid
+        } else {
+            Phase::WaitingDiscard
+        };
+        Ok(())
+    }
+
+    /// 记录一位玩家换出的三张牌，全部选好后按规则的方向同时交换
+    fn submit_exchange(&mut self, seat: u8, action: Action, events: &mut Vec<Event>) -> MajiangResult<()> {
+        let Action::ExchangeTiles { tiles } = action else {
+            return Err(MajiangError::InternalError(format!("换三张阶段不能{}", action)));
+        };
+        self.exchanges[seat as usize] = Some(tiles);
+        if self.exchanges.iter().any(Option::is_none) {
+            return Ok(());
+        }
+
+        let direction = self
+            .rules
+            .tile_exchange()
+            .ok_or_else(|| MajiangError::InternalError("规则没有换三张".to_string()))?;
+        let count = self.config.options.player_count;
+        let chosen: Vec<[Tile; 3]> = std::mem::replace(&mut self.exchanges, vec![None; count as usize])
+            .into_iter()
+            .flatten()
+            .collect();
+        for (seat, tiles) in (0..count).zip(&chosen) {
+            for &tile in tiles {
+                self.hand_mut(seat).remove_tile(tile)?;
+            }
+        }
+        for (seat, tiles) in (0..count).zip(chosen) {
+            let to = direction.recipient(seat, count);
+            for tile in tiles {
+                self.hand_mut(to).add_tile(tile)?;
+            }
+            events.push(Event::TilesExchanged { from: seat, to, tiles: tiles.to_vec() });
+        }
+        self.phase = if self.rules.chooses_void_suit() { Phase::ChoosingVoid } else { Phase::WaitingDiscard };
+        Ok(())
+    }
+
+    /// 记录一位玩家定缺，全部选好后同时公布并由庄家摸牌
+    fn choose_void(&mut self, seat: u8, action: Action, events: &mut Vec<Event>) -> MajiangResult<()> {
+        let Action::ChooseVoid { suit } = action else {
+            return Err(MajiangError::InternalError(format!("定缺阶段不能{}", action)));
+        };
+        self.players[seat as usize].void_suit = Some(suit);
+        if self.players.iter().any(|p| p.void_suit.is_none()) {
+            return Ok(());
+        }
+        events.extend(self.players.iter().filter_map(|p| p.void_suit.map(|suit| Event::VoidChosen { seat: p.seat(), suit })));
+        self.phase = Phase::WaitingDiscard;
+        Ok(())
+    }
+
+    /// 当前玩家回合中的动作
+    fn apply_turn(&mut self, seat: u8, action: Action, events: &mut Vec<Event>) -> MajiangResult<()> {
+        match action {
+            Action::Draw => {
+                let tile = self.wall.draw_tile()?;
+                self.hand_mut(seat).draw(tile)?;
+                self.step = TurnStep::Drawn;
+                events.push(Event::Drew { seat, tile });
+            }
+            Action::Discard { tile, tsumogiri } => self.discard(seat, tile, tsumogiri, false, events)?,
+            Action::Riichi { tile, tsumogiri } => self.discard(seat, tile, tsumogiri, true, events)?,
+            Action::Tsumo => {
+                let tile = self.players[seat as usize]
+                    .hand
+                    .drawn()
+                    .ok_or_else(|| MajiangError::InternalError("自摸时没有摸牌".to_string()))?;
+                self.finish_win(vec![seat], None, tile, false, events)?;
+            }
+            Action::Kan { tile, kan_type: KanType::Closed } => {
+                self.hand_mut(seat).declare_kan(tile, KanType::Closed, None)?;
+                self.push_meld(seat, tile, None, events);
+                self.kan_replacement(seat, KanType::Closed, None, events)?;
+            }
+            // 加杠先给其他玩家抢杠的机会
+            Action::Kan { tile, kan_type: KanType::Added } => self.open_claims(tile, seat, true, None, events)?,
+            Action::DeclareFlower { tile } => {
+                let source = self.config.replacement_source;
+                let hand = &mut self.players[seat as usize].hand;
+                if !self.rules.declare_flower(&mut self.wall, hand, seat, tile, source, events)? {
+                    self.exhaustive_draw(events);
+                }
+            }
+            Action::Kita => self.kita(seat, events)?,
+            Action::AbortiveDraw(kind) => self.abortive_draw(kind, events),
+            _ => {
+                return Err(MajiangError::InvalidAction(format!("{}尚未被游戏流程支持", action)));
+            }
+        }
+        Ok(())
+    }
+
+    /// 其他玩家对打出(或加杠)的牌的应对
+    fn apply_response(&mut self, seat: u8, action: Action, events: &mut Vec<Event>) -> MajiangResult<()> {
+        let pending = self
+            .pending
+            .as_mut()
+            .ok_or_else(|| MajiangError::InternalError("等待应对时没有待应对的牌".to_string()))?;
+        pending.arbiter.submit(seat, action)?;
+        if pending.arbiter.is_complete() {
+            self.resolve_claims(events)?;
+        }
+        Ok(())
+    }
+
+    /// 打出一张牌并等待其他玩家应对
+    fn discard(&mut self, seat: u8, tile: Tile, tsumogiri: bool, riichi: bool, events: &mut Vec<Event>) -> MajiangResult<()> {
+        let first_discard = self.uninterrupted && self.players[seat as usize].discards.is_empty();
+        let player = &mut self.players[seat as usize];
+        player.hand.discard(tile)?;
+        player.discards.push(DiscardRecord { tile, tsumogiri, riichi, claimed: false });
+        // 立直宣言牌之后再打牌，一发圈结束
+        player.ippatsu = false;
+        player.temporary_furiten = false;
+        self.rinshan = false;
+        events.push(Event::Discarded { seat, tile, tsumogiri, riichi });
+        self.reveal_pending_dora(events);
+        self.open_claims(tile, seat, false, riichi.then_some(first_discard), events)
+    }
+
+    /// 立直宣言牌无人荣和，立直成立
+    fn accept_riichi(&mut self, seat: u8, double_riichi: bool) {
+        let player = &mut self.players[seat as usize];
+        player.riichi = true;
+        player.double_riichi = double_riichi;
+        player.ippatsu = true;
+    }
+
+    /// 开始收集其他玩家的应对，只能放弃的玩家和已和牌的玩家自动放弃
+    ///
+    /// `riichi`为立直宣言牌时的待定立直(值为是否两立直)，在仲裁后无人荣和时成立。
+    fn open_claims(
+        &mut self,
+        tile: Tile,
+        from: u8,
+        added_kan: bool,
+        riichi: Option<bool>,
+        events: &mut Vec<Event>,
+    ) -> MajiangResult<()> {
+        let arbiter = ClaimArbiter::new(from, self.config.options.player_count, self.config.ron_policy);
+        self.pending = Some(PendingClaim { tile, from, added_kan, riichi, arbiter });
+        self.phase = Phase::WaitingCalls;
+
+        for seat in (0..self.config.options.player_count).filter(|&s| s != from) {
+            if !self.may_react(seat) {
+                if let Some(pending) = self.pending.as_mut() {
+                    pending.arbiter.submit(seat, Action::Pass)?;
+                }
+            }
+        }
+        if self.pending.as_ref().is_some_and(|p| p.arbiter.is_complete()) {
+            self.resolve_claims(events)?;
+        }
+        Ok(())
+    }
+
+    /// 玩家能否应对等待宣言的牌：先做不列出动作的粗略判断，可能应对时再检查合法动作是否只有`Pass`
+    fn may_react(&self, seat: u8) -> bool {
+        self.view(seat).is_some_and(|view| may_react(&view)) && self.legal_actions(seat) != [Action::Pass]
+    }
+
+    /// 所有玩家应对完毕后执行仲裁结果
+    fn resolve_claims(&mut self, events: &mut Vec<Event>) -> MajiangResult<()> {
+        let PendingClaim { tile, from, added_kan, riichi, arbiter } = self
+            .pending
+            .take()
+            .ok_or_else(|| MajiangError::InternalError("没有待仲裁的宣言".to_string()))?;
+        let resolution = arbiter.resolve();
+        events.extend(
+            resolution.rejected.iter().map(|c| Event::ClaimRejected { seat: c.seat, action: c.action }),
+        );
+        for seat in (0..self.config.options.player_count).filter(|&s| s != from) {
+            if !resolution.winners.iter().any(|c| c.seat == seat && c.action.is_win()) {
+                self.rules.record_missed_win(&mut self.players[seat as usize], tile);
+            }
+        }
+
+        let rons = resolution.winners.iter().chain(&resolution.rejected).filter(|c| c.action.is_win()).count();
+        if rons >= 3 && self.rules.abortive_draws().allows(AbortiveDrawKind::TripleRon) {
+            self.abortive_draw(AbortiveDrawKind::TripleRon, events);
+            return Ok(());
+        }
+        // 立直宣言牌被荣和时立直不成立，不支付供托
+        if let Some(double_riichi) = riichi.filter(|_| rons == 0) {
+            self.accept_riichi(from, double_riichi);
+        }
+        if rons == 0 && !added_kan {
+            if let Some(kind) = self.rules.abortive_after_discard(self) {
+                self.abortive_draw(kind, events);
+                return Ok(());
+            }
+        }
+
+        let Some(first) = resolution.winners.first().copied() else {
+            if added_kan {
+                self.hand_mut(from).declare_kan(tile, KanType::Added, None)?;
+                self.push_meld(from, tile, None, events);
+                return self.kan_replacement(from, KanType::Added, None, events);
+            }
+            self.advance_after_discard(from, events);
+            return Ok(());
+        };
+
+        if !added_kan {
+            self.mark_claimed(from);
+        }
+        let seat = first.seat;
+        match first.action {
+            Action::Ron { .. } => {
+                let winners = resolution.winners.iter().map(|c| c.seat).collect();
+                return self.finish_win(winners, Some(from), tile, added_kan, events);
+            }
+            Action::Pon { .. } => {
+                self.hand_mut(seat).declare_pon(tile, from)?;
+                self.step = TurnStep::AfterCall;
+            }
+            Action::Chi { combination, .. } => {
+                let mut own = combination.to_vec();
+                if let Some(index) = own.iter().position(|&t| t == tile) {
+                    own.remove(index);
+                }
+                let own: [Tile; 2] = own
+                    .try_into()
+                    .map_err(|_| MajiangError::InvalidMeld(format!("吃{}的组合无效", tile)))?;
+                self.hand_mut(seat).declare_chi(tile, own, from)?;
+                self.step = TurnStep::AfterCall;
+            }
+            Action::Kan { kan_type: KanType::Open, .. } => {
+                self.hand_mut(seat).declare_kan(tile, KanType::Open, Some(from))?;
+            }
+            other => {
+                return Err(MajiangError::InternalError(format!("仲裁结果不是宣言: {}", other)));
+            }
+        }
+
+        self.interrupt();
+        self.push_meld(seat, tile, Some(from), events);
+        self.current = seat;
+        self.phase = Phase::WaitingDiscard;
+        if matches!(first.action, Action::Kan { .. }) {
+            self.kan_replacement(seat, KanType::Open, Some(from), events)?;
+        }
+        Ok(())
+    }
+
+    /// 无人鸣牌时轮到下家摸牌，牌墙已摸完则荒牌流局
+    fn advance_after_discard(&mut self, from: u8, events: &mut Vec<Event>) {
+        if self.wall.is_empty() {
+            self.exhaustive_draw(events);
+            return;
+        }
+        self.current = self.next_seat(from);
+        self.step = TurnStep::NeedsDraw;
+        self.phase = Phase::WaitingDiscard;
+    }
+
+    /// 荒牌流局，听牌的玩家亮出手牌
+    ///
+    /// 听牌要求有可以和的牌，和牌张都在自己手中的空听不算听牌。
+    fn exhaustive_draw(&mut self, events: &mut Vec<Event>) {
+        let tenpai: Vec<u8> = self
+            .players
+            .iter()
+            .filter(|p| !p.won && !winning_tiles(&p.hand).is_empty())
+            .map(|p| p.seat())
+            .collect();
+        events.extend(tenpai.iter().map(|&seat| Event::TenpaiRevealed {
+            seat,
+            tiles: self.players[seat as usize].hand.concealed_tiles(),
+        }));
+        self.end_round(RoundOutcome::ExhaustiveDraw { tenpai }, events);
+    }
+
+    /// 途中流局并结束本局
+    fn abortive_draw(&mut self, kind: AbortiveDrawKind, events: &mut Vec<Event>) {
+        events.push(Event::AbortiveDraw(kind));
+        self.end_round(RoundOutcome::AbortiveDraw(kind), events);
+    }
+
+    /// 杠后结算杠的点数、翻开宝牌指示牌并摸岭上牌
+    ///
+    /// 翻开的时机由规则决定；之前的杠尚未翻开的宝牌指示牌在再次开杠时先翻开。
+    /// 明杠的`from`为放杠者。
+    fn kan_replacement(
+        &mut self,
+        seat: u8,
+        kan_type: KanType,
+        from: Option<u8>,
+        events: &mut Vec<Event>,
+    ) -> MajiangResult<()> {
+        let payments = self.rules.kan_payments(self, seat, kan_type, from);
+        if payments.iter().any(|&p| p != 0) {
+            for (total, change) in self.kan_payments.iter_mut().zip(&payments) {
+                *total += change;
+            }
+            events.push(Event::KanPaid { seat, payments });
+        }
+        self.interrupt();
+        self.rinshan = true;
+        self.reveal_pending_dora(events);
+        match self.rules.kan_dora_timing(kan_type) {
+            DoraTiming::Immediate => self.reveal_dora(events),
+            DoraTiming::AfterDiscard => self.pending_dora += 1,
+        }
+        let tile = self.draw_replacement()?;
+        self.hand_mut(seat).draw(tile)?;
+        events.push(Event::DrewReplacement { seat, tile });
+        self.current = seat;
+        self.step = TurnStep::Drawn;
+        self.phase = Phase::WaitingDiscard;
+        Ok(())
+    }
+
+    /// 拔北并从岭上牌区补牌，主牌墙已摸完时荒牌流局
+    fn kita(&mut self, seat: u8, events: &mut Vec<Event>) -> MajiangResult<()> {
+        self.interrupt();
+        self.hand_mut(seat).declare_nuki(Tile::Wind(Wind::North))?;
+        events.push(Event::KitaDeclared { seat });
+        let tile = match self.wall.draw_kita_replacement() {
+            Ok(tile) => tile,
+            Err(MajiangError::NotEnoughTiles) => {
+                self.exhaustive_draw(events);
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        self.rinshan = true;
+        self.hand_mut(seat).draw(tile)?;
+        events.push(Event::DrewReplacement { seat, tile });
+        self.current = seat;
+        self.step = TurnStep::Drawn;
+        self.phase = Phase::WaitingDiscard;
+        Ok(())
+    }
+
+    /// 翻开一张新的宝牌指示牌，没有宝牌的规则不产生事件
+    fn reveal_dora(&mut self, events: &mut Vec<Event>) {
+        if let Ok(&indicator) = self.wall.reveal_next_dora_indicator() {
+            events.push(Event::DoraRevealed { indicator });
+        }
+    }
+
+    /// 翻开等待打牌时才翻开的杠宝牌指示牌
+    fn reveal_pending_dora(&mut self, events: &mut Vec<Event>) {
+        for _ in 0..std::mem::take(&mut self.pending_dora) {
+            self.reveal_dora(events);
+        }
+    }
+
+    /// 摸一张杠后的补牌：来源由`GameConfig::replacement_source`决定
+    ///
+    /// 立直麻将从岭上牌区摸牌后，把主牌墙末尾的一张移入岭上牌区(与拔北相同)。
+    fn draw_replacement(&mut self) -> MajiangResult<Tile> {
+        match (self.config.dead_wall, self.config.replacement_source) {
+            (Some(DeadWallConfig::Riichi { .. }), ReplacementSource::DeadWall) => self.wall.draw_replenished_replacement(),
+            (_, source) => self.wall.draw_replacement_from(source),
+        }
+    }
+
+    /// 和牌，同时构建各和牌者的场况并按规则计分
+    ///
+    /// 血战到底时已和牌的玩家不再支付；尚有两家以上未和牌且牌墙未摸完时本局继续，
+    /// 由最后一位和牌者的下一位未和牌的玩家摸牌。
+    fn finish_win(
+        &mut self,
+        winners: Vec<u8>,
+        from: Option<u8>,
+        tile: Tile,
+        chankan: bool,
+        events: &mut Vec<Event>,
+    ) -> MajiangResult<()> {
+        let contexts: Vec<_> = winners
+            .iter()
+            .map(|&seat| self.build_win_context(seat, from, tile, chankan))
+            .collect();
+        let mut scores = contexts
+            .iter()
+            .map(|context| {
+                self.rules.score_win(context).ok_or_else(|| {
+                    MajiangError::InternalError(format!("座位{}的和牌不满足起和条件", context.seat))
+                })
+            })
+            .collect::<MajiangResult<Vec<_>>>()?;
+        if self.players.iter().any(|p| p.won) {
+            for (context, score) in contexts.iter().zip(&mut scores) {
+                self.exclude_won_payers(context.seat, &mut score.payments);
+            }
+        }
+        self.win_contexts.extend(contexts);
+        self.win_scores.extend(scores);
+        events.extend(winners.iter().map(|&seat| Event::Won { seat, from, tile }));
+        for &seat in &winners {
+            self.players[seat as usize].won = true;
+        }
+
+        let count = self.players.len();
+        let all_winners: Vec<u8> = self.win_contexts.iter().map(|context| context.seat).collect();
+        if !self.rules.bloody_battle() || all_winners.len() + 1 >= count {
+            self.end_round(RoundOutcome::Win { winners: all_winners, from, tile }, events);
+            return Ok(());
+        }
+        if self.wall.is_empty() {
+            self.exhaustive_draw(events);
+            return Ok(());
+        }
+        // 被抢杠的牌从加杠者手中移走
+        if let (true, Some(kanner)) = (chankan, from) {
+            self.hand_mut(kanner).remove_tile(tile)?;
+        }
+        let last = winners.last().copied().unwrap_or(self.current);
+        self.rinshan = false;
+        self.current = self.next_seat(last);
+        self.step = TurnStep::NeedsDraw;
+        self.phase = Phase::WaitingDiscard;
+        Ok(())
+    }
+
+    /// 已和牌的玩家不再支付，和牌者的收入按其余玩家的支付重新计算
+    fn exclude_won_payers(&self, winner: u8, payments: &mut [i32]) {
+        for (player, payment) in self.players.iter().zip(payments.iter_mut()) {
+            if player.won && player.seat() != winner {
+                *payment = 0;
+            }
+        }
+        let paid: i32 = payments.iter().enumerate().filter(|&(seat, _)| seat != winner as usize).map(|(_, p)| p).sum();
+        payments[winner as usize] = -paid;
+    }
+
+    /// 结束本局并按规则结算点数
+    ///
+    /// 点数变化为各次和牌的得分、开杠时已结算的点数与荒牌流局的结算之和。
+    fn end_round(&mut self, outcome: RoundOutcome, events: &mut Vec<Event>) {
+        self.outcome = Some(outcome.clone());
+        let draw_payments = match outcome {
+            RoundOutcome::ExhaustiveDraw { .. } => self.rules.exhaustive_draw_payments(self),
+            RoundOutcome::Win { .. } | RoundOutcome::AbortiveDraw(_) => Vec::new(),
+        };
+        let mut changes = self.kan_payments.clone();
+        for payments in self.win_scores.iter().map(|score| &score.payments).chain([&draw_payments]) {
+            for (sum, change) in changes.iter_mut().zip(payments) {
+                *sum += change;
+            }
+        }
+        self.point_changes = changes;
+        events.push(Event::RoundEnded { outcome });
+        self.pending = None;
+        self.phase = Phase::RoundEnd;
+    }
+
+    /// 鸣牌或杠打断第一巡和所有玩家的一发
+    fn interrupt(&mut self) {
+        self.uninterrupted = false;
+        for player in &mut self.players {
+            player.ippatsu = false;
+        }
+    }
+
+    /// 标记某位玩家最后打出的牌被取走
+    fn mark_claimed(&mut self, seat: u8) {
+        if let Some(record) = self.players[seat as usize].discards.last_mut() {
+            record.claimed = true;
+        }
+    }
+
+    /// 记录某位玩家含`tile`的最新副露(加杠时副露在原来碰的位置)
+    fn push_meld(&self, seat: u8, tile: Tile, from: Option<u8>, events: &mut Vec<Event>) {
+        let melds = self.players[seat as usize].hand.melds();
+        if let Some(meld) = melds.iter().rev().find(|m| m.contains(tile)) {
+            events.push(Event::Called { seat, from, meld: meld.clone() });
+        }
+    }
+
+    fn hand_mut(&mut self, seat: u8) -> &mut Hand {
+        &mut self.players[seat as usize].hand
+    }
+}
+
+/// 比赛长度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchLength {
+    /// 东风战：只打东场
+    Tonpuusen,
+    /// 半庄战：打东场和南场
+    #[default]
+    Hanchan,
+}
+
+impl MatchLength {
+    /// 正常结束前的场数
+    fn winds(self) -> u8 {
+        match self {
+            MatchLength::Tonpuusen => 1,
+            MatchLength::Hanchan => 2,
+        }
+    }
+}
+
+/// 比赛配置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchConfig {
+    /// 比赛长度
+    pub length: MatchLength,
+    /// 玩家人数
+    pub player_count: u8,
+    /// 配给原点
+    pub starting_points: i32,
+    /// 返点，也是西入时结束比赛所需的点数
+    pub target_points: i32,
+    /// 各顺位的马点(点数单位)，长度等于玩家人数
+    pub uma: Vec<i32>,
+    /// 有玩家点数低于0时是否结束比赛(击飞)
+    pub tobi: bool,
+    /// 最后一局结束时无人达到返点是否进入下一场(西入)，之后有人达到返点即结束
+    pub west_extension: bool,
+    /// 最后一局庄家连庄且位居第一时是否可以结束比赛(和了止め)
+    pub agari_yame: bool,
+    /// 每位立直者向供托支付的点数
+    pub riichi_deposit: i32,
+}
+
+impl Default for MatchConfig {
+    /// 四人半庄战：25000点配给，30000点返，马点10-20，立直供托1000点
+    fn default() -> Self {
+        Self {
+            length: MatchLength::Hanchan,
+            player_count: 4,
+            starting_points: 25000,
+            target_points: 30000,
+            uma: vec![20000, 10000, -10000, -20000],
+            tobi: true,
+            west_extension: true,
+            agari_yame: false,
+            riichi_deposit: 1000,
+        }
+    }
+}
+
+impl MatchConfig {
+    /// 三人半庄战：35000点配给，40000点返，马点±15000
+    pub fn sanma() -> Self {
+        Self { player_count: 3, starting_points: 35000, target_points: 40000, uma: vec![15000, 0, -15000], ..Self::default() }
+    }
+}
+
+/// 交给`Match`的一局结算结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundResult {
+    /// 和牌者，按从放铳者起的座位顺序排列；流局时为空
+    pub winners: Vec<u8>,
+    /// 荒牌流局时听牌的玩家
+    pub tenpai: Vec<u8>,
+    /// 是否为途中流局
+    pub abortive: bool,
+    /// 本局宣言立直的玩家，每人向供托支付1000点
+    pub riichi: Vec<u8>,
+    /// 各座位的点数变化(含本场棒和流局罚符，不含立直供托)
+    pub point_changes: Vec<i32>,
+}
+
+impl RoundResult {
+    /// 由一局的结果和点数变化生成结算结果，立直者取自各玩家的立直状态
+    pub fn from_game(game: &GameState, point_changes: Vec<i32>) -> MajiangResult<Self> {
+        let outcome = game
+            .outcome()
+            .ok_or_else(|| MajiangError::InvalidState("本局尚未结束".to_string()))?;
+        let (winners, tenpai, abortive) = match outcome {
+            RoundOutcome::Win { winners, .. } => (winners.clone(), Vec::new(), false),
+            RoundOutcome::ExhaustiveDraw { tenpai } => (Vec::new(), tenpai.clone(), false),
+            RoundOutcome::AbortiveDraw(_) => (Vec::new(), Vec::new(), true),
+        };
+        let riichi = game.players().iter().filter(|p| p.is_riichi()).map(|p| p.seat()).collect();
+        Ok(Self { winners, tenpai, abortive, riichi, point_changes })
+    }
+}
+
+/// 比赛结束时一位玩家的成绩
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    /// 座位
+    pub seat: u8,
+    /// 最终点数(剩余供托归第一位)
+    pub points: i32,
+    /// 顺位，从1开始；同点时起家方向的座位在前
+    pub rank: u8,
+    /// 经过返点、马点和oka调整后的成绩(点数单位，如45000即+45.0)
+    pub score: i32,
+}
+
+/// 一场比赛(东风战或半庄战)的进行状态
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::{Match, MatchConfig, RoundResult};
+/// use majiang_core::tile::Wind;
+///
+/// let mut game = Match::new(MatchConfig::default()).unwrap();
+/// assert_eq!(game.round_wind(), Wind::East);
+/// assert_eq!(game.seat_wind(1), Wind::South);
+///
+/// // 子家荣和，庄家下庄
+/// game.record_round(&RoundResult {
+///     winners: vec![1],
+///     tenpai: vec![],
+///     abortive: false,
+///     riichi: vec![],
+///     point_changes: vec![0, 3900, -3900, 0],
+/// }).unwrap();
+/// assert_eq!(game.dealer(), 1);
+/// assert_eq!(game.round_number(), 2);
+/// assert_eq!(game.points()[1], 28900);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    config: MatchConfig,
+    /// 已经过的场数(0为东场)
+    wind_index: u8,
+    /// 当前场中的局数(0为一局)
+    round_index: u8,
+    honba: u8,
+    riichi_sticks: u8,
+    points: Vec<i32>,
+    finished: bool,
+}
+
+/// 比赛最多进行到北场
+const MAX_WINDS: u8 = 4;
+
+impl Match {
+    /// 创建比赛，起家为座位0
+    ///
+    /// # 错误
+    /// 玩家人数不是2至4人或马点个数与人数不符时返回`ConfigurationError`
+    pub fn new(config: MatchConfig) -> MajiangResult<Self> {
+        if !(2..=4).contains(&config.player_count) {
+            return Err(MajiangError::ConfigurationError(format!("不支持{}人比赛", config.player_count)));
+        }
+        if config.uma.len() != config.player_count as usize {
+            return Err(MajiangError::ConfigurationError(format!(
+                "马点需要{}个，实际为{}个", config.player_count, config.uma.len()
+            )));
+        }
+        let points = vec![config.starting_points; config.player_count as usize];
+        Ok(Self { config, wind_index: 0, round_index: 0, honba: 0, riichi_sticks: 0, points, finished: false })
+    }
+
+    /// 比赛配置
+    pub fn config(&self) -> &MatchConfig {
+        &self.config
+    }
+
+    /// 场风
+    pub fn round_wind(&self) -> Wind {
+        wind_at(self.wind_index)
+    }
+
+    /// 局数，从1开始(东一局为1)
+    pub fn round_number(&self) -> u8 {
+        self.round_index + 1
+    }
+
+    /// 当前庄家座位
+    pub fn dealer(&self) -> u8 {
+        self.round_index
+    }
+
+    /// 为当前一局设置庄家、场风、本场数和各座位的点数
+    pub fn round_config(&self, base: GameConfig) -> GameConfig {
+        let points = Some(self.points.clone());
+        GameConfig { dealer: self.dealer(), round_wind: self.round_wind(), honba: self.honba, points, ..base }
+    }
+
+    /// 某个座位的自风
+    pub fn seat_wind(&self, seat: u8) -> Wind {
+        let count = self.config.player_count;
+        wind_at((seat % count + count - self.dealer()) % count)
+    }
+
+    /// 本场数
+    pub fn honba(&self) -> u8 {
+        self.honba
+    }
+
+    /// 场上累积的立直棒数
+    pub fn riichi_sticks(&self) -> u8 {
+        self.riichi_sticks
+    }
+
+    /// 各座位的当前点数
+    pub fn points(&self) -> &[i32] {
+        &self.points
+    }
+
+    /// 比赛是否已经结束
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// 记录一局的结算，推进局数、本场数和供托，并判断比赛是否结束
+    ///
+    /// # 错误
+    /// 比赛已结束时返回`InvalidState`；点数变化个数与人数不符或座位超出范围时返回`InvalidOperation`
+    pub fn record_round(&mut self, result: &RoundResult) -> MajiangResult<()> {
+        if self.finished {
+            return Err(MajiangError::InvalidState("比赛已经结束".to_string()));
+        }
+        let count = self.config.player_count;
+        if result.point_changes.len() != count as usize {
+            return Err(MajiangError::InvalidOperation(format!(
+                "点数变化需要{}个，实际为{}个", count, result.point_changes.len()
+            )));
+        }
+        let seats = result.winners.iter().chain(&result.tenpai).chain(&result.riichi);
+        if let Some(seat) = seats.copied().find(|&s| s >= count) {
+            return Err(MajiangError::InvalidOperation(format!("座位{}超出范围", seat)));
+        }
+
+        for &seat in &result.riichi {
+            self.points[seat as usize] -= self.config.riichi_deposit;
+            self.riichi_sticks += 1;
+        }
+        for (points, change) in self.points.iter_mut().zip(&result.point_changes) {
+            *points += change;
+        }
+
+        let dealer = self.dealer();
+        let renchan = match result.winners.first() {
+            Some(&first) => {
+                // 供托归离放铳者最近的和牌者
+                self.points[first as usize] += self.config.riichi_deposit * self.riichi_sticks as i32;
+                self.riichi_sticks = 0;
+                result.winners.contains(&dealer)
+            }
+            None if result.abortive => true,
+            None => result.tenpai.contains(&dealer),
+        };
+        // 庄家和牌或任何流局都累积本场，子家和牌清零
+        self.honba = if result.winners.is_empty() || renchan { self.honba + 1 } else { 0 };
+
+        let was_last_round = self.is_last_round();
+        if self.config.tobi && self.points.iter().any(|&p| p < 0) {
+            self.finished = true;
+        } else if renchan {
+            let agari_yame = was_last_round && self.config.agari_yame && self.leader() == dealer
+                && self.points[dealer as usize] >= self.config.target_points;
+            self.finished = agari_yame || self.extension_over();
+        } else {
+            self.advance_dealer();
+        }
+        Ok(())
+    }
+
+    /// 比赛结束时的顺位和调整后的成绩，按顺位排列
+    ///
+    /// 剩余的立直供托归第一位。比赛尚未结束时按当前点数计算。
+    pub fn standings(&self) -> Vec<Standing> {
+        let count = self.config.player_count as usize;
+        let mut order: Vec<u8> = (0..count as u8).collect();
+        order.sort_by_key(|&seat| std::cmp::Reverse(self.points[seat as usize]));
+
+        let oka = (self.config.target_points - self.config.starting_points) * count as i32;
+        order
+            .iter()
+            .enumerate()
+            .map(|(index, &seat)| {
+                let mut points = self.points[seat as usize];
+                if index == 0 {
+                    points += self.config.riichi_deposit * self.riichi_sticks as i32;
+                }
+                let mut score = points - self.config.target_points + self.config.uma[index];
+                if index == 0 {
+                    score += oka;
+                }
+                Standing { seat, points, rank: index as u8 + 1, score }
+            })
+            .collect()
+    }
+
+    /// 当前是否为最后一局(含西入后的每一局)
+    fn is_last_round(&self) -> bool {
+        let extended = self.wind_index >= self.config.length.winds();
+        extended || (self.wind_index + 1 == self.config.length.winds()
+            && self.round_index + 1 == self.config.player_count)
+    }
+
+    /// 点数最高的座位，同点时座位号小者优先
+    fn leader(&self) -> u8 {
+        (0..self.config.player_count)
+            .max_by_key(|&seat| (self.points[seat as usize], std::cmp::Reverse(seat)))
+            .unwrap_or(0)
+    }
+
+    /// 庄家下庄，必要时进入下一场或结束比赛
+    fn advance_dealer(&mut self) {
+        if self.extension_over() {
+            self.finished = true;
+            return;
+        }
+        if self.round_index + 1 < self.config.player_count {
+            self.round_index += 1;
+            return;
+        }
+
+        let next_wind = self.wind_index + 1;
+        let reached_target = self.points.iter().any(|&p| p >= self.config.target_points);
+        let regular_over = next_wind >= self.config.length.winds();
+        if next_wind >= MAX_WINDS || (regular_over && (reached_target || !self.config.west_extension)) {
+            self.finished = true;
+            return;
+        }
+        self.wind_index = next_wind;
+        self.round_index = 0;
+    }
+
+    /// 西入后是否已有玩家达到返点
+    fn extension_over(&self) -> bool {
+        self.wind_index >= self.config.length.winds()
+            && self.points.iter().any(|&p| p >= self.config.target_points)
+    }
+}
+
+/// 由序号得到风(0为东)
+pub(crate) fn wind_at(index: u8) -> Wind {
+    match index % 4 {
+        0 => Wind::East,
+        1 => Wind::South,
+        2 => Wind::West,
+        _ => Wind::North,
+    }
+}
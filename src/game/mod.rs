@@ -0,0 +1,15 @@
+// src/game/mod.rs
+//
+// 游戏流程和状态管理模块的入口文件
+// `GameState`是一局游戏的权威状态，只能通过`apply`推进；鸣牌仲裁在`turn`中实现，
+// 和牌时的场况(`WinContext`)由`GameState`自动构建
+
+mod context;
+mod flow;
+mod state;
+mod turn;
+
+pub use context::WinContext;
+pub use state::{GameConfig, GameState, Phase, Event, RoundOutcome};
+pub use flow::{Match, MatchConfig, MatchLength, RoundResult, Standing};
+pub use turn::{Claim, ClaimArbiter, ClaimResolution, RonPolicy};
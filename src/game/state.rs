@@ -0,0 +1,421 @@
+// src/game/state.rs
+//
+// 一局游戏的权威状态
+// `GameState`持有牌墙、各玩家的模型(手牌、河牌、立直状态)、当前轮到的玩家和所处阶段。
+// 状态只能通过`apply`(见flow.rs)推进，每次推进返回发生的事件列表。
+// 起和条件、计分和流局结算由持有的`RuleSet`决定。
+//
+// 状态中不使用任何依赖哈希顺序的容器，牌墙由种子决定，
+// 因此相同的配置和相同的动作序列总会产生完全相同的事件。
+
+use std::sync::Arc;
+
+use crate::action::{legal_actions, AbortiveDrawKind, Action, ActionOptions, PlayerView, Situation};
+use crate::errors::{MajiangError, MajiangResult};
+use crate::game::context::WinContext;
+use crate::game::turn::{ClaimArbiter, RonPolicy};
+use crate::meld::{Meld, MeldType};
+use crate::player::Player;
+use crate::rules::{Furiten, RiichiRules, RuleSet, SanmaRules, WinScore};
+use crate::tile::{Suit, Tile, Wind};
+use crate::wall::{DeadWallConfig, ReplacementSource, Wall, WallConfig};
+
+/// 一局游戏的配置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameConfig {
+    /// 牌墙配置
+    pub wall: WallConfig,
+    /// 岭上牌区配置
+    pub dead_wall: Option<DeadWallConfig>,
+    /// 补花和杠后补牌的来源
+    pub replacement_source: ReplacementSource,
+    /// 洗牌种子
+    pub seed: u64,
+    /// 庄家座位
+    pub dealer: u8,
+    /// 动作合法性选项(包括玩家人数)
+    pub options: ActionOptions,
+    /// 多家荣和的处理方式
+    pub ron_policy: RonPolicy,
+    /// 场风
+    pub round_wind: Wind,
+    /// 本场数
+    pub honba: u8,
+    /// 各座位本局开始时的点数，长度应等于玩家人数；设置后点数不足以支付供托的玩家不能立直
+    pub points: Option<Vec<i32>>,
+}
+
+impl GameConfig {
+    /// 按规则的牌墙、岭上牌区、动作选项和荣和方式生成配置，庄家为座位0，东场0本场
+    pub fn from_rules(rules: &dyn RuleSet, seed: u64) -> Self {
+        Self {
+            wall: rules.wall_config(),
+            dead_wall: rules.dead_wall_config(),
+            replacement_source: rules.replacement_source(),
+            seed,
+            dealer: 0,
+            options: rules.action_options(),
+            ron_policy: rules.ron_policy(),
+            round_wind: Wind::East,
+            honba: 0,
+            points: None,
+        }
+    }
+
+    /// 四人立直麻将的配置：136张牌，14张岭上牌区，庄家为座位0
+    pub fn riichi(seed: u64) -> Self {
+        Self::from_rules(&RiichiRules::default(), seed)
+    }
+
+    /// 三人立直麻将的配置：108张牌，14张岭上牌区，可以拔北，庄家为座位0
+    ///
+    /// 计分需要`SanmaRules`，请配合`GameState::with_rules`使用。
+    pub fn sanma(seed: u64) -> Self {
+        Self::from_rules(&SanmaRules::default(), seed)
+    }
+}
+
+/// 一局所处的阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// 尚未配牌，等待庄家摸牌开局
+    Dealing,
+    /// 配牌后等待各家选出换三张的牌
+    Exchanging,
+    /// 等待各家定缺
+    ChoosingVoid,
+    /// 等待当前玩家摸牌或打牌
+    WaitingDiscard,
+    /// 等待其他玩家应对打出(或加杠)的牌
+    WaitingCalls,
+    /// 本局已结束
+    RoundEnd,
+}
+
+/// 一局的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundOutcome {
+    /// 和牌，`winners`按从放铳者起的座位顺序排列(自摸时只有一人)，
+    /// `from`为放铳者(自摸时为`None`)，`tile`为和牌张
+    ///
+    /// 血战到底时`winners`为本局所有和牌者(按和牌的先后)，`from`和`tile`为最后一次和牌；
+    /// 有人和牌后荒牌时结果为`ExhaustiveDraw`，之前的和牌仍记在`GameState::win_scores`中
+    Win { winners: Vec<u8>, from: Option<u8>, tile: Tile },
+    /// 荒牌流局，`tenpai`为听牌的玩家
+    ExhaustiveDraw { tenpai: Vec<u8> },
+    /// 途中流局
+    AbortiveDraw(AbortiveDrawKind),
+}
+
+/// 状态推进时发生的事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// 向某位玩家发了配牌
+    Dealt { seat: u8, tiles: Vec<Tile> },
+    /// 从牌墙摸牌
+    Drew { seat: u8, tile: Tile },
+    /// 摸补牌(补花或杠后补牌)，来源见`GameConfig::replacement_source`
+    DrewReplacement { seat: u8, tile: Tile },
+    /// 打出一张牌
+    Discarded { seat: u8, tile: Tile, tsumogiri: bool, riichi: bool },
+    /// 吃、碰或杠，`from`为被鸣牌的玩家，暗杠和加杠为`None`
+    Called { seat: u8, from: Option<u8>, meld: Meld },
+    /// 亮出花牌(开局时配牌中的花牌或对局中摸到的花牌)，随后是一次`DrewReplacement`
+    FlowerDeclared { seat: u8, tile: Tile },
+    /// 三人麻将中拔出一张北作为拔北宝牌，随后是一次`DrewReplacement`
+    KitaDeclared { seat: u8 },
+    /// 换三张：座位`from`的三张牌交给了座位`to`
+    TilesExchanged { from: u8, to: u8, tiles: Vec<Tile> },
+    /// 某位玩家定缺
+    VoidChosen { seat: u8, suit: Suit },
+    /// 开杠时立即结算的点数变化(刮风下雨)
+    KanPaid { seat: u8, payments: Vec<i32> },
+    /// 翻开新的宝牌指示牌
+    DoraRevealed { indicator: Tile },
+    /// 宣言被更高优先级的宣言否决
+    ClaimRejected { seat: u8, action: Action },
+    /// 荒牌流局时听牌的玩家亮出暗手
+    TenpaiRevealed { seat: u8, tiles: Vec<Tile> },
+    /// 途中流局，随后是`RoundEnded`
+    AbortiveDraw(AbortiveDrawKind),
+    /// 和牌，`from`为放铳者，自摸时为`None`
+    Won { seat: u8, from: Option<u8>, tile: Tile },
+    /// 本局结束
+    RoundEnded { outcome: RoundOutcome },
+}
+
+/// 当前玩家回合内所处的步骤
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TurnStep {
+    /// 需要摸牌
+    NeedsDraw,
+    /// 已摸牌(或杠后补牌)，需要打牌
+    Drawn,
+    /// 吃碰后，只能打牌
+    AfterCall,
+}
+
+/// 等待其他玩家应对的牌
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PendingClaim {
+    /// 被应对的牌
+    pub(crate) tile: Tile,
+    /// 打出(或加杠)这张牌的玩家
+    pub(crate) from: u8,
+    /// 是否为加杠(抢杠)
+    pub(crate) added_kan: bool,
+    /// 立直宣言牌：无人荣和时立直才成立，值为是否两立直
+    pub(crate) riichi: Option<bool>,
+    /// 宣言收集与仲裁
+    pub(crate) arbiter: ClaimArbiter,
+}
+
+/// 一局游戏的权威状态
+///
+/// # 示例
+/// ```
+/// use majiang_core::action::Action;
+/// use majiang_core::game::{GameConfig, GameState, Phase};
+///
+/// let mut game = GameState::new(GameConfig::riichi(42)).unwrap();
+/// assert_eq!(game.phase(), Phase::Dealing);
+///
+/// // 庄家摸牌开局：发配牌并摸第一张牌
+/// game.apply(0, Action::Draw).unwrap();
+/// game.apply(0, Action::Draw).unwrap();
+/// assert_eq!(game.phase(), Phase::WaitingDiscard);
+///
+/// // 每一步只能从合法动作中选择
+/// let discard = *game.legal_actions(0).last().unwrap();
+/// let events = game.apply(0, discard).unwrap();
+/// assert!(!events.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GameState {
+    pub(crate) config: GameConfig,
+    pub(crate) rules: Arc<dyn RuleSet>,
+    pub(crate) wall: Wall,
+    pub(crate) players: Vec<Player>,
+    pub(crate) phase: Phase,
+    pub(crate) current: u8,
+    pub(crate) step: TurnStep,
+    pub(crate) pending: Option<PendingClaim>,
+    /// 第一巡是否尚未被鸣牌打断
+    pub(crate) uninterrupted: bool,
+    /// 当前玩家的摸牌是否为杠后的岭上牌
+    pub(crate) rinshan: bool,
+    /// 等到下次打牌时才翻开的杠宝牌指示牌数
+    pub(crate) pending_dora: usize,
+    pub(crate) outcome: Option<RoundOutcome>,
+    pub(crate) win_contexts: Vec<WinContext>,
+    pub(crate) win_scores: Vec<WinScore>,
+    /// 换三张时各座位选出的牌，全部选好后一起交换
+    pub(crate) exchanges: Vec<Option<[Tile; 3]>>,
+    /// 开杠时已结算的点数变化之和
+    pub(crate) kan_payments: Vec<i32>,
+    pub(crate) point_changes: Vec<i32>,
+}
+
+impl GameState {
+    /// 按配置洗牌并创建一局立直麻将，处于`Dealing`阶段
+    ///
+    /// 起和条件和计分使用`RiichiRules`；其他规则请使用`with_rules`。
+    ///
+    /// # 错误
+    /// 玩家人数不是2至4人、庄家座位超出范围、点数的个数与玩家人数不符或牌墙创建失败时返回错误
+    pub fn new(config: GameConfig) -> MajiangResult<Self> {
+        Self::with_rules(config, Arc::new(RiichiRules::default()))
+    }
+
+    /// 按配置洗牌并创建一局，起和条件、计分和流局结算由`rules`决定
+    ///
+    /// 牌墙和动作选项以`config`为准，通常由`GameConfig::from_rules`生成。
+    ///
+    /// # 错误
+    /// 同`new`
+    pub fn with_rules(config: GameConfig, rules: Arc<dyn RuleSet>) -> MajiangResult<Self> {
+        let count = config.options.player_count;
+        if !(2..=4).contains(&count) {
+            return Err(MajiangError::ConfigurationError(format!("不支持{}人游戏", count)));
+        }
+        if config.dealer >= count {
+            return Err(MajiangError::ConfigurationError(format!("庄家座位{}超出范围", config.dealer)));
+        }
+        if let Some(points) = config.points.as_ref().filter(|p| p.len() != count as usize) {
+            return Err(MajiangError::ConfigurationError(format!("{}人游戏设置了{}个座位的点数", count, points.len())));
+        }
+        let wall = Wall::new(config.wall, config.dead_wall, Some(config.seed))?;
+        Ok(Self {
+            current: config.dealer,
+            config,
+            rules,
+            wall,
+            players: (0..count).map(Player::new).collect(),
+            phase: Phase::Dealing,
+            step: TurnStep::NeedsDraw,
+            pending: None,
+            uninterrupted: true,
+            rinshan: false,
+            pending_dora: 0,
+            outcome: None,
+            win_contexts: Vec::new(),
+            win_scores: Vec::new(),
+            exchanges: vec![None; count as usize],
+            kan_payments: vec![0; count as usize],
+            point_changes: Vec::new(),
+        })
+    }
+
+    /// 本局配置
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    /// 本局使用的规则
+    pub fn rules(&self) -> &dyn RuleSet {
+        self.rules.as_ref()
+    }
+
+    /// 当前阶段
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// 当前轮到的玩家(等待应对时为打牌者)
+    pub fn current_seat(&self) -> u8 {
+        self.current
+    }
+
+    /// 庄家座位
+    pub fn dealer(&self) -> u8 {
+        self.config.dealer
+    }
+
+    /// 某位玩家的状态
+    pub fn player(&self, seat: u8) -> Option<&Player> {
+        self.players.get(seat as usize)
+    }
+
+    /// 所有玩家的状态，按座位排列
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// 牌墙
+    pub fn wall(&self) -> &Wall {
+        &self.wall
+    }
+
+    /// 等待应对的牌及其打出者
+    pub fn pending_tile(&self) -> Option<(Tile, u8)> {
+        self.pending.as_ref().map(|p| (p.tile, p.from))
+    }
+
+    /// 本局结果，尚未结束时为`None`
+    pub fn outcome(&self) -> Option<&RoundOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// 和牌者的场况，按`RoundOutcome::Win`中和牌者的顺序排列；无人和牌时为空
+    ///
+    /// 血战到底时按和牌的先后排列，本局尚未结束时也会记录已经和牌的玩家
+    pub fn win_contexts(&self) -> &[WinContext] {
+        &self.win_contexts
+    }
+
+    /// 和牌者的得分，与`win_contexts`一一对应
+    pub fn win_scores(&self) -> &[WinScore] {
+        &self.win_scores
+    }
+
+    /// 本局各座位的点数变化(不含立直供托，含开杠时已结算的点数)，本局结束前为空
+    pub fn point_changes(&self) -> &[i32] {
+        &self.point_changes
+    }
+
+    /// 场上所有玩家的杠数之和
+    pub fn kan_count(&self) -> usize {
+        self.players
+            .iter()
+            .flat_map(|p| p.hand.melds())
+            .filter(|m| matches!(m.meld_type, MeldType::Kan(_)))
+            .count()
+    }
+
+    /// 某位玩家此刻的所有合法动作
+    ///
+    /// 不需要该玩家行动时返回空列表。牌形上和牌但不满足规则起和条件的自摸和荣和不会列出。
+    pub fn legal_actions(&self, seat: u8) -> Vec<Action> {
+        let mut actions = match self.view(seat) {
+            Some(view) => legal_actions(&view),
+            None if self.phase == Phase::Dealing && seat == self.current => vec![Action::Draw],
+            None => Vec::new(),
+        };
+        actions.retain(|action| match *action {
+            Action::Tsumo => self.players[seat as usize]
+                .hand
+                .drawn()
+                .is_some_and(|tile| self.rules.is_valid_win(&self.build_win_context(seat, None, tile, false))),
+            Action::Ron { tile } => {
+                self.furiten(seat).is_none()
+                    && self.pending.as_ref().is_some_and(|p| {
+                        self.rules.is_valid_win(&self.build_win_context(seat, Some(p.from), tile, p.added_kan))
+                    })
+            }
+            Action::Riichi { .. } => {
+                self.config.points.as_ref().is_none_or(|points| points[seat as usize] >= self.rules.riichi_deposit())
+            }
+            _ => true,
+        });
+        actions
+    }
+
+    /// 某位玩家此刻的振听状态，规则没有振听或不振听时返回`None`
+    pub fn furiten(&self, seat: u8) -> Option<Furiten> {
+        self.rules.furiten(self.players.get(seat as usize)?)
+    }
+
+    /// 构建某位玩家的视角，不需要该玩家行动时返回`None`
+    pub(crate) fn view(&self, seat: u8) -> Option<PlayerView<'_>> {
+        let player = self.players.get(seat as usize)?;
+        if player.won {
+            return None;
+        }
+        let situation = match self.phase {
+            Phase::Exchanging if self.exchanges[seat as usize].is_none() => Situation::Exchange,
+            Phase::ChoosingVoid if player.void_suit.is_none() => Situation::ChooseVoid,
+            Phase::WaitingDiscard if seat == self.current => match self.step {
+                TurnStep::NeedsDraw => Situation::Draw,
+                TurnStep::Drawn => Situation::Turn { after_call: false },
+                TurnStep::AfterCall => Situation::Turn { after_call: true },
+            },
+            Phase::WaitingCalls => {
+                let pending = self.pending.as_ref()?;
+                if seat == pending.from || pending.arbiter.has_responded(seat) {
+                    return None;
+                }
+                if pending.added_kan {
+                    Situation::AddedKan { tile: pending.tile, from: pending.from }
+                } else {
+                    Situation::Discard { tile: pending.tile, from: pending.from }
+                }
+            }
+            _ => return None,
+        };
+
+        let mut view = PlayerView::new(seat, &player.hand, situation, self.wall.remaining_tiles());
+        view.in_riichi = player.riichi;
+        view.first_turn = self.uninterrupted && player.discards.is_empty();
+        view.kan_count = self.kan_count();
+        view.void_suit = player.void_suit;
+        view.options = self.config.options;
+        view.win_shape = self.rules.win_shape();
+        Some(view)
+    }
+
+    /// 下一位尚未和牌的玩家的座位(血战到底时跳过已和牌的玩家)
+    pub(crate) fn next_seat(&self, seat: u8) -> u8 {
+        let count = self.config.options.player_count;
+        (1..=count).map(|offset| (seat + offset) % count).find(|&s| !self.players[s as usize].won).unwrap_or(seat)
+    }
+}
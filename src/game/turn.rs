@@ -0,0 +1,146 @@
+// src/game/turn.rs
+//
+// 回合流程中的鸣牌仲裁
+// 一张牌打出后，多位玩家可能同时要求荣和、碰、杠或吃。仲裁器收集所有玩家的宣言，
+// 按 荣和 > 碰/杠 > 吃 的优先级决定生效的宣言；同一优先级时，从打牌者起按座位顺序
+// (下家、对家、上家)越近越优先。多家荣和时按规则选择截和(头跳)或允许多家同时和牌。
+
+use crate::action::Action;
+use crate::errors::{MajiangError, MajiangResult};
+use crate::meld::KanType;
+
+/// 多家同时荣和时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RonPolicy {
+    /// 截和(头跳)：只有离打牌者最近的一家和牌
+    #[default]
+    HeadBump,
+    /// 所有宣言荣和的玩家都和牌
+    Multiple,
+}
+
+/// 一位玩家对打出的牌的宣言
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Claim {
+    /// 宣言者座位
+    pub seat: u8,
+    /// 宣言的动作(荣和、碰、杠或吃)
+    pub action: Action,
+}
+
+/// 仲裁结果
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ClaimResolution {
+    /// 生效的宣言，按从打牌者起的座位顺序排列；多家荣和时可能有多个，无人宣言时为空
+    pub winners: Vec<Claim>,
+    /// 被更高优先级宣言否决的宣言，按优先级和座位顺序排列
+    pub rejected: Vec<Claim>,
+}
+
+/// 鸣牌仲裁器
+///
+/// # 示例
+/// ```
+/// use majiang_core::action::Action;
+/// use majiang_core::game::{ClaimArbiter, RonPolicy};
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let tile = Tile::Suit(Suit::Dot, 5);
+/// let mut arbiter = ClaimArbiter::new(0, 4, RonPolicy::HeadBump);
+/// arbiter.submit(1, Action::Chi { tile, combination: [tile, Tile::Suit(Suit::Dot, 6), Tile::Suit(Suit::Dot, 7)] }).unwrap();
+/// arbiter.submit(2, Action::Pon { tile }).unwrap();
+/// arbiter.submit(3, Action::Pass).unwrap();
+///
+/// let resolution = arbiter.resolve();
+/// assert_eq!(resolution.winners[0].seat, 2);
+/// assert_eq!(resolution.rejected[0].seat, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimArbiter {
+    discarder: u8,
+    player_count: u8,
+    policy: RonPolicy,
+    claims: Vec<Claim>,
+    passed: Vec<u8>,
+}
+
+impl ClaimArbiter {
+    /// 创建仲裁器
+    ///
+    /// # 参数
+    /// * `discarder` - 打牌者座位
+    /// * `player_count` - 玩家人数
+    /// * `policy` - 多家荣和的处理方式
+    pub fn new(discarder: u8, player_count: u8, policy: RonPolicy) -> Self {
+        Self { discarder, player_count, policy, claims: Vec::new(), passed: Vec::new() }
+    }
+
+    /// 提交一位玩家的应对(宣言或`Pass`)
+    ///
+    /// # 错误
+    /// 打牌者本人、座位超出范围或重复提交时返回`InvalidOperation`；
+    /// 动作不是荣和、碰、杠、吃或`Pass`时返回`InvalidAction`
+    pub fn submit(&mut self, seat: u8, action: Action) -> MajiangResult<()> {
+        if seat >= self.player_count || seat == self.discarder {
+            return Err(MajiangError::InvalidOperation(format!("座位{}不能应对这张牌", seat)));
+        }
+        if self.has_responded(seat) {
+            return Err(MajiangError::InvalidOperation(format!("座位{}已经做出应对", seat)));
+        }
+        if action == Action::Pass {
+            self.passed.push(seat);
+            return Ok(());
+        }
+        if priority(&action).is_none() {
+            return Err(MajiangError::InvalidAction(format!("{}不是对打出牌的宣言", action)));
+        }
+        self.claims.push(Claim { seat, action });
+        Ok(())
+    }
+
+    /// 某位玩家是否已经提交应对
+    pub fn has_responded(&self, seat: u8) -> bool {
+        self.passed.contains(&seat) || self.claims.iter().any(|c| c.seat == seat)
+    }
+
+    /// 是否所有其他玩家都已提交应对
+    pub fn is_complete(&self) -> bool {
+        (0..self.player_count).filter(|&s| s != self.discarder).all(|s| self.has_responded(s))
+    }
+
+    /// 已提交的宣言(不含`Pass`)
+    pub fn claims(&self) -> &[Claim] {
+        &self.claims
+    }
+
+    /// 按优先级决定生效的宣言
+    ///
+    /// 尚未提交应对的玩家视为放弃。
+    pub fn resolve(self) -> ClaimResolution {
+        let distance = |seat: u8| (seat + self.player_count - self.discarder) % self.player_count;
+        let mut claims = self.claims;
+        claims.sort_by_key(|c| (std::cmp::Reverse(priority(&c.action)), distance(c.seat)));
+
+        let Some(first) = claims.first().copied() else {
+            return ClaimResolution::default();
+        };
+        let winner_count = match (first.action, self.policy) {
+            (Action::Ron { .. }, RonPolicy::Multiple) => {
+                claims.iter().filter(|c| matches!(c.action, Action::Ron { .. })).count()
+            }
+            _ => 1,
+        };
+        let rejected = claims.split_off(winner_count);
+        ClaimResolution { winners: claims, rejected }
+    }
+}
+
+/// 宣言的优先级，数值越大越优先；不是宣言的动作返回`None`
+fn priority(action: &Action) -> Option<u8> {
+    match action {
+        Action::Ron { .. } => Some(3),
+        Action::Pon { .. } | Action::Kan { kan_type: KanType::Open, .. } => Some(2),
+        Action::Chi { .. } => Some(1),
+        _ => None,
+    }
+}
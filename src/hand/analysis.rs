@@ -0,0 +1,649 @@
+// src/hand/analysis.rs
+//
+// 手牌分析：向听数计算、和牌张与和牌拆分
+// 向听数表示距离听牌还差几次有效摸牌，-1为和牌，0为听牌。
+// 分别计算三种和牌形的向听数并取最小值：
+// - 一般形：四组面子加一个雀头(已声明的副露计为面子)
+// - 七对子：七个不同的对子
+// - 国士无双：十三种幺九牌各一张，其中一种成对
+//
+// 一般形的计算按花色拆分：对每个花色(及字牌)用深度优先搜索列出所有可能的
+// (面子数, 搭子数, 雀头数)组合，再把各花色的组合合并求最小向听数。
+// 各花色的搜索互相独立，结果按牌型缓存，因此可以在模拟中大量调用。
+//
+// 和牌拆分列出加入和牌张后所有的面子/雀头组合，并判断每种拆分下的听牌形状
+// (两面、嵌张、边张、双碰、单骑)，供役种、符数和番种计算使用。
+// 在此基础上还判断打出哪些牌后听牌、暗杠是否改变听牌，供立直等动作的判定使用。
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::hand::joker::{is_complete_with_jokers, joker_shanten_breakdown};
+use crate::hand::representation::{Hand, TILE_KIND_COUNT};
+use crate::meld::KanType;
+use crate::tile::{Tile, COPIES_PER_KIND};
+
+/// 向听数，-1表示已和牌
+pub type Shanten = i8;
+
+/// 已和牌时的向听数
+pub const SHANTEN_COMPLETE: Shanten = -1;
+
+/// 一般形需要的面子数
+const SETS_NEEDED: usize = 4;
+
+/// 十三种幺九牌的种类ID
+const TERMINALS_AND_HONORS: [usize; 13] = [0, 8, 9, 17, 18, 26, 27, 28, 29, 30, 31, 32, 33];
+
+/// 各和牌形的向听数
+///
+/// 七对子和国士无双只能门前成立，有副露时为`None`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShantenBreakdown {
+    /// 一般形(四面子一雀头)的向听数
+    pub standard: Shanten,
+    /// 七对子的向听数
+    pub chiitoitsu: Option<Shanten>,
+    /// 国士无双的向听数
+    pub kokushi: Option<Shanten>,
+}
+
+impl ShantenBreakdown {
+    /// 三种和牌形中的最小向听数
+    pub fn min(&self) -> Shanten {
+        [Some(self.standard), self.chiitoitsu, self.kokushi]
+            .into_iter()
+            .flatten()
+            .min()
+            .unwrap_or(self.standard)
+    }
+}
+
+/// 计算手牌的向听数(三种和牌形中的最小值)
+///
+/// 使用暗手与摸牌的全部牌，已声明的副露计为完成的面子。
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{Hand, shanten};
+///
+/// let hand: Hand = "123m456p789s1122z".parse().unwrap();
+/// assert_eq!(shanten(&hand), 0);
+///
+/// let hand: Hand = "123m456p789s11122z".parse().unwrap();
+/// assert_eq!(shanten(&hand), -1);
+/// ```
+pub fn shanten(hand: &Hand) -> Shanten {
+    shanten_breakdown(hand).min()
+}
+
+/// 分别计算手牌在三种和牌形下的向听数
+///
+/// 手牌含百搭时，百搭作为万能牌处理，取所有替代方案中的最小值
+pub fn shanten_breakdown(hand: &Hand) -> ShantenBreakdown {
+    let counts = hand.tile_counts();
+    match hand.joker_count() {
+        0 => shanten_breakdown_from_counts(&counts, hand.melds().len()),
+        jokers => joker_shanten_breakdown(&counts, jokers as usize, hand.melds().len()),
+    }
+}
+
+/// 直接由计数数组计算各和牌形的向听数，适合在模拟中避免构造`Hand`
+///
+/// # 参数
+/// * `counts` - 暗手(含摸牌)的计数数组
+/// * `meld_count` - 已声明的副露数
+pub fn shanten_breakdown_from_counts(counts: &[u8; TILE_KIND_COUNT], meld_count: usize) -> ShantenBreakdown {
+    // 七对子和国士无双需要门前的13张牌，任何副露(包括暗杠)都使其不成立
+    let closed_forms = meld_count == 0;
+    ShantenBreakdown {
+        standard: standard_shanten(counts, meld_count),
+        chiitoitsu: closed_forms.then(|| chiitoitsu_shanten(counts)),
+        kokushi: closed_forms.then(|| kokushi_shanten(counts)),
+    }
+}
+
+/// 一般形(四面子一雀头)的向听数
+///
+/// # 参数
+/// * `counts` - 暗手(含摸牌)的计数数组
+/// * `meld_count` - 已声明的副露数
+pub fn standard_shanten(counts: &[u8; TILE_KIND_COUNT], meld_count: usize) -> Shanten {
+    let mut combined = BlockTable::single(Blocks::default());
+    for (start, len, sequences) in GROUPS {
+        let mut group = [0u8; 9];
+        group[..len].copy_from_slice(&counts[start..start + len]);
+        combined = combined.combine(&group_table(&mut group[..len], sequences));
+    }
+
+    // 向听数 = 8 - 2*面子数 - 搭子数 - 雀头数，面子与搭子合计不超过还需要的面子数
+    let sets_left = SETS_NEEDED.saturating_sub(meld_count);
+    let base = 2 * sets_left as Shanten;
+    combined
+        .iter()
+        .map(|blocks| {
+            let sets = blocks.sets.min(sets_left);
+            let partials = blocks.partials.min(sets_left - sets);
+            base - (2 * sets + partials + blocks.pair) as Shanten
+        })
+        .min()
+        .unwrap_or(base)
+}
+
+/// 七对子的向听数
+///
+/// 同种的四张牌只能算一个对子
+pub fn chiitoitsu_shanten(counts: &[u8; TILE_KIND_COUNT]) -> Shanten {
+    let pairs = counts.iter().filter(|&&c| c >= 2).count() as Shanten;
+    let kinds = counts.iter().filter(|&&c| c >= 1).count() as Shanten;
+    6 - pairs + (7 - kinds).max(0)
+}
+
+/// 国士无双的向听数
+pub fn kokushi_shanten(counts: &[u8; TILE_KIND_COUNT]) -> Shanten {
+    let kinds = TERMINALS_AND_HONORS.iter().filter(|&&i| counts[i] >= 1).count() as Shanten;
+    let has_pair = TERMINALS_AND_HONORS.iter().any(|&i| counts[i] >= 2);
+    13 - kinds - Shanten::from(has_pair)
+}
+
+/// 计数数组的分组：(起始ID, 长度, 能否组成顺子)
+const GROUPS: [(usize, usize, bool); 4] = [(0, 9, true), (9, 9, true), (18, 9, true), (27, 7, false)];
+
+/// 一组牌拆出的面子、搭子和雀头数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Blocks {
+    sets: usize,
+    partials: usize,
+    pair: usize,
+}
+
+/// 一组牌可达的拆分组合表
+///
+/// 对每个(面子数, 雀头数)只记录最多的搭子数：面子数相同时搭子越多向听数越小，
+/// 其余组合不会成为最优解，因此表的规模固定为 5x2。
+/// 面子数不超过4(14张牌最多4组)，雀头最多1个。
+#[derive(Debug, Clone, Copy)]
+struct BlockTable {
+    /// 最多的搭子数，`UNREACHABLE`表示该组合不可达
+    best: [[i8; 2]; SETS_NEEDED + 1],
+}
+
+/// 不可达组合的标记
+const UNREACHABLE: i8 = -1;
+
+impl Default for BlockTable {
+    fn default() -> Self {
+        Self { best: [[UNREACHABLE; 2]; SETS_NEEDED + 1] }
+    }
+}
+
+impl BlockTable {
+    /// 只包含一个组合的表
+    fn single(blocks: Blocks) -> Self {
+        let mut table = Self::default();
+        table.insert(blocks);
+        table
+    }
+
+    /// 记录一个组合
+    fn insert(&mut self, blocks: Blocks) {
+        let cell = &mut self.best[blocks.sets.min(SETS_NEEDED)][blocks.pair.min(1)];
+        *cell = (*cell).max(blocks.partials.min(i8::MAX as usize) as i8);
+    }
+
+    /// 遍历所有可达组合
+    fn iter(&self) -> impl Iterator<Item = Blocks> + '_ {
+        self.best.iter().enumerate().flat_map(|(sets, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &partials)| partials != UNREACHABLE)
+                .map(move |(pair, &partials)| Blocks { sets, partials: partials as usize, pair })
+        })
+    }
+
+    /// 合并两组牌的组合表，雀头总数不超过1
+    fn combine(&self, other: &Self) -> Self {
+        let mut result = Self::default();
+        for a in self.iter() {
+            for b in other.iter().filter(|b| a.pair + b.pair <= 1) {
+                result.insert(Blocks {
+                    sets: a.sets + b.sets,
+                    partials: a.partials + b.partials,
+                    pair: a.pair + b.pair,
+                });
+            }
+        }
+        result
+    }
+}
+
+thread_local! {
+    /// 各花色牌型的搜索结果缓存，键为牌型的五进制编码
+    ///
+    /// 单一花色的牌型数量有限，模拟中同一牌型会反复出现，缓存后大多数计算只需查表
+    static GROUP_CACHE: RefCell<HashMap<u32, BlockTable>> = RefCell::new(HashMap::new());
+}
+
+/// 计算一组牌的拆分组合表，优先查缓存
+fn group_table(group: &mut [u8], sequences: bool) -> BlockTable {
+    let key = group.iter().fold(u32::from(sequences), |acc, &c| acc * 5 + u32::from(c.min(4)));
+    if let Some(table) = GROUP_CACHE.with(|cache| cache.borrow().get(&key).copied()) {
+        return table;
+    }
+    let mut table = BlockTable::default();
+    search_group(group, 0, sequences, Blocks::default(), &mut table);
+    GROUP_CACHE.with(|cache| cache.borrow_mut().insert(key, table));
+    table
+}
+
+/// 对一组牌做深度优先搜索，记录所有拆分组合
+///
+/// 每一步处理第一张剩余的牌：取为刻子、顺子、雀头、搭子，或作为孤张舍去
+fn search_group(group: &mut [u8], start: usize, sequences: bool, blocks: Blocks, table: &mut BlockTable) {
+    let Some(i) = (start..group.len()).find(|&i| group[i] > 0) else {
+        table.insert(blocks);
+        return;
+    };
+    let len = group.len();
+
+    // 刻子
+    if group[i] >= 3 {
+        group[i] -= 3;
+        search_group(group, i, sequences, Blocks { sets: blocks.sets + 1, ..blocks }, table);
+        group[i] += 3;
+    }
+
+    // 顺子
+    if sequences && i + 2 < len && group[i + 1] > 0 && group[i + 2] > 0 {
+        take(group, &[i, i + 1, i + 2]);
+        search_group(group, i, sequences, Blocks { sets: blocks.sets + 1, ..blocks }, table);
+        restore(group, &[i, i + 1, i + 2]);
+    }
+
+    if group[i] >= 2 {
+        // 雀头
+        if blocks.pair == 0 {
+            group[i] -= 2;
+            search_group(group, i, sequences, Blocks { pair: 1, ..blocks }, table);
+            group[i] += 2;
+        }
+        // 对子作为搭子
+        group[i] -= 2;
+        search_group(group, i, sequences, Blocks { partials: blocks.partials + 1, ..blocks }, table);
+        group[i] += 2;
+    }
+
+    // 两面、边张和嵌张搭子
+    if sequences {
+        for gap in [1, 2] {
+            if i + gap < len && group[i + gap] > 0 {
+                take(group, &[i, i + gap]);
+                search_group(group, i, sequences, Blocks { partials: blocks.partials + 1, ..blocks }, table);
+                restore(group, &[i, i + gap]);
+            }
+        }
+    }
+
+    // 孤张
+    group[i] -= 1;
+    search_group(group, i, sequences, blocks, table);
+    group[i] += 1;
+}
+
+/// 从组中各取一张
+fn take(group: &mut [u8], indices: &[usize]) {
+    for &i in indices {
+        group[i] -= 1;
+    }
+}
+
+/// 把取出的牌放回组中
+fn restore(group: &mut [u8], indices: &[usize]) {
+    for &i in indices {
+        group[i] += 1;
+    }
+}
+
+/// 和牌形
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WinForm {
+    /// 一般形：四面子一雀头
+    Standard,
+    /// 七对子
+    Chiitoitsu,
+    /// 国士无双
+    Kokushi,
+    /// 全不靠：十四张互不相靠的单张(国标麻将的七星不靠和全不靠)，`decompositions`不会给出
+    HonorsAndKnitted,
+}
+
+/// 暗手中的面子，以种类(不区分红五)表示
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mentsu {
+    /// 顺子，记录最小的一张
+    Sequence(Tile),
+    /// 刻子
+    Triplet(Tile),
+}
+
+impl Mentsu {
+    /// 面子中的三张牌
+    pub fn tiles(&self) -> [Tile; 3] {
+        match *self {
+            Mentsu::Triplet(tile) => [tile; 3],
+            Mentsu::Sequence(tile) => {
+                let id = tile.to_id();
+                [tile, Tile::from_id(id + 1).unwrap_or(tile), Tile::from_id(id + 2).unwrap_or(tile)]
+            }
+        }
+    }
+
+    /// 面子是否包含某种牌
+    pub fn contains(&self, tile: Tile) -> bool {
+        self.tiles().iter().any(|t| t.is_same_kind(&tile))
+    }
+}
+
+/// 听牌的形状
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitType {
+    /// 两面：如23等1或4
+    Ryanmen,
+    /// 嵌张：如13等2
+    Kanchan,
+    /// 边张：12等3或89等7
+    Penchan,
+    /// 双碰：两个对子等其中之一成刻
+    Shanpon,
+    /// 单骑：等雀头
+    Tanki,
+}
+
+/// 和牌时手牌的一种拆分方式
+///
+/// 只包含暗手部分，已声明的副露保留在`Hand`中。牌均以种类表示，不区分红五。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decomposition {
+    /// 和牌形
+    pub form: WinForm,
+    /// 雀头；七对子为和牌张组成的对子，国士无双为成对的幺九牌
+    pub pair: Tile,
+    /// 暗手中的面子(含和牌张完成的面子)，七对子和国士无双为空
+    pub sets: Vec<Mentsu>,
+    /// 听牌形状
+    pub wait: WaitType,
+    /// 和牌张完成的面子在`sets`中的下标，单骑时为`None`
+    pub win_block: Option<usize>,
+}
+
+/// 列出听牌手牌的所有和牌张
+///
+/// 手牌应为13-3k张(不含摸牌)。手中和副露已有四张的牌不可能再摸到(空听)，不计入和牌张。
+/// 手牌含百搭时，百搭作为不受限制的万能牌处理。
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{Hand, winning_tiles};
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let hand: Hand = "123m456p789s23m11z".parse().unwrap();
+/// assert_eq!(winning_tiles(&hand), vec![
+///     Tile::Suit(Suit::Character, 1),
+///     Tile::Suit(Suit::Character, 4),
+/// ]);
+/// ```
+pub fn winning_tiles(hand: &Hand) -> Vec<Tile> {
+    let counts = hand.tile_counts();
+    let melds = hand.melds().len();
+    let jokers = hand.joker_count() as usize;
+    (0..TILE_KIND_COUNT)
+        .filter(|&id| counts[id] + melded_count(hand, id) < COPIES_PER_KIND)
+        .filter(|&id| {
+            let mut after = counts;
+            after[id] += 1;
+            is_complete_with_jokers(&after, jokers, melds)
+        })
+        .filter_map(|id| Tile::from_id(id as u8))
+        .collect()
+}
+
+/// 手牌加入`win_tile`后是否构成和牌形(一般形、七对子或国士无双)
+///
+/// 和牌张为百搭时作为万能牌处理。
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{is_winning_shape, Hand};
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let hand: Hand = "123m456p789s23m11z".parse().unwrap();
+/// assert!(is_winning_shape(&hand, Tile::Suit(Suit::Character, 4)));
+/// assert!(!is_winning_shape(&hand, Tile::Suit(Suit::Character, 5)));
+/// ```
+pub fn is_winning_shape(hand: &Hand, win_tile: Tile) -> bool {
+    let mut counts = hand.tile_counts();
+    let mut jokers = hand.joker_count() as usize;
+    if win_tile.is_joker() {
+        jokers += 1;
+    } else if let Some(id) = kind_slot(&win_tile) {
+        counts[id] += 1;
+    } else {
+        return false;
+    }
+    is_complete_with_jokers(&counts, jokers, hand.melds().len())
+}
+
+/// 列出加入和牌张后手牌的所有拆分方式
+///
+/// 同一组面子中和牌张位置不同时(如4567的4既可以是单骑也可以是两面)，分别作为不同的拆分给出。
+/// 不能和牌时返回空列表。本函数不处理百搭，含百搭的手牌请使用`joker_substitutions`。
+///
+/// # 参数
+/// * `hand` - 13-3k张的听牌手牌
+/// * `win_tile` - 和牌张
+pub fn decompositions(hand: &Hand, win_tile: Tile) -> Vec<Decomposition> {
+    let mut counts = hand.tile_counts();
+    let Some(win_id) = kind_slot(&win_tile) else {
+        return Vec::new();
+    };
+    counts[win_id] += 1;
+    decompose_counts(&mut counts, win_id, hand.melds().len())
+}
+
+/// 打出后能听牌的牌，按暗手顺序排列且不重复
+///
+/// 打出后必须有可以和的牌，和牌张都在自己手中的空听不算听牌。
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{parse_tiles, tenpai_discards, Hand};
+///
+/// // 打出白板后听1万和4万
+/// let hand: Hand = "123m456p789s11z23m5z".parse().unwrap();
+/// assert_eq!(tenpai_discards(&hand), parse_tiles("5z").unwrap());
+/// ```
+pub fn tenpai_discards(hand: &Hand) -> Vec<Tile> {
+    let mut tiles = hand.concealed_tiles();
+    tiles.extend(hand.drawn());
+    tiles.dedup();
+    let mut result: Vec<Tile> = Vec::new();
+    for tile in tiles {
+        if result.contains(&tile) {
+            continue;
+        }
+        let mut after = hand.clone();
+        if after.discard(tile).is_ok() && !winning_tiles(&after).is_empty() {
+            result.push(tile);
+        }
+    }
+    result
+}
+
+/// 暗杠刚摸到的`tile`是否不改变听牌和面子构成
+///
+/// 要求杠后听的牌不变，且每种听牌的所有拆分中这四张牌都作为刻子。
+/// `tile`不是刚摸到的牌时返回`false`。
+pub fn kan_keeps_waits(hand: &Hand, tile: Tile) -> bool {
+    let Some(drawn) = hand.drawn().filter(|d| d.is_same_kind(&tile)) else {
+        return false;
+    };
+    let mut before = hand.clone();
+    let mut after = hand.clone();
+    if before.discard(drawn).is_err() || after.declare_kan(tile, KanType::Closed, None).is_err() {
+        return false;
+    }
+    let waits = winning_tiles(&before);
+    waits == winning_tiles(&after)
+        && waits.iter().all(|&wait| {
+            decompositions(&before, wait)
+                .iter()
+                .all(|d| d.sets.iter().any(|m| *m == Mentsu::Triplet(tile.to_normal())))
+        })
+}
+
+/// 对已加入和牌张的计数数组列出所有拆分方式
+///
+/// # 参数
+/// * `counts` - 含和牌张的暗手计数数组，返回时保持不变
+/// * `win_id` - 和牌张的种类ID
+/// * `melds` - 已声明的副露数
+pub(crate) fn decompose_counts(counts: &mut [u8; TILE_KIND_COUNT], win_id: usize, melds: usize) -> Vec<Decomposition> {
+    let Some(win_tile) = Tile::from_id(win_id as u8) else {
+        return Vec::new();
+    };
+    let mut result = Vec::new();
+
+    // 一般形：枚举雀头，再从最小的牌开始拆出面子
+    let sets_needed = SETS_NEEDED.saturating_sub(melds);
+    for pair_id in 0..TILE_KIND_COUNT {
+        if counts[pair_id] < 2 {
+            continue;
+        }
+        counts[pair_id] -= 2;
+        let mut splits = Vec::new();
+        split_sets(counts, 0, &mut Vec::new(), &mut splits);
+        counts[pair_id] += 2;
+
+        let Some(pair) = Tile::from_id(pair_id as u8) else { continue };
+        for sets in splits.into_iter().filter(|sets| sets.len() == sets_needed) {
+            if pair_id == win_id {
+                push_unique(&mut result, Decomposition {
+                    form: WinForm::Standard, pair, sets: sets.clone(), wait: WaitType::Tanki, win_block: None,
+                });
+            }
+            // 相同的面子(如一杯口)只取第一个，避免给出仅下标不同的重复拆分
+            let completed = sets
+                .iter()
+                .enumerate()
+                .filter(|&(i, m)| m.contains(win_tile) && !sets[..i].contains(m));
+            for (index, mentsu) in completed {
+                push_unique(&mut result, Decomposition {
+                    form: WinForm::Standard,
+                    pair,
+                    sets: sets.clone(),
+                    wait: classify_wait(mentsu, win_tile),
+                    win_block: Some(index),
+                });
+            }
+        }
+    }
+
+    if melds == 0 {
+        if is_chiitoitsu(counts) {
+            result.push(Decomposition {
+                form: WinForm::Chiitoitsu, pair: win_tile, sets: Vec::new(), wait: WaitType::Tanki, win_block: None,
+            });
+        }
+        if kokushi_shanten(counts) == SHANTEN_COMPLETE {
+            let pair = TERMINALS_AND_HONORS
+                .iter()
+                .find(|&&id| counts[id] >= 2)
+                .and_then(|&id| Tile::from_id(id as u8))
+                .unwrap_or(win_tile);
+            result.push(Decomposition {
+                form: WinForm::Kokushi, pair, sets: Vec::new(), wait: WaitType::Tanki, win_block: None,
+            });
+        }
+    }
+
+    result
+}
+
+/// 判断计数数组是否已构成和牌形
+pub(crate) fn is_complete(counts: &[u8; TILE_KIND_COUNT], meld_count: usize) -> bool {
+    if standard_shanten(counts, meld_count) == SHANTEN_COMPLETE {
+        return true;
+    }
+    meld_count == 0 && (is_chiitoitsu(counts) || kokushi_shanten(counts) == SHANTEN_COMPLETE)
+}
+
+/// 七对子要求恰好七种牌各两张
+fn is_chiitoitsu(counts: &[u8; TILE_KIND_COUNT]) -> bool {
+    counts.iter().filter(|&&c| c == 2).count() == 7 && counts.iter().all(|&c| c == 0 || c == 2)
+}
+
+/// 副露中某种牌的张数
+fn melded_count(hand: &Hand, id: usize) -> u8 {
+    hand.melds()
+        .iter()
+        .flat_map(|m| &m.tiles)
+        .filter(|t| t.to_id() as usize == id)
+        .count() as u8
+}
+
+/// 牌在计数数组中的下标
+fn kind_slot(tile: &Tile) -> Option<usize> {
+    let id = tile.to_id() as usize;
+    (id < TILE_KIND_COUNT).then_some(id)
+}
+
+/// 从最小的剩余牌开始，把计数数组完全拆成面子，记录所有拆法
+fn split_sets(counts: &mut [u8; TILE_KIND_COUNT], start: usize, current: &mut Vec<Mentsu>, out: &mut Vec<Vec<Mentsu>>) {
+    let Some(i) = (start..TILE_KIND_COUNT).find(|&i| counts[i] > 0) else {
+        out.push(current.clone());
+        return;
+    };
+    let Some(tile) = Tile::from_id(i as u8) else { return };
+
+    if counts[i] >= 3 {
+        counts[i] -= 3;
+        current.push(Mentsu::Triplet(tile));
+        split_sets(counts, i, current, out);
+        current.pop();
+        counts[i] += 3;
+    }
+
+    // 顺子只能由同一花色的数牌组成，起点不能超过7
+    let starts_sequence = i < 27 && i % 9 <= 6;
+    if starts_sequence && counts[i + 1] > 0 && counts[i + 2] > 0 {
+        take(counts, &[i, i + 1, i + 2]);
+        current.push(Mentsu::Sequence(tile));
+        split_sets(counts, i, current, out);
+        current.pop();
+        restore(counts, &[i, i + 1, i + 2]);
+    }
+}
+
+/// 根据和牌张在面子中的位置判断听牌形状
+fn classify_wait(mentsu: &Mentsu, win_tile: Tile) -> WaitType {
+    match *mentsu {
+        Mentsu::Triplet(_) => WaitType::Shanpon,
+        Mentsu::Sequence(low) => {
+            let position = win_tile.to_id().saturating_sub(low.to_id());
+            let low_number = low.suit_and_number().map_or(0, |(_, n)| n);
+            match position {
+                1 => WaitType::Kanchan,
+                0 if low_number == 7 => WaitType::Penchan,
+                2 if low_number == 1 => WaitType::Penchan,
+                _ => WaitType::Ryanmen,
+            }
+        }
+    }
+}
+
+/// 加入拆分结果，忽略重复项(如一杯口中两个相同顺子给出的同一拆分)
+fn push_unique(result: &mut Vec<Decomposition>, decomposition: Decomposition) {
+    if !result.contains(&decomposition) {
+        result.push(decomposition);
+    }
+}
@@ -0,0 +1,207 @@
+// src/hand/efficiency.rs
+//
+// 牌效率(进张)分析
+// 对14张(含副露时为14-3k张)的手牌，逐一尝试打出每种牌，计算打出后的向听数、
+// 能使向听数前进的进张种类，以及这些进张在场上还剩多少张未见。
+//
+// 未见张数 = 4 - 自己手中和副露中的张数 - 场上可见的张数(河、他家副露、宝牌指示牌)。
+// 自己的手牌和副露由`Hand`提供，其余可见的牌由调用方通过`VisibleTiles`传入。
+// 手牌中的百搭作为万能牌计算向听数，但百搭本身不是进张。
+
+use crate::errors::{MajiangError, MajiangResult};
+use crate::hand::analysis::{shanten_breakdown_from_counts, Shanten};
+use crate::hand::joker::joker_shanten_breakdown;
+use crate::hand::representation::{Hand, TILE_KIND_COUNT};
+use crate::meld::Meld;
+use crate::tile::{Tile, COPIES_PER_KIND};
+
+/// 场上可见的牌
+///
+/// 包括所有玩家的河、其他玩家的副露和宝牌指示牌。
+/// 自己的手牌和副露已包含在`Hand`中，不应再加入，否则会被重复计算。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisibleTiles {
+    counts: [u8; TILE_KIND_COUNT],
+}
+
+impl Default for VisibleTiles {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VisibleTiles {
+    /// 创建空的可见牌记录
+    pub fn new() -> Self {
+        Self { counts: [0; TILE_KIND_COUNT] }
+    }
+
+    /// 记录一张可见的牌，花牌和百搭不影响进张计算，会被忽略
+    pub fn add(&mut self, tile: Tile) {
+        let id = tile.to_id() as usize;
+        if id < TILE_KIND_COUNT {
+            self.counts[id] = self.counts[id].saturating_add(1).min(COPIES_PER_KIND);
+        }
+    }
+
+    /// 记录一组可见的牌(如某位玩家的河或宝牌指示牌)
+    pub fn add_tiles(&mut self, tiles: &[Tile]) {
+        for &tile in tiles {
+            self.add(tile);
+        }
+    }
+
+    /// 记录其他玩家的副露
+    pub fn add_melds(&mut self, melds: &[Meld]) {
+        for meld in melds {
+            self.add_tiles(&meld.tiles);
+        }
+    }
+
+    /// 某种牌的可见张数(按种类计算，红五计入五)
+    pub fn count(&self, tile: Tile) -> u8 {
+        let id = tile.to_id() as usize;
+        if id < TILE_KIND_COUNT {
+            self.counts[id]
+        } else {
+            0
+        }
+    }
+}
+
+/// 一手13-3k张手牌的进张情况
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ukeire {
+    /// 当前向听数
+    pub shanten: Shanten,
+    /// 能使向听数前进的牌，按种类ID排序
+    pub tiles: Vec<Tile>,
+    /// 这些牌中尚未见到的总张数
+    pub unseen: usize,
+}
+
+/// 打出某张牌后的牌效率
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscardOption {
+    /// 打出的牌(红五与普通五分别列出)
+    pub discard: Tile,
+    /// 打出后的进张情况
+    pub ukeire: Ukeire,
+}
+
+/// 计算13-3k张手牌的进张
+///
+/// # 错误
+/// 手牌张数不是13-3k时返回`InvalidOperation`
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{Hand, VisibleTiles, ukeire};
+///
+/// let hand: Hand = "123m456p789s23m11z".parse().unwrap();
+/// let result = ukeire(&hand, &VisibleTiles::new()).unwrap();
+/// assert_eq!(result.shanten, 0);
+/// assert_eq!(result.unseen, 7);
+/// ```
+pub fn ukeire(hand: &Hand, visible: &VisibleTiles) -> MajiangResult<Ukeire> {
+    if hand.concealed_count() % 3 != 1 {
+        return Err(MajiangError::InvalidOperation(format!(
+            "计算进张需要13-3k张手牌，当前为{}张", hand.concealed_count()
+        )));
+    }
+    let owned = owned_counts(hand);
+    Ok(ukeire_from_counts(hand.tile_counts(), hand.joker_count() as usize, hand.melds().len(), &owned, visible))
+}
+
+/// 对14-3k张手牌的每种打法计算牌效率，按优劣排序
+///
+/// 排序规则：打出后向听数小者优先，其次未见进张多者优先，最后按牌的种类ID。
+///
+/// # 错误
+/// 手牌张数不是14-3k时返回`InvalidOperation`
+pub fn discard_efficiency(hand: &Hand, visible: &VisibleTiles) -> MajiangResult<Vec<DiscardOption>> {
+    if hand.concealed_count() % 3 != 2 {
+        return Err(MajiangError::InvalidOperation(format!(
+            "计算打牌效率需要14-3k张手牌，当前为{}张", hand.concealed_count()
+        )));
+    }
+
+    // 打出的牌进入河中，仍算作已见，因此未见张数按打牌前的手牌计算
+    let owned = owned_counts(hand);
+    let counts = hand.tile_counts();
+    let jokers = hand.joker_count() as usize;
+    let melds = hand.melds().len();
+
+    let mut candidates = hand.all_concealed_tiles();
+    candidates.sort_by_key(|t| (t.to_id(), !t.is_red()));
+    candidates.dedup();
+
+    let mut options: Vec<DiscardOption> = candidates
+        .into_iter()
+        .map(|discard| {
+            let mut after = counts;
+            let mut jokers_after = jokers;
+            let id = discard.to_id() as usize;
+            if id < TILE_KIND_COUNT {
+                after[id] -= 1;
+            } else if discard.is_joker() {
+                jokers_after -= 1;
+            }
+            DiscardOption { discard, ukeire: ukeire_from_counts(after, jokers_after, melds, &owned, visible) }
+        })
+        .collect();
+
+    options.sort_by_key(|o| (o.ukeire.shanten, std::cmp::Reverse(o.ukeire.unseen), o.discard.to_id()));
+    Ok(options)
+}
+
+/// 由计数数组计算进张
+fn ukeire_from_counts(
+    mut counts: [u8; TILE_KIND_COUNT],
+    jokers: usize,
+    meld_count: usize,
+    owned: &[u8; TILE_KIND_COUNT],
+    visible: &VisibleTiles,
+) -> Ukeire {
+    let shanten = min_shanten(&counts, jokers, meld_count);
+    let mut tiles = Vec::new();
+    let mut unseen = 0;
+
+    for id in 0..TILE_KIND_COUNT {
+        // 自己已持有四张的牌不可能再摸到
+        if owned[id] >= COPIES_PER_KIND {
+            continue;
+        }
+        counts[id] += 1;
+        let improved = min_shanten(&counts, jokers, meld_count) < shanten;
+        counts[id] -= 1;
+
+        if let Some(tile) = Tile::from_id(id as u8).filter(|_| improved) {
+            let seen = owned[id] + visible.counts[id];
+            unseen += COPIES_PER_KIND.saturating_sub(seen) as usize;
+            tiles.push(tile);
+        }
+    }
+
+    Ukeire { shanten, tiles, unseen }
+}
+
+/// 三种和牌形中最小的向听数，百搭作为万能牌处理
+fn min_shanten(counts: &[u8; TILE_KIND_COUNT], jokers: usize, meld_count: usize) -> Shanten {
+    match jokers {
+        0 => shanten_breakdown_from_counts(counts, meld_count).min(),
+        jokers => joker_shanten_breakdown(counts, jokers, meld_count).min(),
+    }
+}
+
+/// 自己手中(含摸牌)与副露中每种牌的张数
+fn owned_counts(hand: &Hand) -> [u8; TILE_KIND_COUNT] {
+    let mut owned = hand.tile_counts();
+    for tile in hand.melds().iter().flat_map(|m| &m.tiles) {
+        let id = tile.to_id() as usize;
+        if id < TILE_KIND_COUNT {
+            owned[id] += 1;
+        }
+    }
+    owned
+}
@@ -0,0 +1,237 @@
+// src/hand/joker.rs
+//
+// 百搭牌(万能牌)的手牌分析
+// 上海麻将等规则中百搭可以代替任意一张牌。本模块枚举百搭的所有具体替代方案，
+// 对每种方案复用一般的向听数和和牌拆分计算，并按地方规则过滤不允许的用法。
+//
+// 替代方案按多重集枚举(两张百搭代替1万和2万与代替2万和1万视为同一方案)，
+// 且替代后同种牌不超过4张。百搭数量通常只有1张，枚举规模很小；
+// 4张百搭时约为4.6万种方案，仍可在毫秒级完成。
+
+use crate::hand::analysis::{
+    decompose_counts, is_complete, shanten_breakdown_from_counts, Decomposition, Mentsu,
+    ShantenBreakdown, WinForm,
+};
+use crate::hand::representation::{Hand, TILE_KIND_COUNT};
+use crate::tile::{Tile, COPIES_PER_KIND};
+
+/// 百搭使用的地方规则限制
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JokerRules {
+    /// 百搭能否充当雀头(及七对子、国士无双的对子)中的牌
+    pub in_pair: bool,
+    /// 百搭能否充当顺子中的牌
+    pub in_sequence: bool,
+}
+
+impl Default for JokerRules {
+    /// 默认不做任何限制
+    fn default() -> Self {
+        Self { in_pair: true, in_sequence: true }
+    }
+}
+
+/// 百搭的一种具体替代方案及其和牌拆分
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JokerSubstitution {
+    /// 每张百搭代替的牌，按种类ID排序；和牌张为百搭时也包含在内
+    pub tiles: Vec<Tile>,
+    /// 替代后的和牌拆分
+    pub decomposition: Decomposition,
+}
+
+/// 列出含百搭手牌和牌时的所有替代方案与拆分
+///
+/// 和牌张本身也可以是百搭。违反`rules`限制的拆分会被排除。
+/// 手牌不含百搭时，结果与`decompositions`一致(替代牌为空)。
+///
+/// # 参数
+/// * `hand` - 13-3k张的听牌手牌
+/// * `win_tile` - 和牌张
+/// * `rules` - 百搭使用限制
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{Hand, JokerRules, joker_substitutions};
+/// use majiang_core::tile::{Tile, Wind};
+///
+/// // 百搭代替雀头中的一张东风
+/// let hand: Hand = "123m456p789s123s0z".parse().unwrap();
+/// let east = Tile::Wind(Wind::East);
+/// assert!(!joker_substitutions(&hand, east, &JokerRules::default()).is_empty());
+///
+/// // 规则不允许百搭作雀头时不能和牌
+/// let strict = JokerRules { in_pair: false, ..JokerRules::default() };
+/// assert!(joker_substitutions(&hand, east, &strict).is_empty());
+/// ```
+pub fn joker_substitutions(hand: &Hand, win_tile: Tile, rules: &JokerRules) -> Vec<JokerSubstitution> {
+    let mut natural = hand.tile_counts();
+    let win_is_joker = win_tile.is_joker();
+    if !win_is_joker {
+        let id = win_tile.to_id() as usize;
+        if id >= TILE_KIND_COUNT {
+            return Vec::new();
+        }
+        natural[id] += 1;
+    }
+
+    let melds = hand.melds().len();
+    let mut result: Vec<JokerSubstitution> = Vec::new();
+    for_each_substitution(&natural, hand.joker_count() as usize, &mut |counts, chosen| {
+        let mut counts = *counts;
+        let win_ids: Vec<usize> = if win_is_joker {
+            (0..TILE_KIND_COUNT).filter(|&id| counts[id] < COPIES_PER_KIND).collect()
+        } else {
+            vec![win_tile.to_id() as usize]
+        };
+
+        for win_id in win_ids {
+            let mut tiles: Vec<usize> = chosen.to_vec();
+            if win_is_joker {
+                counts[win_id] += 1;
+                tiles.push(win_id);
+                tiles.sort_unstable();
+            }
+            let decompositions = decompose_counts(&mut counts, win_id, melds);
+            for decomposition in decompositions {
+                if !allowed(&decomposition, &counts, &natural, rules) {
+                    continue;
+                }
+                let substitution = JokerSubstitution {
+                    tiles: tiles.iter().filter_map(|&id| Tile::from_id(id as u8)).collect(),
+                    decomposition,
+                };
+                if !result.contains(&substitution) {
+                    result.push(substitution);
+                }
+            }
+            if win_is_joker {
+                counts[win_id] -= 1;
+            }
+        }
+    });
+    result
+}
+
+/// 在所有替代方案中找出得分最高的一种
+///
+/// 得分由调用方按所用规则计算(如番数或点数)。得分相同时返回枚举顺序中较早的方案，
+/// 因此结果是确定的。
+///
+/// # 返回
+/// 不能和牌时返回`None`
+pub fn best_substitution<F>(hand: &Hand, win_tile: Tile, rules: &JokerRules, mut score: F) -> Option<JokerSubstitution>
+where
+    F: FnMut(&JokerSubstitution) -> i64,
+{
+    let mut best: Option<(i64, JokerSubstitution)> = None;
+    for substitution in joker_substitutions(hand, win_tile, rules) {
+        let value = score(&substitution);
+        if best.as_ref().is_none_or(|(top, _)| value > *top) {
+            best = Some((value, substitution));
+        }
+    }
+    best.map(|(_, substitution)| substitution)
+}
+
+/// 含百搭时各和牌形的向听数，取所有替代方案中的最小值
+///
+/// 向听数只反映牌形，不考虑`JokerRules`的限制
+pub(crate) fn joker_shanten_breakdown(
+    counts: &[u8; TILE_KIND_COUNT],
+    jokers: usize,
+    meld_count: usize,
+) -> ShantenBreakdown {
+    let mut best = shanten_breakdown_from_counts(counts, meld_count);
+    for_each_substitution(counts, jokers, &mut |substituted, _| {
+        let current = shanten_breakdown_from_counts(substituted, meld_count);
+        best.standard = best.standard.min(current.standard);
+        best.chiitoitsu = min_option(best.chiitoitsu, current.chiitoitsu);
+        best.kokushi = min_option(best.kokushi, current.kokushi);
+    });
+    best
+}
+
+/// 是否存在使手牌和牌的替代方案
+pub(crate) fn is_complete_with_jokers(counts: &[u8; TILE_KIND_COUNT], jokers: usize, meld_count: usize) -> bool {
+    let mut complete = false;
+    for_each_substitution(counts, jokers, &mut |substituted, _| {
+        complete = complete || is_complete(substituted, meld_count);
+    });
+    complete
+}
+
+/// 枚举`jokers`张百搭的所有替代方案(按种类ID不减的多重集)
+///
+/// 回调参数为替代后的计数数组与各百搭代替的种类ID
+fn for_each_substitution<F>(counts: &[u8; TILE_KIND_COUNT], jokers: usize, f: &mut F)
+where
+    F: FnMut(&[u8; TILE_KIND_COUNT], &[usize]),
+{
+    let mut counts = *counts;
+    let mut chosen = Vec::with_capacity(jokers);
+    substitute(&mut counts, 0, jokers, &mut chosen, f);
+}
+
+/// 递归地为剩余的百搭选择代替的牌
+fn substitute<F>(counts: &mut [u8; TILE_KIND_COUNT], start: usize, left: usize, chosen: &mut Vec<usize>, f: &mut F)
+where
+    F: FnMut(&[u8; TILE_KIND_COUNT], &[usize]),
+{
+    if left == 0 {
+        f(counts, chosen);
+        return;
+    }
+    for id in start..TILE_KIND_COUNT {
+        if counts[id] >= COPIES_PER_KIND {
+            continue;
+        }
+        counts[id] += 1;
+        chosen.push(id);
+        substitute(counts, id, left - 1, chosen, f);
+        chosen.pop();
+        counts[id] -= 1;
+    }
+}
+
+/// 检查拆分中百搭的用法是否符合规则
+///
+/// 同种的百搭与真牌可以互换，因此只需检查每种牌受限位置所需的真牌数是否足够：
+/// 刻子总能容纳百搭，受限的对子和顺子必须由真牌组成。
+fn allowed(
+    decomposition: &Decomposition,
+    counts: &[u8; TILE_KIND_COUNT],
+    natural: &[u8; TILE_KIND_COUNT],
+    rules: &JokerRules,
+) -> bool {
+    let mut needed = [0u8; TILE_KIND_COUNT];
+    match decomposition.form {
+        WinForm::Standard => {
+            if !rules.in_pair {
+                needed[decomposition.pair.to_id() as usize] += 2;
+            }
+            if !rules.in_sequence {
+                for mentsu in &decomposition.sets {
+                    if let Mentsu::Sequence(_) = mentsu {
+                        for tile in mentsu.tiles() {
+                            needed[tile.to_id() as usize] += 1;
+                        }
+                    }
+                }
+            }
+        }
+        // 七对子的每个对子都受雀头限制
+        WinForm::Chiitoitsu if !rules.in_pair => needed = *counts,
+        WinForm::Kokushi if !rules.in_pair => needed[decomposition.pair.to_id() as usize] = 2,
+        _ => {}
+    }
+    needed.iter().zip(natural).all(|(need, have)| need <= have)
+}
+
+/// 两个可选向听数中的较小者
+fn min_option<T: Ord>(a: Option<T>, b: Option<T>) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
@@ -0,0 +1,29 @@
+// src/hand/mod.rs
+//
+// 手牌模块的入口文件，负责导出手牌相关的类型和功能函数
+
+mod parser;
+mod representation;
+mod analysis;
+mod efficiency;
+mod joker;
+
+// 导出手牌记法的解析与格式化功能
+pub use parser::{ParsedHand, parse_tiles, parse_hand, format_tiles, format_meld, format_hand};
+
+// 导出手牌表示
+pub use representation::{Hand, TILE_KIND_COUNT, FULL_HAND_SIZE};
+
+// 导出手牌分析功能
+pub use analysis::{
+    Shanten, ShantenBreakdown, SHANTEN_COMPLETE,
+    shanten, shanten_breakdown, shanten_breakdown_from_counts, standard_shanten, chiitoitsu_shanten, kokushi_shanten,
+    WinForm, Mentsu, WaitType, Decomposition, winning_tiles, is_winning_shape, decompositions, tenpai_discards, kan_keeps_waits,
+};
+pub(crate) use analysis::decompose_counts;
+
+// 导出牌效率分析功能
+pub use efficiency::{VisibleTiles, Ukeire, DiscardOption, ukeire, discard_efficiency};
+
+// 导出百搭相关分析功能
+pub use joker::{JokerRules, JokerSubstitution, joker_substitutions, best_substitution};
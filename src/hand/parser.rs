@@ -0,0 +1,389 @@
+// src/hand/parser.rs
+//
+// 手牌的MPSZ简写记法解析与格式化
+// MPSZ是麻将社区通用的文本记法，如"123m456p789s11z"，便于在日志、测试和牌谱中书写手牌。
+//
+// 记法约定：
+// - 数字后跟花色字母，同一花色的连续数字共用一个字母：m=万 p=筒 s=索 z=字
+// - 字牌 1z-7z 依次为 东南西北白发中
+// - 红五写作 0m/0p/0s
+// - 扩展：花牌写作 1f-8f，依次为 春夏秋冬梅兰竹菊；百搭写作 0z
+// - 副露用括号包围：[...] 为明副露(吃/碰/明杠)，(...) 为暗杠，{...} 为加杠
+//   例如 [555p] 为碰，[345m] 为吃，[7777z] 为明杠，(1111s) 为暗杠
+// - 空白字符会被忽略
+//
+// 解析错误统一返回 MajiangError::InvalidTile，错误信息中带有出错位置(从1开始的列号)。
+
+use crate::errors::{MajiangError, MajiangResult};
+use crate::meld::{validate_chi, validate_kan, validate_pon, KanType, Meld, MeldSource, MeldType};
+use crate::tile::{Dragon, Flower, Suit, Tile, Wind};
+
+/// 解析出的手牌：暗手中的牌与已声明的副露
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ParsedHand {
+    /// 暗手中的牌，保持书写顺序
+    pub tiles: Vec<Tile>,
+    /// 副露，保持书写顺序
+    pub melds: Vec<Meld>,
+}
+
+/// 副露括号的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Bracket {
+    /// [...] 明副露
+    Open,
+    /// (...) 暗杠
+    Closed,
+    /// {...} 加杠
+    Added,
+}
+
+impl Bracket {
+    /// 根据左括号判断括号种类
+    fn from_open(c: char) -> Option<Self> {
+        match c {
+            '[' => Some(Bracket::Open),
+            '(' => Some(Bracket::Closed),
+            '{' => Some(Bracket::Added),
+            _ => None,
+        }
+    }
+
+    /// 对应的右括号
+    fn close_char(self) -> char {
+        match self {
+            Bracket::Open => ']',
+            Bracket::Closed => ')',
+            Bracket::Added => '}',
+        }
+    }
+}
+
+/// 生成带列号的解析错误
+fn parse_error(column: usize, message: &str) -> MajiangError {
+    MajiangError::InvalidTile(format!("第{}列: {}", column, message))
+}
+
+/// 解析一组只包含牌(不含副露)的MPSZ记法
+///
+/// # 参数
+/// * `notation` - MPSZ记法，如"123m456p0s11z"
+///
+/// # 错误
+/// 记法非法或包含副露时返回`InvalidTile`，信息中带有出错列号
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::parse_tiles;
+/// use majiang_core::tile::{Tile, Suit, Wind};
+///
+/// let tiles = parse_tiles("120m1z").unwrap();
+/// assert_eq!(tiles, vec![
+///     Tile::Suit(Suit::Character, 1),
+///     Tile::Suit(Suit::Character, 2),
+///     Tile::new_red_five(Suit::Character),
+///     Tile::Wind(Wind::East),
+/// ]);
+/// ```
+pub fn parse_tiles(notation: &str) -> MajiangResult<Vec<Tile>> {
+    if let Some((column, _)) = notation.chars().enumerate().find(|(_, c)| Bracket::from_open(*c).is_some()) {
+        return Err(parse_error(column + 1, "牌串中不允许出现副露"));
+    }
+    parse_hand(notation).map(|parsed| parsed.tiles)
+}
+
+/// 解析包含副露的完整手牌记法
+///
+/// 解析结果不包含座位信息：明副露中第一张书写的牌视为被鸣的牌，
+/// 其来源统一记为`MeldSource::Player(0)`，需要时由调用方修正。
+///
+/// # 参数
+/// * `notation` - MPSZ记法，如"123m456p[555z](1111s)"
+///
+/// # 错误
+/// 记法非法或副露不成立时返回`InvalidTile`，信息中带有出错列号
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::parse_hand;
+/// use majiang_core::meld::MeldType;
+///
+/// let parsed = parse_hand("123m456p11z[555p]").unwrap();
+/// assert_eq!(parsed.tiles.len(), 8);
+/// assert_eq!(parsed.melds[0].meld_type, MeldType::Pon);
+/// ```
+pub fn parse_hand(notation: &str) -> MajiangResult<ParsedHand> {
+    let chars: Vec<char> = notation.chars().collect();
+    let mut parsed = ParsedHand::default();
+    let mut pending: Vec<(char, usize)> = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() {
+            pending.push((c, column));
+            i += 1;
+        } else if let Some(bracket) = Bracket::from_open(c) {
+            if let Some(&(_, col)) = pending.first() {
+                return Err(parse_error(col, "数字缺少花色字母"));
+            }
+            let (meld, next) = parse_meld(&chars, i, bracket)?;
+            parsed.melds.push(meld);
+            i = next;
+        } else if is_suffix(c) {
+            if pending.is_empty() {
+                return Err(parse_error(column, &format!("花色字母'{}'前缺少数字", c)));
+            }
+            for &(digit, col) in &pending {
+                parsed.tiles.push(digit_to_tile(digit, c, col)?);
+            }
+            pending.clear();
+            i += 1;
+        } else {
+            return Err(parse_error(column, &format!("无法识别的字符'{}'", c)));
+        }
+    }
+
+    if let Some(&(_, col)) = pending.first() {
+        return Err(parse_error(col, "数字缺少花色字母"));
+    }
+
+    Ok(parsed)
+}
+
+/// 解析从`start`位置开始的一个括号副露
+///
+/// # 返回
+/// 解析出的副露，以及右括号之后的下一个字符位置
+fn parse_meld(chars: &[char], start: usize, bracket: Bracket) -> MajiangResult<(Meld, usize)> {
+    let close = bracket.close_char();
+    let mut digits: Vec<(char, usize)> = Vec::new();
+    let mut tiles = Vec::new();
+    let mut i = start + 1;
+
+    loop {
+        let Some(&c) = chars.get(i) else {
+            return Err(parse_error(start + 1, &format!("副露缺少右括号'{}'", close)));
+        };
+        let column = i + 1;
+
+        if c == close {
+            break;
+        } else if c.is_whitespace() {
+            // 忽略空白
+        } else if c.is_ascii_digit() {
+            digits.push((c, column));
+        } else if is_suffix(c) {
+            if digits.is_empty() {
+                return Err(parse_error(column, &format!("花色字母'{}'前缺少数字", c)));
+            }
+            for &(digit, col) in &digits {
+                tiles.push(digit_to_tile(digit, c, col)?);
+            }
+            digits.clear();
+        } else {
+            return Err(parse_error(column, &format!("副露中无法识别的字符'{}'", c)));
+        }
+        i += 1;
+    }
+
+    if let Some(&(_, col)) = digits.first() {
+        return Err(parse_error(col, "数字缺少花色字母"));
+    }
+
+    let meld = build_meld(tiles, bracket).map_err(|e| parse_error(start + 1, &e.to_string()))?;
+    Ok((meld, i + 1))
+}
+
+/// 根据括号种类和牌组构造副露
+fn build_meld(tiles: Vec<Tile>, bracket: Bracket) -> MajiangResult<Meld> {
+    let count = tiles.len();
+    let claimed = MeldSource::Player(0);
+
+    let (meld_type, sources) = match (bracket, count) {
+        (Bracket::Open, 3) if validate_pon(&tiles).is_ok() => {
+            (MeldType::Pon, open_sources(claimed, 3))
+        }
+        (Bracket::Open, 3) => {
+            validate_chi(&tiles)?;
+            (MeldType::Chi, open_sources(claimed, 3))
+        }
+        (Bracket::Open, 4) => {
+            let sources = open_sources(claimed, 4);
+            validate_kan(&tiles, KanType::Open, &sources)?;
+            (MeldType::Kan(KanType::Open), sources)
+        }
+        (Bracket::Closed, 4) => {
+            let sources = vec![MeldSource::SelfDrawn; 4];
+            validate_kan(&tiles, KanType::Closed, &sources)?;
+            (MeldType::Kan(KanType::Closed), sources)
+        }
+        (Bracket::Added, 4) => {
+            let sources = open_sources(claimed, 4);
+            validate_kan(&tiles, KanType::Added, &sources)?;
+            (MeldType::Kan(KanType::Added), sources)
+        }
+        (Bracket::Open, _) => {
+            return Err(MajiangError::InvalidMeld(format!("明副露需要3或4张牌，但提供了{}张", count)));
+        }
+        _ => {
+            return Err(MajiangError::InvalidMeld(format!("杠需要4张牌，但提供了{}张", count)));
+        }
+    };
+
+    Meld::new(tiles, meld_type, sources)
+}
+
+/// 明副露的来源：第一张为被鸣的牌，其余为自己的牌
+fn open_sources(claimed: MeldSource, count: usize) -> Vec<MeldSource> {
+    let mut sources = vec![MeldSource::SelfDrawn; count];
+    sources[0] = claimed;
+    sources
+}
+
+/// 判断是否为花色字母
+fn is_suffix(c: char) -> bool {
+    matches!(c, 'm' | 'p' | 's' | 'z' | 'f')
+}
+
+/// 将一个数字和花色字母转换为牌
+fn digit_to_tile(digit: char, suffix: char, column: usize) -> MajiangResult<Tile> {
+    let n = digit.to_digit(10).unwrap_or(0) as u8;
+
+    let tile = match suffix {
+        'm' | 'p' | 's' => {
+            let suit = match suffix {
+                'm' => Suit::Character,
+                'p' => Suit::Dot,
+                _ => Suit::Bamboo,
+            };
+            if n == 0 {
+                Some(Tile::new_red_five(suit))
+            } else {
+                Tile::new_suit(suit, n)
+            }
+        }
+        'z' => match n {
+            0 => Some(Tile::Joker),
+            1 => Some(Tile::Wind(Wind::East)),
+            2 => Some(Tile::Wind(Wind::South)),
+            3 => Some(Tile::Wind(Wind::West)),
+            4 => Some(Tile::Wind(Wind::North)),
+            5 => Some(Tile::Dragon(Dragon::White)),
+            6 => Some(Tile::Dragon(Dragon::Green)),
+            7 => Some(Tile::Dragon(Dragon::Red)),
+            _ => None,
+        },
+        'f' => FLOWER_ORDER.get((n as usize).wrapping_sub(1)).map(|&f| Tile::Flower(f)),
+        _ => None,
+    };
+
+    tile.ok_or_else(|| parse_error(column, &format!("'{}{}'不是有效的牌", digit, suffix)))
+}
+
+/// 花牌在记法中的顺序
+const FLOWER_ORDER: [Flower; 8] = [
+    Flower::Spring, Flower::Summer, Flower::Autumn, Flower::Winter,
+    Flower::Plum, Flower::Orchid, Flower::Bamboo, Flower::Chrysanthemum,
+];
+
+/// 将一张牌转换为(数字, 花色字母)
+fn tile_to_digit(tile: &Tile) -> (char, char) {
+    let digit = |n: u8| char::from(b'0' + n);
+    match *tile {
+        Tile::Suit(Suit::Character, n) => (digit(n), 'm'),
+        Tile::Suit(Suit::Dot, n) => (digit(n), 'p'),
+        Tile::Suit(Suit::Bamboo, n) => (digit(n), 's'),
+        Tile::RedFive(Suit::Character) => ('0', 'm'),
+        Tile::RedFive(Suit::Dot) => ('0', 'p'),
+        Tile::RedFive(Suit::Bamboo) => ('0', 's'),
+        Tile::Wind(wind) => {
+            let n = match wind {
+                Wind::East => 1,
+                Wind::South => 2,
+                Wind::West => 3,
+                Wind::North => 4,
+            };
+            (digit(n), 'z')
+        }
+        Tile::Dragon(dragon) => {
+            let n = match dragon {
+                Dragon::White => 5,
+                Dragon::Green => 6,
+                Dragon::Red => 7,
+            };
+            (digit(n), 'z')
+        }
+        Tile::Flower(flower) => {
+            let index = FLOWER_ORDER.iter().position(|&f| f == flower).unwrap_or(0);
+            (digit(index as u8 + 1), 'f')
+        }
+        Tile::Joker => ('0', 'z'),
+    }
+}
+
+/// 将一组牌格式化为MPSZ记法
+///
+/// 保持牌的原有顺序，相邻的同花色牌共用一个花色字母。
+/// 格式化结果可以被`parse_tiles`无损解析回原牌组。
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::format_tiles;
+/// use majiang_core::tile::{Tile, Suit, Dragon};
+///
+/// let tiles = vec![
+///     Tile::Suit(Suit::Dot, 1),
+///     Tile::new_red_five(Suit::Dot),
+///     Tile::Dragon(Dragon::Red),
+/// ];
+/// assert_eq!(format_tiles(&tiles), "10p7z");
+/// ```
+pub fn format_tiles(tiles: &[Tile]) -> String {
+    let mut result = String::with_capacity(tiles.len() * 2);
+    let mut current_suffix: Option<char> = None;
+
+    for tile in tiles {
+        let (digit, suffix) = tile_to_digit(tile);
+        if let Some(prev) = current_suffix {
+            if prev != suffix {
+                result.push(prev);
+            }
+        }
+        result.push(digit);
+        current_suffix = Some(suffix);
+    }
+
+    if let Some(suffix) = current_suffix {
+        result.push(suffix);
+    }
+
+    result
+}
+
+/// 将一个副露格式化为带括号的MPSZ记法
+///
+/// 明副露(吃、碰、明杠)用[]，暗杠用()，加杠用{}
+pub fn format_meld(meld: &Meld) -> String {
+    let (open, close) = match meld.meld_type {
+        MeldType::Chi | MeldType::Pon | MeldType::Kan(KanType::Open) => ('[', ']'),
+        MeldType::Kan(KanType::Closed) => ('(', ')'),
+        MeldType::Kan(KanType::Added) => ('{', '}'),
+    };
+    format!("{}{}{}", open, format_tiles(&meld.tiles), close)
+}
+
+/// 将暗手和副露格式化为完整的手牌记法
+///
+/// 暗手在前，副露依次在后，如"123m456p11z[555p]"
+pub fn format_hand(tiles: &[Tile], melds: &[Meld]) -> String {
+    let mut result = format_tiles(tiles);
+    for meld in melds {
+        result.push_str(&format_meld(meld));
+    }
+    result
+}
@@ -0,0 +1,583 @@
+// src/hand/representation.rs
+//
+// 定义手牌的内部表示
+// 暗手以34格计数数组保存(每格对应一种牌的种类ID)，便于向听、听牌等分析快速遍历；
+// 红五、百搭、花牌不占用计数格或需要额外信息，单独记录。
+// 摸到的牌单独保存，打牌时才并入暗手，便于区分摸切与手切。
+//
+// 所有修改操作都会检查手牌是否仍然可能存在：
+// - 同一种牌(暗手、摸牌和副露合计)不超过4张
+// - 暗手与摸牌合计不超过 14 - 3*副露数 张
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::errors::{MajiangError, MajiangResult};
+use crate::hand::parser::{format_hand, parse_hand};
+use crate::meld::{
+    can_form_chi, can_form_kan, can_form_pon, validate_chi, validate_kan, validate_pon,
+    KanType, Meld, MeldSource, MeldType,
+};
+use crate::tile::{Suit, Tile, COPIES_PER_KIND};
+
+/// 计数数组的格数，即不含花牌和百搭的牌种类数
+pub const TILE_KIND_COUNT: usize = 34;
+
+/// 一手完整的牌(含摸牌)在没有副露时的张数
+pub const FULL_HAND_SIZE: usize = 14;
+
+/// 三种数牌花色，与红五计数数组的下标一一对应
+const SUITS: [Suit; 3] = [Suit::Character, Suit::Dot, Suit::Bamboo];
+
+/// 玩家的手牌
+///
+/// 包含暗手、摸到的牌、副露和已经亮出的花牌。
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::Hand;
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let mut hand: Hand = "123m456p789s1122z".parse().unwrap();
+/// hand.draw(Tile::Suit(Suit::Dot, 7)).unwrap();
+/// assert_eq!(hand.concealed_count(), 14);
+///
+/// // 摸切
+/// assert!(hand.discard(Tile::Suit(Suit::Dot, 7)).unwrap());
+/// assert_eq!(hand.concealed_count(), 13);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    /// 暗手中每种牌的张数(不含摸牌)，下标为牌的种类ID
+    counts: [u8; TILE_KIND_COUNT],
+    /// 暗手中每种花色红五的张数(不含摸牌)，已包含在counts中
+    red_fives: [u8; 3],
+    /// 暗手中百搭的张数(不含摸牌)
+    jokers: u8,
+    /// 刚摸到、尚未并入暗手的牌
+    drawn: Option<Tile>,
+    /// 已声明的副露
+    melds: Vec<Meld>,
+    /// 已亮出的花牌
+    flowers: Vec<Tile>,
+    /// 已拔出的北(三人麻将)
+    nuki: Vec<Tile>,
+}
+
+impl Default for Hand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hand {
+    /// 创建空手牌
+    pub fn new() -> Self {
+        Self {
+            counts: [0; TILE_KIND_COUNT],
+            red_fives: [0; 3],
+            jokers: 0,
+            drawn: None,
+            melds: Vec::new(),
+            flowers: Vec::new(),
+            nuki: Vec::new(),
+        }
+    }
+
+    /// 由一组牌创建手牌，所有牌都放入暗手，花牌直接亮出
+    ///
+    /// # 错误
+    /// 牌数超过一手牌的上限或同种牌超过4张时返回`InvalidOperation`
+    pub fn from_tiles(tiles: &[Tile]) -> MajiangResult<Self> {
+        let mut hand = Self::new();
+        for &tile in tiles {
+            if tile.is_flower() {
+                hand.add_flower(tile)?;
+            } else {
+                hand.add_tile(tile)?;
+            }
+        }
+        Ok(hand)
+    }
+
+    /// 暗手计数数组(不含摸牌)
+    pub fn counts(&self) -> &[u8; TILE_KIND_COUNT] {
+        &self.counts
+    }
+
+    /// 暗手与摸牌合计的计数数组，分析功能应使用此数组
+    ///
+    /// 百搭不计入数组，可通过`joker_count`获取
+    pub fn tile_counts(&self) -> [u8; TILE_KIND_COUNT] {
+        let mut counts = self.counts;
+        if let Some(tile) = self.drawn {
+            if let Some(slot) = kind_index(&tile) {
+                counts[slot] += 1;
+            }
+        }
+        counts
+    }
+
+    /// 刚摸到的牌
+    pub fn drawn(&self) -> Option<Tile> {
+        self.drawn
+    }
+
+    /// 已声明的副露
+    pub fn melds(&self) -> &[Meld] {
+        &self.melds
+    }
+
+    /// 已亮出的花牌
+    pub fn flowers(&self) -> &[Tile] {
+        &self.flowers
+    }
+
+    /// 已拔出的北(三人麻将的拔北宝牌)
+    pub fn nuki(&self) -> &[Tile] {
+        &self.nuki
+    }
+
+    /// 暗手与摸牌中百搭的张数
+    pub fn joker_count(&self) -> u8 {
+        self.jokers + u8::from(self.drawn == Some(Tile::Joker))
+    }
+
+    /// 暗手与摸牌合计的张数
+    pub fn concealed_count(&self) -> usize {
+        self.counts.iter().map(|&c| c as usize).sum::<usize>()
+            + self.jokers as usize
+            + usize::from(self.drawn.is_some())
+    }
+
+    /// 暗手与摸牌中某种牌的张数(按种类计算，红五计入五)
+    pub fn count_of(&self, tile: Tile) -> u8 {
+        if tile.is_joker() {
+            return self.joker_count();
+        }
+        kind_index(&tile).map_or(0, |slot| self.tile_counts()[slot])
+    }
+
+    /// 暗手、摸牌和副露中红五的总张数
+    pub fn red_five_count(&self) -> usize {
+        let concealed: usize = self.red_fives.iter().map(|&c| c as usize).sum();
+        let drawn = usize::from(self.drawn.is_some_and(|t| t.is_red()));
+        let melded = self.melds.iter().flat_map(|m| &m.tiles).filter(|t| t.is_red()).count();
+        concealed + drawn + melded
+    }
+
+    /// 是否门前清(没有明副露，暗杠不破坏门清)
+    pub fn is_closed(&self) -> bool {
+        self.melds.iter().all(|m| !m.is_open())
+    }
+
+    /// 暗手中的牌(不含摸牌)，按种类ID排序，红五排在同种普通五之前，百搭在最后
+    pub fn concealed_tiles(&self) -> Vec<Tile> {
+        let mut tiles = Vec::with_capacity(FULL_HAND_SIZE);
+        for (slot, &count) in self.counts.iter().enumerate() {
+            let Some(tile) = Tile::from_id(slot as u8) else { continue };
+            let red = red_index(&tile).map_or(0, |i| self.red_fives[i]);
+            if let Some((suit, _)) = tile.suit_and_number().filter(|_| red > 0) {
+                tiles.extend(std::iter::repeat_n(Tile::new_red_five(suit), red as usize));
+            }
+            tiles.extend(std::iter::repeat_n(tile, (count - red) as usize));
+        }
+        tiles.extend(std::iter::repeat_n(Tile::Joker, self.jokers as usize));
+        tiles
+    }
+
+    /// 暗手与摸牌中的所有牌，摸牌在最后
+    pub fn all_concealed_tiles(&self) -> Vec<Tile> {
+        let mut tiles = self.concealed_tiles();
+        tiles.extend(self.drawn);
+        tiles
+    }
+
+    /// 向暗手加入一张牌
+    ///
+    /// # 错误
+    /// 花牌应使用`add_flower`；牌数超出上限时返回`InvalidOperation`
+    pub fn add_tile(&mut self, tile: Tile) -> MajiangResult<()> {
+        if tile.is_flower() {
+            return Err(MajiangError::InvalidTile(format!("{}是花牌，不能放入暗手", tile)));
+        }
+        self.check_can_hold(tile, 1)?;
+        self.insert(tile);
+        Ok(())
+    }
+
+    /// 从暗手(或摸牌)中移除一张牌，红五与普通五区分对待
+    ///
+    /// # 错误
+    /// 手中没有这张牌时返回`TileNotFound`
+    pub fn remove_tile(&mut self, tile: Tile) -> MajiangResult<()> {
+        if self.take(tile) {
+            Ok(())
+        } else if self.drawn == Some(tile) {
+            self.drawn = None;
+            Ok(())
+        } else {
+            Err(MajiangError::TileNotFound)
+        }
+    }
+
+    /// 亮出一张花牌
+    pub fn add_flower(&mut self, tile: Tile) -> MajiangResult<()> {
+        if !tile.is_flower() {
+            return Err(MajiangError::InvalidTile(format!("{}不是花牌", tile)));
+        }
+        if self.flowers.contains(&tile) {
+            return Err(MajiangError::InvalidOperation(format!("花牌{}已经亮出", tile)));
+        }
+        self.flowers.push(tile);
+        Ok(())
+    }
+
+    /// 从暗手(或摸牌)中拔出一张牌放在一旁，摸牌并入暗手
+    ///
+    /// 用于三人麻将的拔北，之后应摸一张补牌。
+    ///
+    /// # 错误
+    /// 手中没有这张牌时返回`TileNotFound`
+    pub fn declare_nuki(&mut self, tile: Tile) -> MajiangResult<()> {
+        self.discard(tile)?;
+        self.nuki.push(tile);
+        Ok(())
+    }
+
+    /// 摸一张牌，作为摸牌单独保存
+    ///
+    /// # 错误
+    /// 已经持有摸牌，或摸牌后牌数超出上限时返回`InvalidOperation`
+    pub fn draw(&mut self, tile: Tile) -> MajiangResult<()> {
+        if let Some(drawn) = self.drawn {
+            return Err(MajiangError::InvalidOperation(format!("已经摸了{}，需要先打出一张牌", drawn)));
+        }
+        if !tile.is_flower() {
+            self.check_can_hold(tile, 1)?;
+        }
+        self.drawn = Some(tile);
+        Ok(())
+    }
+
+    /// 打出一张牌
+    ///
+    /// 打出摸牌即为摸切；打出暗手中的牌时，摸牌并入暗手。
+    ///
+    /// # 返回
+    /// 是否为摸切
+    ///
+    /// # 错误
+    /// 手中没有这张牌时返回`TileNotFound`
+    pub fn discard(&mut self, tile: Tile) -> MajiangResult<bool> {
+        if self.drawn == Some(tile) {
+            self.drawn = None;
+            return Ok(true);
+        }
+        if !self.take(tile) {
+            return Err(MajiangError::TileNotFound);
+        }
+        self.merge_drawn();
+        Ok(false)
+    }
+
+    /// 吃牌
+    ///
+    /// # 参数
+    /// * `claimed` - 上家打出的牌
+    /// * `own` - 自己手中用于组成顺子的两张牌
+    /// * `from` - 打出这张牌的玩家座位
+    ///
+    /// # 错误
+    /// 持有摸牌、手中没有对应的牌或不能组成顺子时返回`InvalidMeld`
+    pub fn declare_chi(&mut self, claimed: Tile, own: [Tile; 2], from: u8) -> MajiangResult<()> {
+        self.check_no_drawn("吃")?;
+        if !can_form_chi(&self.concealed_tiles(), claimed) {
+            return Err(MajiangError::InvalidMeld(format!("手牌无法吃{}", claimed)));
+        }
+        let tiles = vec![claimed, own[0], own[1]];
+        validate_chi(&tiles)?;
+        self.check_melded_kind(claimed)?;
+
+        self.take_all(&own)?;
+        let sources = vec![MeldSource::Player(from), MeldSource::SelfDrawn, MeldSource::SelfDrawn];
+        self.melds.push(Meld::new(tiles, MeldType::Chi, sources)?);
+        Ok(())
+    }
+
+    /// 碰牌，从暗手中取出两张同种牌(优先使用普通牌)
+    ///
+    /// # 错误
+    /// 持有摸牌或手中同种牌不足两张时返回`InvalidMeld`
+    pub fn declare_pon(&mut self, claimed: Tile, from: u8) -> MajiangResult<()> {
+        self.check_no_drawn("碰")?;
+        if !can_form_pon(&self.concealed_tiles(), claimed) {
+            return Err(MajiangError::InvalidMeld(format!("手牌无法碰{}", claimed)));
+        }
+        self.check_melded_kind(claimed)?;
+
+        let own = self.pick_same_kind(claimed, 2);
+        let tiles = vec![claimed, own[0], own[1]];
+        validate_pon(&tiles)?;
+
+        self.take_all(&own)?;
+        let sources = vec![MeldSource::Player(from), MeldSource::SelfDrawn, MeldSource::SelfDrawn];
+        self.melds.push(Meld::new(tiles, MeldType::Pon, sources)?);
+        Ok(())
+    }
+
+    /// 杠牌
+    ///
+    /// * 明杠：`tile`为他人打出的牌，需要暗手中有三张同种牌，`from`为打出者座位
+    /// * 暗杠：需要暗手与摸牌中共有四张同种牌
+    /// * 加杠：需要已有同种牌的碰，并在暗手或摸牌中持有第四张
+    ///
+    /// 暗杠和加杠后未用到的摸牌并入暗手，之后应摸岭上牌。
+    ///
+    /// # 错误
+    /// 条件不满足时返回`InvalidMeld`
+    pub fn declare_kan(&mut self, tile: Tile, kan_type: KanType, from: Option<u8>) -> MajiangResult<()> {
+        match kan_type {
+            KanType::Open => self.declare_open_kan(tile, from),
+            KanType::Closed => self.declare_closed_kan(tile),
+            KanType::Added => self.declare_added_kan(tile),
+        }
+    }
+
+    /// 加入一个已成立的副露(如从记法解析得到的副露)
+    ///
+    /// # 错误
+    /// 加入后牌数超出上限时返回`InvalidOperation`
+    pub fn add_meld(&mut self, meld: Meld) -> MajiangResult<()> {
+        let melded = self.melds.len() + 1;
+        if self.concealed_count() + 3 * melded > FULL_HAND_SIZE {
+            return Err(MajiangError::InvalidOperation("副露数量与暗手张数合计超出一手牌的上限".to_string()));
+        }
+        for &tile in &meld.tiles {
+            let in_meld = meld.tiles.iter().filter(|t| t.is_same_kind(&tile)).count();
+            let total = self.count_of(tile) as usize + self.melded_count(tile) + in_meld;
+            if total > COPIES_PER_KIND as usize {
+                return Err(too_many_copies(tile));
+            }
+        }
+        self.melds.push(meld);
+        Ok(())
+    }
+
+    /// 明杠
+    fn declare_open_kan(&mut self, claimed: Tile, from: Option<u8>) -> MajiangResult<()> {
+        let from = from.ok_or_else(|| MajiangError::InvalidMeld("明杠需要指定打出者座位".to_string()))?;
+        self.check_no_drawn("明杠")?;
+        if !can_form_kan(&self.concealed_tiles(), claimed, false) {
+            return Err(MajiangError::InvalidMeld(format!("手牌无法明杠{}", claimed)));
+        }
+        self.check_melded_kind(claimed)?;
+
+        let own = self.pick_same_kind(claimed, 3);
+        let mut tiles = vec![claimed];
+        tiles.extend(&own);
+        let mut sources = vec![MeldSource::SelfDrawn; 4];
+        sources[0] = MeldSource::Player(from);
+        validate_kan(&tiles, KanType::Open, &sources)?;
+
+        self.take_all(&own)?;
+        self.melds.push(Meld::new(tiles, MeldType::Kan(KanType::Open), sources)?);
+        Ok(())
+    }
+
+    /// 暗杠
+    fn declare_closed_kan(&mut self, tile: Tile) -> MajiangResult<()> {
+        let all = self.all_concealed_tiles();
+        let tiles: Vec<Tile> = all.iter().copied().filter(|t| t.is_same_kind(&tile)).collect();
+        if tiles.len() < 4 || !can_form_kan(&all, tile, true) {
+            return Err(MajiangError::InvalidMeld(format!("手牌中没有四张{}，无法暗杠", tile)));
+        }
+        let sources = vec![MeldSource::SelfDrawn; 4];
+        validate_kan(&tiles, KanType::Closed, &sources)?;
+
+        for &t in &tiles {
+            self.remove_tile(t)?;
+        }
+        self.merge_drawn();
+        self.melds.push(Meld::new(tiles, MeldType::Kan(KanType::Closed), sources)?);
+        Ok(())
+    }
+
+    /// 加杠
+    fn declare_added_kan(&mut self, tile: Tile) -> MajiangResult<()> {
+        let index = self
+            .melds
+            .iter()
+            .position(|m| m.meld_type == MeldType::Pon && m.contains(tile))
+            .ok_or_else(|| MajiangError::InvalidMeld(format!("没有{}的碰，无法加杠", tile)))?;
+        let added = self
+            .all_concealed_tiles()
+            .into_iter()
+            .find(|t| t.is_same_kind(&tile))
+            .ok_or_else(|| MajiangError::InvalidMeld(format!("手中没有{}，无法加杠", tile)))?;
+
+        let pon = &self.melds[index];
+        let mut tiles = pon.tiles.clone();
+        tiles.push(added);
+        let mut sources = pon.sources.clone();
+        sources.push(MeldSource::SelfDrawn);
+        validate_kan(&tiles, KanType::Added, &sources)?;
+
+        self.remove_tile(added)?;
+        self.merge_drawn();
+        self.melds[index] = Meld::new(tiles, MeldType::Kan(KanType::Added), sources)?;
+        Ok(())
+    }
+
+    /// 检查再加入`extra`张`tile`后手牌是否仍然可能存在
+    fn check_can_hold(&self, tile: Tile, extra: usize) -> MajiangResult<()> {
+        let limit = FULL_HAND_SIZE - 3 * self.melds.len();
+        if self.concealed_count() + extra > limit {
+            return Err(MajiangError::InvalidOperation(format!(
+                "手牌最多{}张(含摸牌)，无法再加入{}", limit, tile
+            )));
+        }
+        if !tile.is_joker() {
+            let total = self.count_of(tile) as usize + self.melded_count(tile) + extra;
+            if total > COPIES_PER_KIND as usize {
+                return Err(too_many_copies(tile));
+            }
+        }
+        Ok(())
+    }
+
+    /// 鸣牌后同种牌总数不能超过4张(被鸣的牌原本不在手中)
+    fn check_melded_kind(&self, claimed: Tile) -> MajiangResult<()> {
+        let total = self.count_of(claimed) as usize + self.melded_count(claimed) + 1;
+        if total > COPIES_PER_KIND as usize {
+            return Err(too_many_copies(claimed));
+        }
+        Ok(())
+    }
+
+    /// 鸣牌时不能持有摸牌
+    fn check_no_drawn(&self, action: &str) -> MajiangResult<()> {
+        match self.drawn {
+            Some(drawn) => Err(MajiangError::InvalidMeld(format!("持有摸牌{}时不能{}", drawn, action))),
+            None => Ok(()),
+        }
+    }
+
+    /// 副露中某种牌的张数
+    fn melded_count(&self, tile: Tile) -> usize {
+        self.melds
+            .iter()
+            .flat_map(|m| &m.tiles)
+            .filter(|t| t.is_same_kind(&tile))
+            .count()
+    }
+
+    /// 从暗手中挑选`n`张同种牌，普通牌优先
+    fn pick_same_kind(&self, tile: Tile, n: usize) -> Vec<Tile> {
+        let mut same: Vec<Tile> = self
+            .concealed_tiles()
+            .into_iter()
+            .filter(|t| t.is_same_kind(&tile))
+            .collect();
+        same.sort_by_key(|t| t.is_red());
+        same.truncate(n);
+        same
+    }
+
+    /// 从暗手中移除一组牌，任意一张不存在时不做任何修改
+    fn take_all(&mut self, tiles: &[Tile]) -> MajiangResult<()> {
+        let mut trial = self.clone();
+        for &tile in tiles {
+            if !trial.take(tile) {
+                return Err(MajiangError::TileNotFound);
+            }
+        }
+        *self = trial;
+        Ok(())
+    }
+
+    /// 将摸牌并入暗手
+    fn merge_drawn(&mut self) {
+        if let Some(tile) = self.drawn.take() {
+            self.insert(tile);
+        }
+    }
+
+    /// 向暗手计数中加入一张牌，不做检查
+    fn insert(&mut self, tile: Tile) {
+        if tile.is_joker() {
+            self.jokers += 1;
+            return;
+        }
+        if let Some(slot) = kind_index(&tile) {
+            self.counts[slot] += 1;
+            if let Some(i) = red_index(&tile).filter(|_| tile.is_red()) {
+                self.red_fives[i] += 1;
+            }
+        }
+    }
+
+    /// 从暗手计数中取出一张牌，红五与普通五区分对待
+    fn take(&mut self, tile: Tile) -> bool {
+        if tile.is_joker() {
+            if self.jokers == 0 {
+                return false;
+            }
+            self.jokers -= 1;
+            return true;
+        }
+        let Some(slot) = kind_index(&tile) else { return false };
+        let red = red_index(&tile).map_or(0, |i| self.red_fives[i]);
+        let available = if tile.is_red() { red } else { self.counts[slot] - red };
+        if available == 0 {
+            return false;
+        }
+        self.counts[slot] -= 1;
+        if let Some(i) = red_index(&tile).filter(|_| tile.is_red()) {
+            self.red_fives[i] -= 1;
+        }
+        true
+    }
+}
+
+/// 牌在计数数组中的下标，花牌和百搭没有下标
+fn kind_index(tile: &Tile) -> Option<usize> {
+    let id = tile.to_id() as usize;
+    (id < TILE_KIND_COUNT).then_some(id)
+}
+
+/// 五在红五计数数组中的下标，其他牌没有下标
+fn red_index(tile: &Tile) -> Option<usize> {
+    match tile.suit_and_number() {
+        Some((suit, 5)) => SUITS.iter().position(|&s| s == suit),
+        _ => None,
+    }
+}
+
+/// 同种牌超过4张的错误
+fn too_many_copies(tile: Tile) -> MajiangError {
+    MajiangError::InvalidOperation(format!("{}超过{}张", tile, COPIES_PER_KIND))
+}
+
+/// 从MPSZ记法解析手牌，所有牌都放入暗手
+impl FromStr for Hand {
+    type Err = MajiangError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = parse_hand(s)?;
+        let mut hand = Self::from_tiles(&parsed.tiles)?;
+        for meld in parsed.melds {
+            hand.add_meld(meld)?;
+        }
+        Ok(hand)
+    }
+}
+
+/// 以MPSZ记法显示手牌，摸牌接在暗手之后
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tiles = self.all_concealed_tiles();
+        tiles.extend(&self.flowers);
+        write!(f, "{}", format_hand(&tiles, &self.melds))
+    }
+}
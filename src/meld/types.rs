@@ -4,13 +4,13 @@
 // 这些类型构成了麻将游戏中副露操作的数据模型
 
 use crate::errors::{MajiangError, MajiangResult};
-use crate::tile::Tile;
+use crate::tile::{Tile, PhysicalTile};
 
 /// 牌的来源，用于标识副露中每张牌的来源
 /// 
 /// 在麻将游戏中，副露的牌可能来自自己摸到的牌，也可能来自其他玩家打出的牌。
 /// 这个枚举用于追踪每张牌的具体来源，对于判断副露的合法性和计分至关重要。
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MeldSource {
     /// 自己摸到的牌
     /// 
@@ -27,7 +27,7 @@ pub enum MeldSource {
 /// 杠的类型
 /// 
 /// 麻将中的杠有三种不同类型，它们的形成方式、显示方式和计分方式各不相同。
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum KanType {
     /// 明杠
     /// 
@@ -51,7 +51,7 @@ pub enum KanType {
 /// 副露的类型
 /// 
 /// 麻将中的副露分为三种基本类型：吃、碰、杠。其中杠又进一步细分为明杠、暗杠和加杠。
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MeldType {
     /// 吃
     /// 
@@ -87,6 +87,12 @@ pub struct Meld {
     
     /// 牌的来源，与tiles数组一一对应
     pub sources: Vec<MeldSource>,
+    
+    /// 组成副露的实体牌，与tiles数组一一对应
+    /// 
+    /// 仅在副露由实体牌创建时存在(如对局中从牌墙摸到的牌)，
+    /// 由牌面直接创建的副露此项为空。
+    pub instances: Vec<PhysicalTile>,
 }
 
 impl Meld {
@@ -155,9 +161,31 @@ impl Meld {
         Ok(Self { 
             tiles, 
             meld_type, 
-            sources 
+            sources,
+            instances: Vec::new(),
         })
     }
+    
+    /// 由实体牌创建副露实例
+    /// 
+    /// 与`new`的验证规则相同，同时保留每张牌的实体编号，
+    /// 牌谱记录可据此区分副露用的是哪一张牌(例如是否为红五)。
+    /// 
+    /// # 参数
+    /// 
+    /// * `instances` - 组成副露的实体牌
+    /// * `meld_type` - 副露类型（吃、碰、杠）
+    /// * `sources` - 每张牌的来源
+    /// 
+    /// # 错误
+    /// 
+    /// 与`new`相同
+    pub fn from_instances(instances: Vec<PhysicalTile>, meld_type: MeldType, sources: Vec<MeldSource>) -> MajiangResult<Self> {
+        let tiles = instances.iter().map(|t| t.tile()).collect();
+        let mut meld = Self::new(tiles, meld_type, sources)?;
+        meld.instances = instances;
+        Ok(meld)
+    }
 
     /// 获取副露中的关键牌
     /// 
@@ -186,6 +214,8 @@ impl Meld {
     
     /// 检查这个副露是否包含特定的牌
     /// 
+    /// 按种类比较，查询普通五时包含红五的副露也会返回true。
+    /// 
     /// # 参数
     /// 
     /// * `tile` - 要查找的牌
@@ -194,7 +224,7 @@ impl Meld {
     /// 
     /// 如果副露中包含该牌，则返回true，否则返回false
     pub fn contains(&self, tile: Tile) -> bool {
-        self.tiles.contains(&tile)
+        self.tiles.iter().any(|t| t.is_same_kind(&tile))
     }
     
     /// 判断这个副露是否是明的（可见的）
@@ -69,8 +69,9 @@ pub fn validate_chi(tiles: &[Tile]) -> MajiangResult<()> {
     let mut suits = Vec::new();
     let mut numbers = Vec::new();
     
+    // 红五按普通五参与判定，与普通五视为同一点数
     for tile in tiles {
-        if let Tile::Suit(suit, num) = *tile {
+        if let Some((suit, num)) = tile.suit_and_number() {
             suits.push(suit);
             numbers.push(num);
         }
@@ -96,7 +97,7 @@ pub fn validate_chi(tiles: &[Tile]) -> MajiangResult<()> {
 /// 
 /// 检查给定的牌组是否构成有效的碰组合。在麻将规则中，碰必须满足以下条件：
 /// 1. 由三张牌组成
-/// 2. 所有牌必须完全相同
+/// 2. 所有牌必须是同一种牌（红五与普通五视为同一种）
 /// 
 /// # 参数
 /// 
@@ -137,9 +138,9 @@ pub fn validate_pon(tiles: &[Tile]) -> MajiangResult<()> {
         return Err(MajiangError::InvalidMeld("碰必须由三张牌组成".to_string()));
     }
 
-    // 检查所有牌是否相同
+    // 检查所有牌是否相同（按种类比较，允许红五与普通五混用）
     let first_tile = tiles[0];
-    if !tiles.iter().all(|&t| t == first_tile) {
+    if !tiles.iter().all(|t| t.is_same_kind(&first_tile)) {
         return Err(MajiangError::InvalidMeld("碰必须由三张相同的牌组成".to_string()));
     }
 
@@ -150,7 +151,7 @@ pub fn validate_pon(tiles: &[Tile]) -> MajiangResult<()> {
 /// 
 /// 检查给定的牌组是否构成有效的杠组合。在麻将规则中，杠必须满足以下条件：
 /// 1. 由四张牌组成
-/// 2. 所有牌必须完全相同
+/// 2. 所有牌必须是同一种牌（红五与普通五视为同一种）
 /// 3. 杠的类型必须与来源匹配
 /// 
 /// # 参数
@@ -194,9 +195,9 @@ pub fn validate_kan(tiles: &[Tile], kan_type: KanType, sources: &[MeldSource]) -
         return Err(MajiangError::InvalidMeld("杠必须由四张牌组成".to_string()));
     }
 
-    // 检查所有牌是否相同
+    // 检查所有牌是否相同（按种类比较，允许红五与普通五混用）
     let first_tile = tiles[0];
-    if !tiles.iter().all(|&t| t == first_tile) {
+    if !tiles.iter().all(|t| t.is_same_kind(&first_tile)) {
         return Err(MajiangError::InvalidMeld("杠必须由四张相同的牌组成".to_string()));
     }
 
@@ -267,29 +268,34 @@ pub fn validate_kan(tiles: &[Tile], kan_type: KanType, sources: &[MeldSource]) -
 pub fn sort_tiles_in_meld(meld: &Meld) -> Meld {
     match meld.meld_type {
         MeldType::Chi => {
-            // 为吃按照点数排序
-            let mut sorted_pairs: Vec<(Tile, MeldSource)> = meld.tiles.iter()
+            // 为吃按照点数排序，记录原始下标以便实体牌随之排序
+            let mut sorted_pairs: Vec<(Tile, MeldSource, usize)> = meld.tiles.iter()
                 .zip(meld.sources.iter())
-                .map(|(&t, &s)| (t, s))
+                .enumerate()
+                .map(|(i, (&t, &s))| (t, s, i))
                 .collect();
 
             // 按点数排序
-            sorted_pairs.sort_by(|(a, _), (b, _)| {
-                if let (Tile::Suit(_, a_num), Tile::Suit(_, b_num)) = (*a, *b) {
+            sorted_pairs.sort_by(|(a, _, _), (b, _, _)| {
+                if let (Some((_, a_num)), Some((_, b_num))) = (a.suit_and_number(), b.suit_and_number()) {
                     a_num.cmp(&b_num)
                 } else {
                     std::cmp::Ordering::Equal // 理论上不会走到这里，因为吃只能由数牌组成
                 }
             });
 
-            // 拆分回tiles和sources
-            let tiles = sorted_pairs.iter().map(|(t, _)| *t).collect();
-            let sources = sorted_pairs.iter().map(|(_, s)| *s).collect();
+            // 拆分回tiles、sources和instances
+            let tiles = sorted_pairs.iter().map(|(t, _, _)| *t).collect();
+            let sources = sorted_pairs.iter().map(|(_, s, _)| *s).collect();
+            let instances = sorted_pairs.iter()
+                .filter_map(|(_, _, i)| meld.instances.get(*i).copied())
+                .collect();
 
             Meld {
                 tiles,
                 meld_type: MeldType::Chi,
                 sources,
+                instances,
             }
         },
         _ => meld.clone(), // 对于碰和杠，不需要排序因为所有牌都一样
@@ -337,8 +343,9 @@ pub fn can_form_chi(hand_tiles: &[Tile], discarded_tile: Tile) -> bool {
     }
 
     // 提取discarded_tile的花色和点数
-    if let Tile::Suit(suit, number) = discarded_tile {
-        let hand_set: HashSet<_> = hand_tiles.iter().copied().collect();
+    if let Some((suit, number)) = discarded_tile.suit_and_number() {
+        // 按种类建立集合，红五与普通五可以互相替代
+        let hand_set: HashSet<_> = hand_tiles.iter().map(|t| t.to_normal()).collect();
         
         // 检查三种可能的吃牌组合
         // 例: 如果打出3筒，检查是否有(1筒,2筒)、(2筒,4筒)或(4筒,5筒)
@@ -355,7 +362,7 @@ pub fn can_form_chi(hand_tiles: &[Tile], discarded_tile: Tile) -> bool {
         }
         
         // 组合2: n-1, n, n+1
-        if number >= 2 && number <= 8 {
+        if (2..=8).contains(&number) {
             let need_1 = Tile::new_suit(suit, number - 1);
             let need_2 = Tile::new_suit(suit, number + 1);
             if let (Some(n1), Some(n2)) = (need_1, need_2) {
@@ -416,8 +423,8 @@ pub fn can_form_chi(hand_tiles: &[Tile], discarded_tile: Tile) -> bool {
 /// assert!(!can_form_pon(&hand, tile2));
 /// ```
 pub fn can_form_pon(hand_tiles: &[Tile], discarded_tile: Tile) -> bool {
-    // 计算手中有多少张与discarded_tile相同的牌
-    hand_tiles.iter().filter(|&&t| t == discarded_tile).count() >= 2
+    // 计算手中有多少张与discarded_tile同种类的牌
+    hand_tiles.iter().filter(|t| t.is_same_kind(&discarded_tile)).count() >= 2
 }
 
 /// 检查是否可以形成杠
@@ -456,7 +463,7 @@ pub fn can_form_pon(hand_tiles: &[Tile], discarded_tile: Tile) -> bool {
 /// assert!(can_form_kan(&hand, tile, true));
 /// ```
 pub fn can_form_kan(hand_tiles: &[Tile], discarded_tile: Tile, is_self_drawn: bool) -> bool {
-    let same_count = hand_tiles.iter().filter(|&&t| t == discarded_tile).count();
+    let same_count = hand_tiles.iter().filter(|t| t.is_same_kind(&discarded_tile)).count();
     
     if is_self_drawn {
         // 自摸杠，需要手里有4张相同的牌(包括刚摸到的那张)
@@ -509,39 +516,31 @@ pub fn get_possible_chi_combinations(hand_tiles: &[Tile], discarded_tile: Tile)
         return combinations;
     }
 
-    if let Tile::Suit(suit, number) = discarded_tile {
-        let hand_set: HashSet<_> = hand_tiles.iter().copied().collect();
+    if let Some((suit, number)) = discarded_tile.suit_and_number() {
+        // 组合中使用手牌里实际存在的那一张，这样红五会如实出现在组合中
+        let find = |n: u8| {
+            Tile::new_suit(suit, n)
+                .and_then(|kind| hand_tiles.iter().copied().find(|t| t.is_same_kind(&kind)))
+        };
         
         // 组合1: n-2, n-1, n
         if number >= 3 {
-            let need_1 = Tile::new_suit(suit, number - 2);
-            let need_2 = Tile::new_suit(suit, number - 1);
-            if let (Some(n1), Some(n2)) = (need_1, need_2) {
-                if hand_set.contains(&n1) && hand_set.contains(&n2) {
-                    combinations.push(vec![n1, n2, discarded_tile]);
-                }
+            if let (Some(n1), Some(n2)) = (find(number - 2), find(number - 1)) {
+                combinations.push(vec![n1, n2, discarded_tile]);
             }
         }
         
         // 组合2: n-1, n, n+1
-        if number >= 2 && number <= 8 {
-            let need_1 = Tile::new_suit(suit, number - 1);
-            let need_2 = Tile::new_suit(suit, number + 1);
-            if let (Some(n1), Some(n2)) = (need_1, need_2) {
-                if hand_set.contains(&n1) && hand_set.contains(&n2) {
-                    combinations.push(vec![n1, discarded_tile, n2]);
-                }
+        if (2..=8).contains(&number) {
+            if let (Some(n1), Some(n2)) = (find(number - 1), find(number + 1)) {
+                combinations.push(vec![n1, discarded_tile, n2]);
             }
         }
         
         // 组合3: n, n+1, n+2
         if number <= 7 {
-            let need_1 = Tile::new_suit(suit, number + 1);
-            let need_2 = Tile::new_suit(suit, number + 2);
-            if let (Some(n1), Some(n2)) = (need_1, need_2) {
-                if hand_set.contains(&n1) && hand_set.contains(&n2) {
-                    combinations.push(vec![discarded_tile, n1, n2]);
-                }
+            if let (Some(n1), Some(n2)) = (find(number + 1), find(number + 2)) {
+                combinations.push(vec![discarded_tile, n1, n2]);
             }
         }
     }
@@ -551,6 +550,8 @@ pub fn get_possible_chi_combinations(hand_tiles: &[Tile], discarded_tile: Tile)
 
 /// 获取杠中包含多少张指定的牌
 /// 
+/// 按种类计数，红五与普通五都会被计入。
+/// 
 /// # 参数
 /// 
 /// * `meld` - 杠副露
@@ -582,7 +583,7 @@ pub fn get_possible_chi_combinations(hand_tiles: &[Tile], discarded_tile: Tile)
 /// ```
 pub fn get_kan_tile_count(meld: &Meld, tile: Tile) -> usize {
     if let MeldType::Kan(_) = meld.meld_type {
-        meld.tiles.iter().filter(|&&t| t == tile).count()
+        meld.tiles.iter().filter(|t| t.is_same_kind(&tile)).count()
     } else {
         0
     }
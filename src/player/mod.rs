@@ -0,0 +1,7 @@
+// src/player/mod.rs
+//
+// 玩家模块的入口文件
+
+mod model;
+
+pub use model::{Player, DiscardRecord};
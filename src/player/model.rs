@@ -0,0 +1,99 @@
+// src/player/model.rs
+//
+// 玩家数据模型
+// 保存一位玩家在一局中的全部公开与非公开状态：手牌(含副露和花牌)、河牌、立直状态，
+// 以及四川麻将的定缺花色和血战中是否已经和牌。
+// 玩家模型只记录数据，状态的变化统一由`GameState`驱动。
+
+use crate::hand::Hand;
+use crate::tile::{Suit, Tile};
+
+/// 河中的一张牌
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiscardRecord {
+    /// 打出的牌
+    pub tile: Tile,
+    /// 是否为摸切
+    pub tsumogiri: bool,
+    /// 是否为立直宣言牌
+    pub riichi: bool,
+    /// 是否被其他玩家鸣牌或荣和取走
+    pub claimed: bool,
+}
+
+/// 一位玩家在一局中的状态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Player {
+    seat: u8,
+    pub(crate) hand: Hand,
+    pub(crate) discards: Vec<DiscardRecord>,
+    pub(crate) riichi: bool,
+    pub(crate) double_riichi: bool,
+    pub(crate) ippatsu: bool,
+    /// 同巡振听：放过和牌张后到自己下次打牌为止
+    pub(crate) temporary_furiten: bool,
+    /// 立直振听：立直后放过和牌张
+    pub(crate) riichi_furiten: bool,
+    /// 定缺的花色
+    pub(crate) void_suit: Option<Suit>,
+    /// 是否已经和牌(血战到底中和牌后不再参与本局)
+    pub(crate) won: bool,
+}
+
+impl Player {
+    /// 创建空手牌的玩家
+    pub fn new(seat: u8) -> Self {
+        Self {
+            seat,
+            hand: Hand::new(),
+            discards: Vec::new(),
+            riichi: false,
+            double_riichi: false,
+            ippatsu: false,
+            temporary_furiten: false,
+            riichi_furiten: false,
+            void_suit: None,
+            won: false,
+        }
+    }
+
+    /// 座位
+    pub fn seat(&self) -> u8 {
+        self.seat
+    }
+
+    /// 手牌
+    pub fn hand(&self) -> &Hand {
+        &self.hand
+    }
+
+    /// 河牌，按打出顺序排列(被鸣走的牌也保留，并标记为`claimed`)
+    pub fn discards(&self) -> &[DiscardRecord] {
+        &self.discards
+    }
+
+    /// 是否已经立直
+    pub fn is_riichi(&self) -> bool {
+        self.riichi
+    }
+
+    /// 是否为两立直
+    pub fn is_double_riichi(&self) -> bool {
+        self.double_riichi
+    }
+
+    /// 立直后是否仍在一发圈内
+    pub fn is_ippatsu(&self) -> bool {
+        self.ippatsu
+    }
+
+    /// 定缺的花色，没有定缺时为`None`
+    pub fn void_suit(&self) -> Option<Suit> {
+        self.void_suit
+    }
+
+    /// 本局是否已经和牌
+    pub fn has_won(&self) -> bool {
+        self.won
+    }
+}
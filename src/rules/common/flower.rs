@@ -0,0 +1,108 @@
+// src/rules/common/flower.rs
+//
+// 补花流程
+// 国标麻将和上海麻将的花牌不参与组牌：
+// - 开局时，各玩家从庄家起按座位顺序亮出配牌中的花牌并补牌；
+// - 对局中摸到花牌时亮出并补牌；
+// - 补到的牌仍是花牌时继续亮出再补，直到补到普通牌为止。
+// 补牌的来源(补花区或牌墙末尾)由`GameConfig::replacement_source`决定。
+// 每亮出一张花牌产生一个`FlowerDeclared`，每补一张牌产生一个`DrewReplacement`，
+// 客户端可以据此逐张播放动画。`RuleSet::replace_dealt_flowers`和`RuleSet::declare_flower`
+// 默认使用这里的流程。
+
+use crate::errors::{MajiangError, MajiangResult};
+use crate::game::Event;
+use crate::hand::Hand;
+use crate::tile::Tile;
+use crate::wall::{ReplacementSource, Wall};
+
+/// 亮出配牌中的花牌并补牌，补到的普通牌放入暗手
+///
+/// `hand`为去掉花牌后的配牌，`flowers`为配牌中的花牌。
+///
+/// # 返回
+/// 是否补齐；牌墙在补花途中摸完时返回`false`
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{parse_tiles, Hand};
+/// use majiang_core::rules::common::flower::replace_dealt_flowers;
+/// use majiang_core::wall::{DeadWallConfig, ReplacementSource, Wall, WallConfig};
+///
+/// let mut wall = Wall::new(WallConfig::MCR, Some(DeadWallConfig::MCR { replacement_count: 8 }), Some(1)).unwrap();
+/// wall.start_game();
+/// let mut hand: Hand = "123456789m1234p".parse().unwrap();
+/// let mut events = Vec::new();
+/// let flowers = parse_tiles("1f").unwrap();
+/// let complete = replace_dealt_flowers(&mut wall, &mut hand, 0, &flowers, ReplacementSource::DeadWall, &mut events).unwrap();
+/// assert!(complete);
+/// assert!(!hand.flowers().is_empty());
+/// assert_eq!(hand.concealed_count(), 14);
+/// ```
+pub fn replace_dealt_flowers(
+    wall: &mut Wall,
+    hand: &mut Hand,
+    seat: u8,
+    flowers: &[Tile],
+    source: ReplacementSource,
+    events: &mut Vec<Event>,
+) -> MajiangResult<bool> {
+    let mut queue: Vec<Tile> = flowers.iter().rev().copied().collect();
+    while let Some(flower) = queue.pop() {
+        hand.add_flower(flower)?;
+        events.push(Event::FlowerDeclared { seat, tile: flower });
+        let Some(tile) = draw_replacement(wall, source)? else {
+            return Ok(false);
+        };
+        events.push(Event::DrewReplacement { seat, tile });
+        if tile.is_flower() {
+            queue.push(tile);
+        } else {
+            hand.add_tile(tile)?;
+        }
+    }
+    Ok(true)
+}
+
+/// 亮出手中的一张花牌(通常是刚摸到的牌)并补牌，补到的牌作为摸牌
+///
+/// 补到花牌时继续亮出并补牌，直到补到普通牌。
+///
+/// # 返回
+/// 是否补齐；牌墙在补花途中摸完时返回`false`
+///
+/// # 错误
+/// 手中没有这张花牌时返回`TileNotFound`
+pub fn declare_flower(
+    wall: &mut Wall,
+    hand: &mut Hand,
+    seat: u8,
+    flower: Tile,
+    source: ReplacementSource,
+    events: &mut Vec<Event>,
+) -> MajiangResult<bool> {
+    let mut flower = flower;
+    loop {
+        hand.remove_tile(flower)?;
+        hand.add_flower(flower)?;
+        events.push(Event::FlowerDeclared { seat, tile: flower });
+        let Some(tile) = draw_replacement(wall, source)? else {
+            return Ok(false);
+        };
+        hand.draw(tile)?;
+        events.push(Event::DrewReplacement { seat, tile });
+        if !tile.is_flower() {
+            return Ok(true);
+        }
+        flower = tile;
+    }
+}
+
+/// 摸一张补牌，牌已摸完时返回`None`
+fn draw_replacement(wall: &mut Wall, source: ReplacementSource) -> MajiangResult<Option<Tile>> {
+    match wall.draw_replacement_from(source) {
+        Ok(tile) => Ok(Some(tile)),
+        Err(MajiangError::NotEnoughTiles) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,11 @@
+// src/rules/common/mod.rs
+//
+// 各规则共用的工具：和牌形模型、补花流程，以及规则接口使用的选项和判定结果
+
+pub mod flower;
+pub mod types;
+pub mod win_patterns;
+
+pub use flower::{declare_flower, replace_dealt_flowers};
+pub use types::{AbortiveDrawOptions, DoraTiming, ExchangeDirection, Furiten};
+pub use win_patterns::{all_tiles, win_patterns, Block, BlockKind, WinPattern};
@@ -0,0 +1,110 @@
+// src/rules/common/types.rs
+//
+// `RuleSet`的方法使用的选项和判定结果
+// 这些类型出现在规则接口中，由`GameState`按统一的方式处理，具体取值由各规则决定：
+// 杠宝牌的翻开时机和途中流局选项来自立直麻将，换三张的方向来自四川麻将，
+// 振听的种类来自立直麻将的振听判定。
+
+use crate::action::AbortiveDrawKind;
+
+/// 振听的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Furiten {
+    /// 舍张振听：听的牌在自己的河中
+    Discard,
+    /// 同巡振听：放过和牌张后，到自己下次打牌为止
+    Temporary,
+    /// 立直振听：立直后放过和牌张，本局不再解除
+    Riichi,
+}
+
+/// 杠后翻开新宝牌指示牌的时机
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoraTiming {
+    /// 杠后立即翻开
+    Immediate,
+    /// 杠后打出一张牌时翻开(岭上开花时不翻开)
+    AfterDiscard,
+}
+
+/// 各种途中流局是否成立
+///
+/// # 示例
+/// ```
+/// use majiang_core::action::AbortiveDrawKind;
+/// use majiang_core::rules::AbortiveDrawOptions;
+///
+/// let options = AbortiveDrawOptions { four_kans: false, ..AbortiveDrawOptions::default() };
+/// assert!(options.allows(AbortiveDrawKind::FourWinds));
+/// assert!(!options.allows(AbortiveDrawKind::FourKans));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AbortiveDrawOptions {
+    /// 九种九牌
+    pub nine_terminals: bool,
+    /// 四风连打
+    pub four_winds: bool,
+    /// 四家立直
+    pub four_riichi: bool,
+    /// 四杠散了
+    pub four_kans: bool,
+    /// 三家和
+    pub triple_ron: bool,
+}
+
+impl AbortiveDrawOptions {
+    /// 没有任何途中流局
+    pub const NONE: Self =
+        Self { nine_terminals: false, four_winds: false, four_riichi: false, four_kans: false, triple_ron: false };
+
+    /// 某种途中流局是否成立
+    pub fn allows(&self, kind: AbortiveDrawKind) -> bool {
+        match kind {
+            AbortiveDrawKind::NineTerminals => self.nine_terminals,
+            AbortiveDrawKind::FourWinds => self.four_winds,
+            AbortiveDrawKind::FourRiichi => self.four_riichi,
+            AbortiveDrawKind::FourKans => self.four_kans,
+            AbortiveDrawKind::TripleRon => self.triple_ron,
+        }
+    }
+}
+
+impl Default for AbortiveDrawOptions {
+    /// 所有途中流局都成立
+    fn default() -> Self {
+        Self { nine_terminals: true, four_winds: true, four_riichi: true, four_kans: true, triple_ron: true }
+    }
+}
+
+/// 换三张的方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExchangeDirection {
+    /// 交给下家
+    #[default]
+    ToNext,
+    /// 交给对家
+    ToOpposite,
+    /// 交给上家
+    ToPrevious,
+}
+
+impl ExchangeDirection {
+    /// 座位`seat`交出的牌由哪个座位收到
+    ///
+    /// # 示例
+    /// ```
+    /// use majiang_core::rules::ExchangeDirection;
+    ///
+    /// assert_eq!(ExchangeDirection::ToNext.recipient(3, 4), 0);
+    /// assert_eq!(ExchangeDirection::ToOpposite.recipient(1, 4), 3);
+    /// assert_eq!(ExchangeDirection::ToPrevious.recipient(0, 4), 3);
+    /// ```
+    pub fn recipient(self, seat: u8, player_count: u8) -> u8 {
+        let offset = match self {
+            ExchangeDirection::ToNext => 1,
+            ExchangeDirection::ToOpposite => player_count / 2,
+            ExchangeDirection::ToPrevious => player_count - 1,
+        };
+        (seat + offset) % player_count
+    }
+}
@@ -0,0 +1,179 @@
+// src/rules/common/win_patterns.rs
+//
+// 各规则共用的和牌形模型
+// `hand::decompositions`只拆分暗手部分，计算役种和番种时还需要把副露一起考虑，
+// 并区分明暗(荣和完成的刻子视为明刻)。这里把一种拆分与手牌的副露合并成`WinPattern`：
+// 一个雀头加若干面子(`Block`)，面子按暗手在前、副露在后的顺序排列。
+// 国标麻将的组合龙把147、258、369三组不同花色的牌各记为一组`Knitted`面子，
+// 因此"组合龙加一组面子一个雀头"同样可以用这个模型表示。
+
+use crate::hand::{decompositions, Decomposition, Hand, Mentsu, WaitType, WinForm};
+use crate::meld::{Meld, MeldType};
+use crate::tile::Tile;
+
+/// 面子的种类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockKind {
+    /// 顺子
+    Sequence,
+    /// 刻子
+    Triplet,
+    /// 杠子
+    Kan,
+    /// 组合龙中的一组：同一花色的147、258或369
+    Knitted,
+}
+
+/// 和牌形中的一组面子
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Block {
+    /// 面子种类
+    pub kind: BlockKind,
+    /// 顺子和组合龙为最小的一张，刻子和杠子为组成的牌，均为普通牌(不区分红五)
+    pub tile: Tile,
+    /// 是否为明的：吃、碰、明杠、加杠，以及荣和完成的刻子
+    pub open: bool,
+    /// 是否来自已声明的副露(含暗杠)
+    pub melded: bool,
+}
+
+impl Block {
+    /// 由已声明的副露创建面子
+    pub fn from_meld(meld: &Meld) -> Self {
+        let tile = meld
+            .tiles
+            .iter()
+            .map(|t| t.to_normal())
+            .min_by_key(|t| t.to_id())
+            .unwrap_or_else(|| meld.get_key_tile().to_normal());
+        let kind = match meld.meld_type {
+            MeldType::Chi => BlockKind::Sequence,
+            MeldType::Pon => BlockKind::Triplet,
+            MeldType::Kan(_) => BlockKind::Kan,
+        };
+        Self { kind, tile, open: meld.is_open(), melded: true }
+    }
+
+    /// 由暗手中的面子创建
+    pub fn concealed(mentsu: Mentsu) -> Self {
+        match mentsu {
+            Mentsu::Sequence(tile) => Self { kind: BlockKind::Sequence, tile: tile.to_normal(), open: false, melded: false },
+            Mentsu::Triplet(tile) => Self { kind: BlockKind::Triplet, tile: tile.to_normal(), open: false, melded: false },
+        }
+    }
+
+    /// 是否为顺子
+    pub fn is_sequence(&self) -> bool {
+        self.kind == BlockKind::Sequence
+    }
+
+    /// 是否为刻子或杠子
+    pub fn is_triplet_like(&self) -> bool {
+        matches!(self.kind, BlockKind::Triplet | BlockKind::Kan)
+    }
+
+    /// 由组合龙中同一花色的三张牌创建，`tile`为最小的一张
+    pub fn knitted(tile: Tile) -> Self {
+        Self { kind: BlockKind::Knitted, tile: tile.to_normal(), open: false, melded: false }
+    }
+
+    /// 面子中的三种牌(刻子和杠子为同一种牌)
+    pub fn kinds(&self) -> [Tile; 3] {
+        match self.kind {
+            BlockKind::Sequence => Mentsu::Sequence(self.tile).tiles(),
+            BlockKind::Triplet | BlockKind::Kan => [self.tile; 3],
+            BlockKind::Knitted => match self.tile.suit_and_number() {
+                Some((suit, n)) => [n, n + 3, n + 6].map(|number| Tile::Suit(suit, number)),
+                None => [self.tile; 3],
+            },
+        }
+    }
+
+    /// 面子是否含有幺九牌
+    pub fn has_terminal_or_honor(&self) -> bool {
+        self.kinds().iter().any(Tile::is_terminal_or_honor)
+    }
+}
+
+/// 一种完整的和牌形
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WinPattern {
+    /// 和牌形
+    pub form: WinForm,
+    /// 雀头；七对子和国士无双见`Decomposition::pair`
+    pub pair: Tile,
+    /// 全部面子，暗手中的在前、副露在后；七对子和国士无双为空
+    pub blocks: Vec<Block>,
+    /// 听牌形状
+    pub wait: WaitType,
+    /// 和牌张完成的面子在`blocks`中的下标，单骑时为`None`
+    pub win_block: Option<usize>,
+}
+
+impl WinPattern {
+    /// 由暗手拆分和手牌的副露合成和牌形
+    ///
+    /// 荣和时和牌张完成的刻子记为明刻。
+    pub fn from_decomposition(hand: &Hand, decomposition: &Decomposition, tsumo: bool) -> Self {
+        let mut blocks: Vec<Block> = decomposition.sets.iter().map(|&m| Block::concealed(m)).collect();
+        if let Some(index) = decomposition.win_block {
+            let block = &mut blocks[index];
+            if !tsumo && block.kind == BlockKind::Triplet {
+                block.open = true;
+            }
+        }
+        blocks.extend(hand.melds().iter().map(Block::from_meld));
+        Self {
+            form: decomposition.form,
+            pair: decomposition.pair.to_normal(),
+            blocks,
+            wait: decomposition.wait,
+            win_block: decomposition.win_block,
+        }
+    }
+
+    /// 暗刻(含暗杠)的数量
+    pub fn concealed_triplets(&self) -> usize {
+        self.blocks.iter().filter(|b| b.is_triplet_like() && !b.open).count()
+    }
+
+    /// 杠子的数量
+    pub fn kans(&self) -> usize {
+        self.blocks.iter().filter(|b| b.kind == BlockKind::Kan).count()
+    }
+}
+
+/// 列出手牌加入和牌张后的所有和牌形
+///
+/// # 参数
+/// * `hand` - 和牌前的手牌(不含和牌张)
+/// * `win_tile` - 和牌张
+/// * `tsumo` - 是否自摸
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::common::win_patterns;
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let hand: Hand = "111222333m456p7s".parse().unwrap();
+/// let patterns = win_patterns(&hand, Tile::Suit(Suit::Bamboo, 7), false);
+/// // 三暗刻或三个顺子(一杯口以上)两种拆法
+/// assert_eq!(patterns.len(), 2);
+/// ```
+pub fn win_patterns(hand: &Hand, win_tile: Tile, tsumo: bool) -> Vec<WinPattern> {
+    decompositions(hand, win_tile)
+        .iter()
+        .map(|d| WinPattern::from_decomposition(hand, d, tsumo))
+        .collect()
+}
+
+/// 和牌的全部牌(暗手、和牌张和副露)，均为普通牌，杠子计四张
+pub fn all_tiles(hand: &Hand, win_tile: Tile) -> Vec<Tile> {
+    let mut tiles: Vec<Tile> = hand.concealed_tiles().iter().map(|t| t.to_normal()).collect();
+    tiles.extend(hand.drawn().map(|t| t.to_normal()));
+    tiles.push(win_tile.to_normal());
+    tiles.extend(hand.melds().iter().flat_map(|m| m.tiles.iter().map(|t| t.to_normal())));
+    tiles
+}
+
@@ -0,0 +1,6 @@
+// src/rules/mcr/flower.rs
+//
+// 国标麻将的补花流程
+// 开局和对局中的亮花、补花以及连续补花都使用各规则共用的实现。
+
+pub use crate::rules::common::flower::{declare_flower, replace_dealt_flowers};
@@ -0,0 +1,56 @@
+// src/rules/mcr/mod.rs
+//
+// 中国国家标准麻将(国标麻将，MCR)规则
+
+pub mod flower;
+pub mod scoring;
+pub mod win_check;
+
+use crate::action::{ActionOptions, WinShape};
+use crate::game::WinContext;
+use crate::rules::{FlowerHandling, RuleSet, WinScore};
+use crate::wall::{DeadWallConfig, WallConfig};
+
+pub use scoring::{evaluate_fan, evaluate_pattern, payments, Fan, FanResult, MIN_FAN};
+pub use win_check::{is_winning_hand, winning_decompositions};
+
+/// 国标麻将规则：144张牌(含八张花牌)，可吃，没有立直和途中流局，截和
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct McrRules;
+
+impl RuleSet for McrRules {
+    fn name(&self) -> &'static str {
+        "国标麻将"
+    }
+
+    fn wall_config(&self) -> WallConfig {
+        WallConfig::MCR
+    }
+
+    fn dead_wall_config(&self) -> Option<DeadWallConfig> {
+        Some(DeadWallConfig::MCR { replacement_count: 8 })
+    }
+
+    fn action_options(&self) -> ActionOptions {
+        ActionOptions { allow_riichi: false, allow_abortive_draws: false, ..ActionOptions::default() }
+    }
+
+    /// 另外承认全不靠、组合龙和含四张相同牌的七对
+    fn win_shape(&self) -> WinShape {
+        is_winning_hand
+    }
+
+    fn flower_handling(&self) -> FlowerHandling {
+        FlowerHandling::Replace
+    }
+
+    fn is_valid_win(&self, context: &WinContext) -> bool {
+        evaluate_fan(context).is_some_and(|result| result.is_valid())
+    }
+
+    /// 列出番种和花牌，不足8番(不含花牌)时不能和牌
+    fn score_win(&self, context: &WinContext) -> Option<WinScore> {
+        let result = evaluate_fan(context).filter(FanResult::is_valid)?;
+        Some(WinScore { items: result.items(), payments: payments(context, result.total()) })
+    }
+}
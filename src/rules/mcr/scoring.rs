@@ -0,0 +1,1133 @@
+// src/rules/mcr/scoring.rs
+//
+// 国标麻将的番种计算
+// 按《中国麻将竞赛规则》的81个番种计番。对和牌的每一种拆分分别计番，取总番数最高的一种。
+//
+// 计番遵循以下原则：
+// - 不重复原则：某番种必然包含的番种不再另计(如清一色不计无字，见`Fan::excludes`)；
+// - 不拆移原则：已组成面子的牌不能拆开另组番种，按拆分逐一计算即可保证；
+// - 套算一次原则：顺子(刻子)之间的组合番，尚未组合过的一组只能与已组合过的一组再组合一次；
+// - 就高不就低原则：可以有多种组合时取番数最高的组合。
+//
+// 起和需要8番，花牌不计入起和番数，但和牌后每张花牌加一番。
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::game::WinContext;
+use crate::hand::{WaitType, WinForm};
+use crate::rules::common::{all_tiles, Block, BlockKind, WinPattern};
+use crate::rules::mcr::win_check::{winning_decompositions, winning_tiles};
+use crate::rules::ScoreItem;
+use crate::tile::{Dragon, Suit, Tile};
+
+/// 起和番数(不含花牌)
+pub const MIN_FAN: u32 = 8;
+
+/// 绿一色可以使用的牌：二三四六八条和发
+const GREEN_TILES: [Tile; 6] = [
+    Tile::Suit(Suit::Bamboo, 2),
+    Tile::Suit(Suit::Bamboo, 3),
+    Tile::Suit(Suit::Bamboo, 4),
+    Tile::Suit(Suit::Bamboo, 6),
+    Tile::Suit(Suit::Bamboo, 8),
+    Tile::Dragon(Dragon::Green),
+];
+
+/// 推不倒可以使用的牌：一二三四五八九筒、二四五六八九条和白板
+const REVERSIBLE_TILES: [Tile; 14] = [
+    Tile::Suit(Suit::Dot, 1),
+    Tile::Suit(Suit::Dot, 2),
+    Tile::Suit(Suit::Dot, 3),
+    Tile::Suit(Suit::Dot, 4),
+    Tile::Suit(Suit::Dot, 5),
+    Tile::Suit(Suit::Dot, 8),
+    Tile::Suit(Suit::Dot, 9),
+    Tile::Suit(Suit::Bamboo, 2),
+    Tile::Suit(Suit::Bamboo, 4),
+    Tile::Suit(Suit::Bamboo, 5),
+    Tile::Suit(Suit::Bamboo, 6),
+    Tile::Suit(Suit::Bamboo, 8),
+    Tile::Suit(Suit::Bamboo, 9),
+    Tile::Dragon(Dragon::White),
+];
+
+/// 九莲宝灯和牌前的牌形：同一花色的1112345678999
+const NINE_GATES: [u8; 9] = [3, 1, 1, 1, 1, 1, 1, 1, 3];
+
+/// 国标麻将的81个番种
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fan {
+    /// 大四喜
+    BigFourWinds,
+    /// 大三元
+    BigThreeDragons,
+    /// 绿一色
+    AllGreen,
+    /// 九莲宝灯
+    NineGates,
+    /// 四杠
+    FourKongs,
+    /// 连七对
+    SevenShiftedPairs,
+    /// 十三幺
+    ThirteenOrphans,
+    /// 清幺九
+    AllTerminals,
+    /// 小四喜
+    LittleFourWinds,
+    /// 小三元
+    LittleThreeDragons,
+    /// 字一色
+    AllHonors,
+    /// 四暗刻
+    FourConcealedPungs,
+    /// 一色双龙会
+    PureTerminalChows,
+    /// 一色四同顺
+    QuadrupleChow,
+    /// 一色四节高
+    FourPureShiftedPungs,
+    /// 一色四步高
+    FourPureShiftedChows,
+    /// 三杠
+    ThreeKongs,
+    /// 混幺九
+    AllTerminalsAndHonors,
+    /// 七对
+    SevenPairs,
+    /// 七星不靠
+    GreaterHonorsAndKnittedTiles,
+    /// 全双刻
+    AllEvenPungs,
+    /// 清一色
+    FullFlush,
+    /// 一色三同顺
+    PureTripleChow,
+    /// 一色三节高
+    PureShiftedPungs,
+    /// 全大
+    UpperTiles,
+    /// 全中
+    MiddleTiles,
+    /// 全小
+    LowerTiles,
+    /// 清龙
+    PureStraight,
+    /// 三色双龙会
+    ThreeSuitedTerminalChows,
+    /// 一色三步高
+    PureShiftedChows,
+    /// 全带五
+    AllFives,
+    /// 三同刻
+    TriplePung,
+    /// 三暗刻
+    ThreeConcealedPungs,
+    /// 全不靠
+    LesserHonorsAndKnittedTiles,
+    /// 组合龙
+    KnittedStraight,
+    /// 大于五
+    UpperFour,
+    /// 小于五
+    LowerFour,
+    /// 三风刻
+    BigThreeWinds,
+    /// 花龙
+    MixedStraight,
+    /// 推不倒
+    ReversibleTiles,
+    /// 三色三同顺
+    MixedTripleChow,
+    /// 三色三节高
+    MixedShiftedPungs,
+    /// 无番和
+    ChickenHand,
+    /// 妙手回春
+    LastTileDraw,
+    /// 海底捞月
+    LastTileClaim,
+    /// 杠上开花
+    OutWithReplacementTile,
+    /// 抢杠和
+    RobbingTheKong,
+    /// 碰碰和
+    AllPungs,
+    /// 混一色
+    HalfFlush,
+    /// 三色三步高
+    MixedShiftedChows,
+    /// 五门齐
+    AllTypes,
+    /// 全求人
+    MeldedHand,
+    /// 双暗杠
+    TwoConcealedKongs,
+    /// 双箭刻
+    TwoDragonPungs,
+    /// 全带幺
+    OutsideHand,
+    /// 不求人
+    FullyConcealedHand,
+    /// 双明杠
+    TwoMeldedKongs,
+    /// 和绝张
+    LastTile,
+    /// 箭刻
+    DragonPung,
+    /// 圈风刻
+    PrevalentWind,
+    /// 门风刻
+    SeatWind,
+    /// 门前清
+    ConcealedHand,
+    /// 平和
+    AllChows,
+    /// 四归一
+    TileHog,
+    /// 双同刻
+    DoublePung,
+    /// 双暗刻
+    TwoConcealedPungs,
+    /// 暗杠
+    ConcealedKong,
+    /// 断幺
+    AllSimples,
+    /// 一般高
+    PureDoubleChow,
+    /// 喜相逢
+    MixedDoubleChow,
+    /// 连六
+    ShortStraight,
+    /// 老少副
+    TwoTerminalChows,
+    /// 幺九刻
+    PungOfTerminalsOrHonors,
+    /// 明杠
+    MeldedKong,
+    /// 缺一门
+    OneVoidedSuit,
+    /// 无字
+    NoHonors,
+    /// 边张
+    EdgeWait,
+    /// 坎张
+    ClosedWait,
+    /// 单钓将
+    SingleWait,
+    /// 自摸
+    SelfDrawn,
+    /// 花牌
+    FlowerTiles,
+}
+
+impl Fan {
+    /// 全部番种，按番数从高到低排列
+    pub const ALL: [Fan; 81] = [
+        Fan::BigFourWinds,
+        Fan::BigThreeDragons,
+        Fan::AllGreen,
+        Fan::NineGates,
+        Fan::FourKongs,
+        Fan::SevenShiftedPairs,
+        Fan::ThirteenOrphans,
+        Fan::AllTerminals,
+        Fan::LittleFourWinds,
+        Fan::LittleThreeDragons,
+        Fan::AllHonors,
+        Fan::FourConcealedPungs,
+        Fan::PureTerminalChows,
+        Fan::QuadrupleChow,
+        Fan::FourPureShiftedPungs,
+        Fan::FourPureShiftedChows,
+        Fan::ThreeKongs,
+        Fan::AllTerminalsAndHonors,
+        Fan::SevenPairs,
+        Fan::GreaterHonorsAndKnittedTiles,
+        Fan::AllEvenPungs,
+        Fan::FullFlush,
+        Fan::PureTripleChow,
+        Fan::PureShiftedPungs,
+        Fan::UpperTiles,
+        Fan::MiddleTiles,
+        Fan::LowerTiles,
+        Fan::PureStraight,
+        Fan::ThreeSuitedTerminalChows,
+        Fan::PureShiftedChows,
+        Fan::AllFives,
+        Fan::TriplePung,
+        Fan::ThreeConcealedPungs,
+        Fan::LesserHonorsAndKnittedTiles,
+        Fan::KnittedStraight,
+        Fan::UpperFour,
+        Fan::LowerFour,
+        Fan::BigThreeWinds,
+        Fan::MixedStraight,
+        Fan::ReversibleTiles,
+        Fan::MixedTripleChow,
+        Fan::MixedShiftedPungs,
+        Fan::ChickenHand,
+        Fan::LastTileDraw,
+        Fan::LastTileClaim,
+        Fan::OutWithReplacementTile,
+        Fan::RobbingTheKong,
+        Fan::AllPungs,
+        Fan::HalfFlush,
+        Fan::MixedShiftedChows,
+        Fan::AllTypes,
+        Fan::MeldedHand,
+        Fan::TwoConcealedKongs,
+        Fan::TwoDragonPungs,
+        Fan::OutsideHand,
+        Fan::FullyConcealedHand,
+        Fan::TwoMeldedKongs,
+        Fan::LastTile,
+        Fan::DragonPung,
+        Fan::PrevalentWind,
+        Fan::SeatWind,
+        Fan::ConcealedHand,
+        Fan::AllChows,
+        Fan::TileHog,
+        Fan::DoublePung,
+        Fan::TwoConcealedPungs,
+        Fan::ConcealedKong,
+        Fan::AllSimples,
+        Fan::PureDoubleChow,
+        Fan::MixedDoubleChow,
+        Fan::ShortStraight,
+        Fan::TwoTerminalChows,
+        Fan::PungOfTerminalsOrHonors,
+        Fan::MeldedKong,
+        Fan::OneVoidedSuit,
+        Fan::NoHonors,
+        Fan::EdgeWait,
+        Fan::ClosedWait,
+        Fan::SingleWait,
+        Fan::SelfDrawn,
+        Fan::FlowerTiles,
+    ];
+
+    /// 番种名称
+    pub fn name(self) -> &'static str {
+        match self {
+            Fan::BigFourWinds => "大四喜",
+            Fan::BigThreeDragons => "大三元",
+            Fan::AllGreen => "绿一色",
+            Fan::NineGates => "九莲宝灯",
+            Fan::FourKongs => "四杠",
+            Fan::SevenShiftedPairs => "连七对",
+            Fan::ThirteenOrphans => "十三幺",
+            Fan::AllTerminals => "清幺九",
+            Fan::LittleFourWinds => "小四喜",
+            Fan::LittleThreeDragons => "小三元",
+            Fan::AllHonors => "字一色",
+            Fan::FourConcealedPungs => "四暗刻",
+            Fan::PureTerminalChows => "一色双龙会",
+            Fan::QuadrupleChow => "一色四同顺",
+            Fan::FourPureShiftedPungs => "一色四节高",
+            Fan::FourPureShiftedChows => "一色四步高",
+            Fan::ThreeKongs => "三杠",
+            Fan::AllTerminalsAndHonors => "混幺九",
+            Fan::SevenPairs => "七对",
+            Fan::GreaterHonorsAndKnittedTiles => "七星不靠",
+            Fan::AllEvenPungs => "全双刻",
+            Fan::FullFlush => "清一色",
+            Fan::PureTripleChow => "一色三同顺",
+            Fan::PureShiftedPungs => "一色三节高",
+            Fan::UpperTiles => "全大",
+            Fan::MiddleTiles => "全中",
+            Fan::LowerTiles => "全小",
+            Fan::PureStraight => "清龙",
+            Fan::ThreeSuitedTerminalChows => "三色双龙会",
+            Fan::PureShiftedChows => "一色三步高",
+            Fan::AllFives => "全带五",
+            Fan::TriplePung => "三同刻",
+            Fan::ThreeConcealedPungs => "三暗刻",
+            Fan::LesserHonorsAndKnittedTiles => "全不靠",
+            Fan::KnittedStraight => "组合龙",
+            Fan::UpperFour => "大于五",
+            Fan::LowerFour => "小于五",
+            Fan::BigThreeWinds => "三风刻",
+            Fan::MixedStraight => "花龙",
+            Fan::ReversibleTiles => "推不倒",
+            Fan::MixedTripleChow => "三色三同顺",
+            Fan::MixedShiftedPungs => "三色三节高",
+            Fan::ChickenHand => "无番和",
+            Fan::LastTileDraw => "妙手回春",
+            Fan::LastTileClaim => "海底捞月",
+            Fan::OutWithReplacementTile => "杠上开花",
+            Fan::RobbingTheKong => "抢杠和",
+            Fan::AllPungs => "碰碰和",
+            Fan::HalfFlush => "混一色",
+            Fan::MixedShiftedChows => "三色三步高",
+            Fan::AllTypes => "五门齐",
+            Fan::MeldedHand => "全求人",
+            Fan::TwoConcealedKongs => "双暗杠",
+            Fan::TwoDragonPungs => "双箭刻",
+            Fan::OutsideHand => "全带幺",
+            Fan::FullyConcealedHand => "不求人",
+            Fan::TwoMeldedKongs => "双明杠",
+            Fan::LastTile => "和绝张",
+            Fan::DragonPung => "箭刻",
+            Fan::PrevalentWind => "圈风刻",
+            Fan::SeatWind => "门风刻",
+            Fan::ConcealedHand => "门前清",
+            Fan::AllChows => "平和",
+            Fan::TileHog => "四归一",
+            Fan::DoublePung => "双同刻",
+            Fan::TwoConcealedPungs => "双暗刻",
+            Fan::ConcealedKong => "暗杠",
+            Fan::AllSimples => "断幺",
+            Fan::PureDoubleChow => "一般高",
+            Fan::MixedDoubleChow => "喜相逢",
+            Fan::ShortStraight => "连六",
+            Fan::TwoTerminalChows => "老少副",
+            Fan::PungOfTerminalsOrHonors => "幺九刻",
+            Fan::MeldedKong => "明杠",
+            Fan::OneVoidedSuit => "缺一门",
+            Fan::NoHonors => "无字",
+            Fan::EdgeWait => "边张",
+            Fan::ClosedWait => "坎张",
+            Fan::SingleWait => "单钓将",
+            Fan::SelfDrawn => "自摸",
+            Fan::FlowerTiles => "花牌",
+        }
+    }
+
+    /// 番数
+    pub fn points(self) -> u32 {
+        match self {
+            Fan::BigFourWinds
+            | Fan::BigThreeDragons
+            | Fan::AllGreen
+            | Fan::NineGates
+            | Fan::FourKongs
+            | Fan::SevenShiftedPairs
+            | Fan::ThirteenOrphans => 88,
+            Fan::AllTerminals
+            | Fan::LittleFourWinds
+            | Fan::LittleThreeDragons
+            | Fan::AllHonors
+            | Fan::FourConcealedPungs
+            | Fan::PureTerminalChows => 64,
+            Fan::QuadrupleChow | Fan::FourPureShiftedPungs => 48,
+            Fan::FourPureShiftedChows | Fan::ThreeKongs | Fan::AllTerminalsAndHonors => 32,
+            Fan::SevenPairs
+            | Fan::GreaterHonorsAndKnittedTiles
+            | Fan::AllEvenPungs
+            | Fan::FullFlush
+            | Fan::PureTripleChow
+            | Fan::PureShiftedPungs
+            | Fan::UpperTiles
+            | Fan::MiddleTiles
+            | Fan::LowerTiles => 24,
+            Fan::PureStraight
+            | Fan::ThreeSuitedTerminalChows
+            | Fan::PureShiftedChows
+            | Fan::AllFives
+            | Fan::TriplePung
+            | Fan::ThreeConcealedPungs => 16,
+            Fan::LesserHonorsAndKnittedTiles
+            | Fan::KnittedStraight
+            | Fan::UpperFour
+            | Fan::LowerFour
+            | Fan::BigThreeWinds => 12,
+            Fan::MixedStraight
+            | Fan::ReversibleTiles
+            | Fan::MixedTripleChow
+            | Fan::MixedShiftedPungs
+            | Fan::ChickenHand
+            | Fan::LastTileDraw
+            | Fan::LastTileClaim
+            | Fan::OutWithReplacementTile
+            | Fan::RobbingTheKong => 8,
+            Fan::AllPungs
+            | Fan::HalfFlush
+            | Fan::MixedShiftedChows
+            | Fan::AllTypes
+            | Fan::MeldedHand
+            | Fan::TwoConcealedKongs
+            | Fan::TwoDragonPungs => 6,
+            Fan::OutsideHand | Fan::FullyConcealedHand | Fan::TwoMeldedKongs | Fan::LastTile => 4,
+            Fan::DragonPung
+            | Fan::PrevalentWind
+            | Fan::SeatWind
+            | Fan::ConcealedHand
+            | Fan::AllChows
+            | Fan::TileHog
+            | Fan::DoublePung
+            | Fan::TwoConcealedPungs
+            | Fan::ConcealedKong
+            | Fan::AllSimples => 2,
+            Fan::PureDoubleChow
+            | Fan::MixedDoubleChow
+            | Fan::ShortStraight
+            | Fan::TwoTerminalChows
+            | Fan::PungOfTerminalsOrHonors
+            | Fan::MeldedKong
+            | Fan::OneVoidedSuit
+            | Fan::NoHonors
+            | Fan::EdgeWait
+            | Fan::ClosedWait
+            | Fan::SingleWait
+            | Fan::SelfDrawn
+            | Fan::FlowerTiles => 1,
+        }
+    }
+
+    /// 成立时不再另计的番种(不重复原则)
+    pub fn excludes(self) -> &'static [Fan] {
+        use Fan::*;
+        match self {
+            BigFourWinds => &[BigThreeWinds, AllPungs, PrevalentWind, SeatWind, PungOfTerminalsOrHonors],
+            BigThreeDragons => &[TwoDragonPungs, DragonPung],
+            AllGreen => &[HalfFlush],
+            NineGates => &[FullFlush, ConcealedHand, PungOfTerminalsOrHonors, NoHonors],
+            FourKongs => &[AllPungs, SingleWait],
+            SevenShiftedPairs => &[FullFlush, FullyConcealedHand, ConcealedHand, SingleWait, SevenPairs, NoHonors],
+            ThirteenOrphans => &[AllTypes, FullyConcealedHand, ConcealedHand, SingleWait, AllTerminalsAndHonors],
+            AllTerminals => &[
+                AllPungs,
+                TriplePung,
+                DoublePung,
+                PungOfTerminalsOrHonors,
+                NoHonors,
+                AllTerminalsAndHonors,
+                OutsideHand,
+            ],
+            LittleFourWinds => &[BigThreeWinds],
+            LittleThreeDragons => &[TwoDragonPungs, DragonPung],
+            AllHonors => &[AllPungs, PungOfTerminalsOrHonors, AllTerminalsAndHonors, OutsideHand],
+            FourConcealedPungs => &[ConcealedHand, AllPungs, ThreeConcealedPungs, TwoConcealedPungs],
+            PureTerminalChows => {
+                &[SevenPairs, FullFlush, AllChows, PureDoubleChow, TwoTerminalChows, NoHonors, OneVoidedSuit]
+            }
+            QuadrupleChow => &[PureShiftedPungs, PureTripleChow, PureDoubleChow, TileHog],
+            FourPureShiftedPungs => &[PureTripleChow, PureShiftedPungs, AllPungs, DoublePung],
+            FourPureShiftedChows => &[PureShiftedChows, ShortStraight, TwoTerminalChows],
+            AllTerminalsAndHonors => &[AllPungs, PungOfTerminalsOrHonors, OutsideHand],
+            SevenPairs => &[FullyConcealedHand, ConcealedHand, SingleWait],
+            GreaterHonorsAndKnittedTiles => {
+                &[AllTypes, FullyConcealedHand, ConcealedHand, SingleWait, LesserHonorsAndKnittedTiles]
+            }
+            AllEvenPungs => &[AllPungs, AllSimples, NoHonors],
+            FullFlush => &[NoHonors, OneVoidedSuit],
+            PureTripleChow => &[PureShiftedPungs, PureDoubleChow],
+            PureShiftedPungs => &[PureTripleChow],
+            UpperTiles => &[UpperFour, NoHonors],
+            MiddleTiles => &[AllSimples, NoHonors],
+            LowerTiles => &[LowerFour, NoHonors],
+            PureStraight => &[ShortStraight, TwoTerminalChows],
+            ThreeSuitedTerminalChows => &[MixedDoubleChow, TwoTerminalChows, NoHonors, AllChows],
+            AllFives => &[AllSimples, NoHonors],
+            TriplePung => &[DoublePung],
+            ThreeConcealedPungs => &[TwoConcealedPungs],
+            LesserHonorsAndKnittedTiles => &[AllTypes, FullyConcealedHand, ConcealedHand, SingleWait],
+            UpperFour | LowerFour => &[NoHonors],
+            ReversibleTiles => &[OneVoidedSuit],
+            LastTileDraw | OutWithReplacementTile => &[SelfDrawn],
+            RobbingTheKong => &[LastTile],
+            MeldedHand => &[SingleWait],
+            TwoConcealedKongs => &[TwoConcealedPungs, ConcealedKong],
+            TwoDragonPungs => &[DragonPung],
+            FullyConcealedHand => &[ConcealedHand, SelfDrawn],
+            TwoMeldedKongs => &[MeldedKong],
+            AllChows | AllSimples => &[NoHonors],
+            _ => &[],
+        }
+    }
+}
+
+impl fmt::Display for Fan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// 一种拆分下的计番结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FanResult {
+    /// 成立的番种及其次数(如两个幺九刻、三张花牌)，按番数从高到低排列
+    pub fans: Vec<(Fan, u8)>,
+    /// 计番所用的和牌形
+    pub pattern: WinPattern,
+}
+
+impl FanResult {
+    /// 总番数(含花牌)
+    pub fn total(&self) -> u32 {
+        self.fans.iter().map(|&(fan, count)| fan.points() * count as u32).sum()
+    }
+
+    /// 花牌番数
+    pub fn flower_fan(&self) -> u32 {
+        self.fans.iter().filter(|(fan, _)| *fan == Fan::FlowerTiles).map(|&(_, count)| count as u32).sum()
+    }
+
+    /// 是否达到起和番数(花牌不计入)
+    pub fn is_valid(&self) -> bool {
+        self.total() - self.flower_fan() >= MIN_FAN
+    }
+
+    /// 可以展示给玩家的计番明细，重复计算的番种在名称后注明次数
+    pub fn items(&self) -> Vec<ScoreItem> {
+        self.fans
+            .iter()
+            .map(|&(fan, count)| {
+                let name = if count > 1 { format!("{}×{}", fan.name(), count) } else { fan.name().to_string() };
+                ScoreItem::new(name, fan.points() * count as u32)
+            })
+            .collect()
+    }
+}
+
+/// 计算和牌的番种，返回总番数最高的拆分
+///
+/// 牌形不能和牌时返回`None`；是否够8番起和见`FanResult::is_valid`。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::WinContext;
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::mcr::scoring::{evaluate_fan, Fan};
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let hand: Hand = "123456789m1155p".parse().unwrap();
+/// let result = evaluate_fan(&WinContext::new(hand, Tile::Suit(Suit::Dot, 5), Some(1))).unwrap();
+/// assert!(result.fans.contains(&(Fan::PureStraight, 1)));
+/// assert!(result.is_valid());
+/// ```
+pub fn evaluate_fan(context: &WinContext) -> Option<FanResult> {
+    let mut best: Option<FanResult> = None;
+    for pattern in winning_decompositions(&context.hand, context.win_tile, context.is_tsumo()) {
+        let result = evaluate_pattern(context, &pattern);
+        if best.as_ref().is_none_or(|b| result.total() > b.total()) {
+            best = Some(result);
+        }
+    }
+    best
+}
+
+/// 计算一种和牌形的番种
+pub fn evaluate_pattern(context: &WinContext, pattern: &WinPattern) -> FanResult {
+    let tiles = all_tiles(&context.hand, context.win_tile);
+    let mut fans = Vec::new();
+
+    match pattern.form {
+        WinForm::Kokushi => fans.push(Fan::ThirteenOrphans),
+        WinForm::Chiitoitsu => {
+            fans.push(if is_seven_shifted_pairs(&tiles) { Fan::SevenShiftedPairs } else { Fan::SevenPairs })
+        }
+        WinForm::HonorsAndKnitted => {
+            let honors = tiles.iter().filter(|t| t.is_honor()).count();
+            fans.push(if honors == 7 { Fan::GreaterHonorsAndKnittedTiles } else { Fan::LesserHonorsAndKnittedTiles });
+            // 数牌为完整的组合龙时另计组合龙
+            if honors == 5 {
+                fans.push(Fan::KnittedStraight);
+            }
+        }
+        WinForm::Standard => fans.extend(block_fans(context, pattern)),
+    }
+    fans.extend(tile_fans(context, &tiles));
+    fans.extend(situation_fans(context, pattern));
+
+    let excluded: HashSet<Fan> = {
+        let mut excluded = HashSet::new();
+        let mut ordered = fans.clone();
+        ordered.sort_by_key(|fan| std::cmp::Reverse(fan.points()));
+        for fan in ordered {
+            if !excluded.contains(&fan) {
+                excluded.extend(fan.excludes().iter().copied());
+            }
+        }
+        excluded
+    };
+    fans.retain(|fan| !excluded.contains(fan));
+    if fans.is_empty() {
+        fans.push(Fan::ChickenHand);
+    }
+
+    let flowers = context.hand.flowers().len() as u8;
+    let mut counted: Vec<(Fan, u8)> = Vec::new();
+    for fan in fans {
+        match counted.iter_mut().find(|(f, _)| *f == fan) {
+            Some((_, count)) => *count += 1,
+            None => counted.push((fan, 1)),
+        }
+    }
+    if flowers > 0 {
+        counted.push((Fan::FlowerTiles, flowers));
+    }
+    counted.sort_by_key(|&(fan, _)| Fan::ALL.iter().position(|&f| f == fan));
+
+    FanResult { fans: counted, pattern: pattern.clone() }
+}
+
+/// 国标麻将的点数变化：每位支付者付8分底分，放铳者(自摸时为所有人)另付和牌番数
+pub fn payments(context: &WinContext, fan: u32) -> Vec<i32> {
+    let count = context.player_count as usize;
+    let mut payments = vec![0; count];
+    let seat = context.seat as usize % count;
+    for (payer, payment) in payments.iter_mut().enumerate() {
+        if payer == seat {
+            continue;
+        }
+        let pays_fan = context.from.is_none_or(|from| from as usize == payer);
+        *payment = -(8 + if pays_fan { fan as i32 } else { 0 });
+    }
+    payments[seat] = -payments.iter().sum::<i32>();
+    payments
+}
+
+/// 由面子组成决定的番种
+fn block_fans(context: &WinContext, pattern: &WinPattern) -> Vec<Fan> {
+    let mut fans = Vec::new();
+    let blocks = &pattern.blocks;
+    let triplets: Vec<&Block> = blocks.iter().filter(|b| b.is_triplet_like()).collect();
+    let chows: Vec<(Suit, u8)> = blocks.iter().filter(|b| b.is_sequence()).filter_map(|b| b.tile.suit_and_number()).collect();
+    let number_pungs: Vec<(Suit, u8)> = triplets.iter().filter_map(|b| b.tile.suit_and_number()).collect();
+
+    // 风牌和箭牌
+    let winds = triplets.iter().filter(|b| b.tile.is_wind()).count();
+    let dragons = triplets.iter().filter(|b| b.tile.is_dragon()).count();
+    match (winds, pattern.pair.is_wind()) {
+        (4, _) => fans.push(Fan::BigFourWinds),
+        (3, true) => fans.push(Fan::LittleFourWinds),
+        (3, false) => fans.push(Fan::BigThreeWinds),
+        _ => {}
+    }
+    match (dragons, pattern.pair.is_dragon()) {
+        (3, _) => fans.push(Fan::BigThreeDragons),
+        (2, true) => fans.push(Fan::LittleThreeDragons),
+        (2, false) => fans.push(Fan::TwoDragonPungs),
+        (1, _) => fans.push(Fan::DragonPung),
+        _ => {}
+    }
+    for block in &triplets {
+        let prevalent = block.tile == Tile::Wind(context.round_wind);
+        let seat = block.tile == Tile::Wind(context.seat_wind);
+        if prevalent {
+            fans.push(Fan::PrevalentWind);
+        }
+        if seat {
+            fans.push(Fan::SeatWind);
+        }
+        // 三风刻以上的风刻以及箭刻、圈风刻、门风刻不再计幺九刻
+        let counted_wind = block.tile.is_wind() && (winds >= 3 || prevalent || seat);
+        if block.tile.is_terminal() || (block.tile.is_wind() && !counted_wind) {
+            fans.push(Fan::PungOfTerminalsOrHonors);
+        }
+    }
+
+    // 杠
+    let kans: Vec<&Block> = blocks.iter().filter(|b| b.kind == BlockKind::Kan).collect();
+    let concealed_kans = kans.iter().filter(|b| !b.open).count();
+    match (kans.len(), concealed_kans) {
+        (4, _) => fans.push(Fan::FourKongs),
+        (3, _) => fans.push(Fan::ThreeKongs),
+        (2, 2) => fans.push(Fan::TwoConcealedKongs),
+        (2, 0) => fans.push(Fan::TwoMeldedKongs),
+        (2, _) => fans.extend([Fan::ConcealedKong, Fan::MeldedKong]),
+        (1, 1) => fans.push(Fan::ConcealedKong),
+        (1, _) => fans.push(Fan::MeldedKong),
+        _ => {}
+    }
+
+    // 暗刻
+    match pattern.concealed_triplets() {
+        4 => fans.push(Fan::FourConcealedPungs),
+        3 => fans.push(Fan::ThreeConcealedPungs),
+        2 => fans.push(Fan::TwoConcealedPungs),
+        _ => {}
+    }
+
+    // 整手牌的组成
+    let pair_and_blocks = || std::iter::once(pattern.pair).chain(blocks.iter().map(|b| b.tile));
+    if triplets.len() == 4 {
+        fans.push(Fan::AllPungs);
+        let even = |tile: Tile| matches!(tile.suit_and_number(), Some((_, n)) if n % 2 == 0);
+        if pair_and_blocks().all(even) {
+            fans.push(Fan::AllEvenPungs);
+        }
+    }
+    // 组合龙加顺子和数牌雀头同样计平和
+    if triplets.is_empty() && !pattern.pair.is_honor() {
+        fans.push(Fan::AllChows);
+    }
+    if pattern.pair.is_terminal_or_honor() && blocks.iter().all(|b| b.has_terminal_or_honor()) {
+        fans.push(Fan::OutsideHand);
+    }
+    let has_five = |tiles: &[Tile]| tiles.iter().any(|t| matches!(t.suit_and_number(), Some((_, 5))));
+    if has_five(&[pattern.pair]) && blocks.iter().all(|b| has_five(&b.kinds())) {
+        fans.push(Fan::AllFives);
+    }
+
+    if blocks.iter().any(|b| b.kind == BlockKind::Knitted) {
+        fans.push(Fan::KnittedStraight);
+    }
+
+    // 顺子和刻子之间的组合
+    match terminal_chows(&chows, pattern.pair) {
+        Some(fan) => fans.push(fan),
+        None => fans.extend(combination_fans(&chows, four_chow_fan, three_chow_fan, two_chow_fan)),
+    }
+    fans.extend(combination_fans(&number_pungs, four_pung_fan, three_pung_fan, two_pung_fan));
+
+    fans
+}
+
+/// 只由全部牌的种类决定的番种，七对、十三幺和全不靠同样适用
+fn tile_fans(context: &WinContext, tiles: &[Tile]) -> Vec<Fan> {
+    let mut fans = Vec::new();
+    let mut suits: Vec<Suit> = Vec::new();
+    for (suit, _) in tiles.iter().filter_map(|t| t.suit_and_number()) {
+        if !suits.contains(&suit) {
+            suits.push(suit);
+        }
+    }
+    let numbers: Vec<u8> = tiles.iter().filter_map(|t| t.suit_and_number()).map(|(_, n)| n).collect();
+    let honors = tiles.iter().any(|t| t.is_honor());
+
+    if tiles.iter().all(|t| t.is_honor()) {
+        fans.push(Fan::AllHonors);
+    } else if tiles.iter().all(Tile::is_terminal) {
+        fans.push(Fan::AllTerminals);
+    } else if tiles.iter().all(Tile::is_terminal_or_honor) {
+        fans.push(Fan::AllTerminalsAndHonors);
+    }
+    if tiles.iter().all(|t| GREEN_TILES.contains(t)) {
+        fans.push(Fan::AllGreen);
+    }
+    if tiles.iter().all(|t| REVERSIBLE_TILES.contains(t)) {
+        fans.push(Fan::ReversibleTiles);
+    }
+    if is_nine_gates(context) {
+        fans.push(Fan::NineGates);
+    }
+
+    match (suits.len(), honors) {
+        (1, false) => fans.push(Fan::FullFlush),
+        (1, true) => fans.push(Fan::HalfFlush),
+        (2, _) => fans.push(Fan::OneVoidedSuit),
+        _ => {}
+    }
+    if suits.len() == 3 && tiles.iter().any(|t| t.is_wind()) && tiles.iter().any(|t| t.is_dragon()) {
+        fans.push(Fan::AllTypes);
+    }
+
+    if !honors {
+        fans.push(Fan::NoHonors);
+        if numbers.iter().all(|&n| n >= 7) {
+            fans.push(Fan::UpperTiles);
+        } else if numbers.iter().all(|&n| (4..=6).contains(&n)) {
+            fans.push(Fan::MiddleTiles);
+        } else if numbers.iter().all(|&n| n <= 3) {
+            fans.push(Fan::LowerTiles);
+        } else if numbers.iter().all(|&n| n >= 6) {
+            fans.push(Fan::UpperFour);
+        } else if numbers.iter().all(|&n| n <= 4) {
+            fans.push(Fan::LowerFour);
+        }
+        if numbers.iter().all(|&n| (2..=8).contains(&n)) {
+            fans.push(Fan::AllSimples);
+        }
+    }
+
+    // 四归一：同种牌四张而没有开杠
+    let kan_tiles: Vec<Tile> = context
+        .hand
+        .melds()
+        .iter()
+        .filter(|m| m.tiles.len() == 4)
+        .map(|m| m.get_key_tile().to_normal())
+        .collect();
+    let mut seen: Vec<Tile> = Vec::new();
+    for &tile in tiles {
+        if seen.contains(&tile) {
+            continue;
+        }
+        seen.push(tile);
+        if tiles.iter().filter(|&&t| t == tile).count() == 4 && !kan_tiles.contains(&tile) {
+            fans.push(Fan::TileHog);
+        }
+    }
+
+    fans
+}
+
+/// 由和牌方式和场况决定的番种
+fn situation_fans(context: &WinContext, pattern: &WinPattern) -> Vec<Fan> {
+    let mut fans = Vec::new();
+    let hand = &context.hand;
+    let tsumo = context.is_tsumo();
+
+    if hand.is_closed() {
+        fans.push(if tsumo { Fan::FullyConcealedHand } else { Fan::ConcealedHand });
+    } else if !tsumo && hand.melds().len() == 4 && hand.melds().iter().all(|m| m.is_open()) {
+        fans.push(Fan::MeldedHand);
+    }
+    if tsumo {
+        fans.push(Fan::SelfDrawn);
+    }
+    if context.haitei {
+        fans.push(Fan::LastTileDraw);
+    }
+    if context.houtei {
+        fans.push(Fan::LastTileClaim);
+    }
+    if context.rinshan {
+        fans.push(Fan::OutWithReplacementTile);
+    }
+    if context.chankan {
+        fans.push(Fan::RobbingTheKong);
+    }
+    if context.last_of_kind {
+        fans.push(Fan::LastTile);
+    }
+
+    // 边张、坎张、单钓将只在听一张牌时计，和牌张落在组合龙中时不计
+    let knitted_win = pattern.win_block.is_some_and(|i| pattern.blocks[i].kind == BlockKind::Knitted);
+    let wait_fan = match pattern.wait {
+        WaitType::Penchan => Some(Fan::EdgeWait),
+        WaitType::Kanchan => Some(Fan::ClosedWait),
+        WaitType::Tanki => Some(Fan::SingleWait),
+        WaitType::Ryanmen | WaitType::Shanpon => None,
+    };
+    if let Some(fan) = wait_fan.filter(|_| !knitted_win && winning_tiles(hand).len() == 1) {
+        fans.push(fan);
+    }
+
+    fans
+}
+
+/// 九莲宝灯：门前清，和牌前为同一花色的1112345678999
+fn is_nine_gates(context: &WinContext) -> bool {
+    if !context.hand.melds().is_empty() {
+        return false;
+    }
+    let Some((suit, _)) = context.win_tile.suit_and_number() else {
+        return false;
+    };
+    let mut tiles = context.hand.concealed_tiles();
+    tiles.extend(context.hand.drawn());
+    let mut counts = [0u8; 9];
+    for tile in tiles {
+        match tile.suit_and_number() {
+            Some((s, n)) if s == suit => counts[n as usize - 1] += 1,
+            _ => return false,
+        }
+    }
+    counts == NINE_GATES
+}
+
+/// 连七对：同一花色序数相连的七个对子
+fn is_seven_shifted_pairs(tiles: &[Tile]) -> bool {
+    let Some((suit, _)) = tiles.first().and_then(|t| t.suit_and_number()) else {
+        return false;
+    };
+    let mut numbers = Vec::new();
+    for tile in tiles {
+        match tile.suit_and_number() {
+            Some((s, n)) if s == suit => numbers.push(n),
+            _ => return false,
+        }
+    }
+    numbers.sort_unstable();
+    numbers.dedup();
+    numbers.len() == 7 && numbers[6] - numbers[0] == 6
+}
+
+/// 一色双龙会和三色双龙会：两组老少副加五的雀头
+fn terminal_chows(chows: &[(Suit, u8)], pair: Tile) -> Option<Fan> {
+    let (pair_suit, 5) = pair.suit_and_number()? else {
+        return None;
+    };
+    if chows.len() != 4 {
+        return None;
+    }
+    let mut suits: Vec<Suit> = Vec::new();
+    for suit in chows.iter().map(|&(s, _)| s) {
+        if !suits.contains(&suit) {
+            suits.push(suit);
+        }
+    }
+    let terminal_pair = |suit: Suit| {
+        let numbers = |n: u8| chows.iter().filter(|&&c| c == (suit, n)).count();
+        numbers(1) > 0 && numbers(1) == numbers(7) && numbers(1) + numbers(7) == chows.iter().filter(|c| c.0 == suit).count()
+    };
+    match suits.as_slice() {
+        [suit] if *suit == pair_suit && terminal_pair(*suit) => Some(Fan::PureTerminalChows),
+        [a, b] if *a != pair_suit && *b != pair_suit && terminal_pair(*a) && terminal_pair(*b) => {
+            Some(Fan::ThreeSuitedTerminalChows)
+        }
+        _ => None,
+    }
+}
+
+/// 按套算一次原则计算一组顺子或刻子之间的组合番种
+///
+/// 四组都参与的番种成立时不再计其他组合；否则在"三组的番种加第四组与其中一组的组合"和
+/// "两两组合"之间取番数高者。两两组合时每组尚未组合过的牌只能与已组合过的牌组合一次，
+/// 即所取的组合不能成环，按番数从高到低贪心选取即为最优。
+fn combination_fans<T: Copy>(
+    items: &[T],
+    four: fn(&[T]) -> Option<Fan>,
+    three: fn(T, T, T) -> Option<Fan>,
+    two: fn(T, T) -> Option<Fan>,
+) -> Vec<Fan> {
+    let n = items.len();
+    if n == 4 {
+        if let Some(fan) = four(items) {
+            return vec![fan];
+        }
+    }
+
+    let total = |fans: &[Fan]| fans.iter().map(|f| f.points()).sum::<u32>();
+    let mut best = pair_fans(items, two);
+    for i in 0..n {
+        for j in i + 1..n {
+            for k in j + 1..n {
+                let Some(fan) = three(items[i], items[j], items[k]) else {
+                    continue;
+                };
+                let mut candidate = vec![fan];
+                // 第四组只能与三组中的一组再组合一次
+                let extra = (0..n)
+                    .filter(|&r| r != i && r != j && r != k)
+                    .flat_map(|r| [i, j, k].map(|s| two(items[r], items[s])))
+                    .flatten()
+                    .filter(|f| !fan.excludes().contains(f))
+                    .max_by_key(|f| f.points());
+                candidate.extend(extra);
+                if total(&candidate) > total(&best) {
+                    best = candidate;
+                }
+            }
+        }
+    }
+    best
+}
+
+/// 两两组合的番种，所取组合不成环
+fn pair_fans<T: Copy>(items: &[T], two: fn(T, T) -> Option<Fan>) -> Vec<Fan> {
+    let mut edges: Vec<(usize, usize, Fan)> = Vec::new();
+    for i in 0..items.len() {
+        for j in i + 1..items.len() {
+            if let Some(fan) = two(items[i], items[j]) {
+                edges.push((i, j, fan));
+            }
+        }
+    }
+    // 番数相同时按番种表的顺序，同花色的组合在前
+    edges.sort_by_key(|&(_, _, fan)| (std::cmp::Reverse(fan.points()), Fan::ALL.iter().position(|&f| f == fan)));
+
+    let mut group: Vec<usize> = (0..items.len()).collect();
+    let mut fans = Vec::new();
+    for (i, j, fan) in edges {
+        let (gi, gj) = (group[i], group[j]);
+        if gi == gj {
+            continue;
+        }
+        for g in group.iter_mut().filter(|g| **g == gj) {
+            *g = gi;
+        }
+        fans.push(fan);
+    }
+    fans
+}
+
+/// 按序数排序后的三个或四个数是否等差，公差在`steps`中
+fn is_shifted(numbers: &mut [u8], steps: &[u8]) -> bool {
+    numbers.sort_unstable();
+    let step = numbers[1].wrapping_sub(numbers[0]);
+    steps.contains(&step) && numbers.windows(2).all(|w| w[1].wrapping_sub(w[0]) == step)
+}
+
+/// 花色是否全部相同
+fn same_suit(suits: &[Suit]) -> bool {
+    suits.iter().all(|&s| s == suits[0])
+}
+
+/// 花色是否两两不同
+fn distinct_suits(suits: &[Suit]) -> bool {
+    suits.iter().enumerate().all(|(i, s)| !suits[i + 1..].contains(s))
+}
+
+fn four_chow_fan(chows: &[(Suit, u8)]) -> Option<Fan> {
+    let suits: Vec<Suit> = chows.iter().map(|&(s, _)| s).collect();
+    let mut numbers: Vec<u8> = chows.iter().map(|&(_, n)| n).collect();
+    if !same_suit(&suits) {
+        return None;
+    }
+    if is_shifted(&mut numbers, &[0]) {
+        Some(Fan::QuadrupleChow)
+    } else if is_shifted(&mut numbers, &[1, 2]) {
+        Some(Fan::FourPureShiftedChows)
+    } else {
+        None
+    }
+}
+
+fn three_chow_fan(a: (Suit, u8), b: (Suit, u8), c: (Suit, u8)) -> Option<Fan> {
+    let suits = [a.0, b.0, c.0];
+    let mut numbers = [a.1, b.1, c.1];
+    if same_suit(&suits) {
+        if is_shifted(&mut numbers, &[0]) {
+            Some(Fan::PureTripleChow)
+        } else if numbers == [1, 4, 7] {
+            Some(Fan::PureStraight)
+        } else if is_shifted(&mut numbers, &[1, 2]) {
+            Some(Fan::PureShiftedChows)
+        } else {
+            None
+        }
+    } else if distinct_suits(&suits) {
+        if is_shifted(&mut numbers, &[0]) {
+            Some(Fan::MixedTripleChow)
+        } else if numbers == [1, 4, 7] {
+            Some(Fan::MixedStraight)
+        } else if is_shifted(&mut numbers, &[1]) {
+            Some(Fan::MixedShiftedChows)
+        } else {
+            None
+        }
+    } else {
+        None
+    }
+}
+
+fn two_chow_fan(a: (Suit, u8), b: (Suit, u8)) -> Option<Fan> {
+    let (low, high) = (a.1.min(b.1), a.1.max(b.1));
+    match (a.0 == b.0, high - low) {
+        (true, 0) => Some(Fan::PureDoubleChow),
+        (false, 0) => Some(Fan::MixedDoubleChow),
+        (true, 3) => Some(Fan::ShortStraight),
+        (true, 6) => Some(Fan::TwoTerminalChows),
+        _ => None,
+    }
+}
+
+fn four_pung_fan(pungs: &[(Suit, u8)]) -> Option<Fan> {
+    let suits: Vec<Suit> = pungs.iter().map(|&(s, _)| s).collect();
+    let mut numbers: Vec<u8> = pungs.iter().map(|&(_, n)| n).collect();
+    (same_suit(&suits) && is_shifted(&mut numbers, &[1])).then_some(Fan::FourPureShiftedPungs)
+}
+
+fn three_pung_fan(a: (Suit, u8), b: (Suit, u8), c: (Suit, u8)) -> Option<Fan> {
+    let suits = [a.0, b.0, c.0];
+    let mut numbers = [a.1, b.1, c.1];
+    if same_suit(&suits) && is_shifted(&mut numbers, &[1]) {
+        Some(Fan::PureShiftedPungs)
+    } else if distinct_suits(&suits) && is_shifted(&mut numbers, &[0]) {
+        Some(Fan::TriplePung)
+    } else if distinct_suits(&suits) && is_shifted(&mut numbers, &[1]) {
+        Some(Fan::MixedShiftedPungs)
+    } else {
+        None
+    }
+}
+
+fn two_pung_fan(a: (Suit, u8), b: (Suit, u8)) -> Option<Fan> {
+    (a.0 != b.0 && a.1 == b.1).then_some(Fan::DoublePung)
+}
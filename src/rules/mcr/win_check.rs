@@ -0,0 +1,171 @@
+// src/rules/mcr/win_check.rs
+//
+// 国标麻将的和牌判定
+// 除四面子一雀头和十三幺外，国标麻将还承认以下牌形：
+// - 七对：七个对子，四张相同的牌可以当作两个对子；
+// - 全不靠：十四张互不相靠的单张，数牌取自同一组组合龙(147、258、369分属三种花色)，
+//   字牌各不相同，七种字牌齐全时为七星不靠；
+// - 组合龙：一组完整的组合龙加一组面子和一个雀头。
+// `hand::decompositions`只给出立直麻将的牌形，这里在`rules::common::win_patterns`的
+// 基础上补充上述拆分，组合龙按三组`Knitted`面子表示。
+
+use crate::hand::{decompose_counts, Decomposition, Hand, WaitType, WinForm, TILE_KIND_COUNT};
+use crate::rules::common::{all_tiles, win_patterns, Block, WinPattern};
+use crate::tile::{Suit, Tile};
+
+/// 组合龙中三种花色的所有排列，依次对应147、258、369
+const KNITTED_SUIT_ORDERS: [[Suit; 3]; 6] = [
+    [Suit::Character, Suit::Dot, Suit::Bamboo],
+    [Suit::Character, Suit::Bamboo, Suit::Dot],
+    [Suit::Dot, Suit::Character, Suit::Bamboo],
+    [Suit::Dot, Suit::Bamboo, Suit::Character],
+    [Suit::Bamboo, Suit::Character, Suit::Dot],
+    [Suit::Bamboo, Suit::Dot, Suit::Character],
+];
+
+/// 手牌加入和牌张后是否构成国标麻将的和牌形(不考虑是否够8番)
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::mcr::win_check::is_winning_hand;
+/// use majiang_core::tile::{Tile, Dragon};
+///
+/// // 全不靠：147万258筒369条中的九张加五种字牌
+/// let hand: Hand = "147m258p36s12345z".parse().unwrap();
+/// assert!(is_winning_hand(&hand, Tile::Dragon(Dragon::Green)));
+/// ```
+pub fn is_winning_hand(hand: &Hand, win_tile: Tile) -> bool {
+    !winning_decompositions(hand, win_tile, false).is_empty()
+}
+
+/// 列出手牌加入和牌张后国标麻将的所有和牌形
+///
+/// 一般形和十三幺与`win_patterns`相同；七对改用国标麻将的判定(允许四张相同的牌)，
+/// 另外加入全不靠和组合龙的拆分。
+///
+/// # 参数
+/// * `hand` - 和牌前的手牌(不含和牌张)
+/// * `win_tile` - 和牌张
+/// * `tsumo` - 是否自摸
+pub fn winning_decompositions(hand: &Hand, win_tile: Tile, tsumo: bool) -> Vec<WinPattern> {
+    let mut patterns: Vec<WinPattern> = win_patterns(hand, win_tile, tsumo)
+        .into_iter()
+        .filter(|p| p.form != WinForm::Chiitoitsu)
+        .collect();
+    let mut counts = hand.tile_counts();
+    let win_id = win_tile.to_id() as usize;
+    if win_id >= TILE_KIND_COUNT {
+        return patterns;
+    }
+    counts[win_id] += 1;
+
+    if hand.melds().is_empty() && counts.iter().map(|&c| c as usize).sum::<usize>() == 14 {
+        if counts.iter().all(|&c| c % 2 == 0) {
+            patterns.push(single_form(WinForm::Chiitoitsu, win_tile));
+        }
+        if counts.iter().all(|&c| c <= 1) && knitted_order(&counts).is_some() {
+            patterns.push(single_form(WinForm::HonorsAndKnitted, win_tile));
+        }
+    }
+
+    for pattern in knitted_straight_patterns(hand, &counts, win_id, tsumo) {
+        if !patterns.contains(&pattern) {
+            patterns.push(pattern);
+        }
+    }
+    patterns
+}
+
+/// 国标麻将的听牌：加入后构成和牌形的所有牌，已经用完四张的牌不计
+pub fn winning_tiles(hand: &Hand) -> Vec<Tile> {
+    (0..TILE_KIND_COUNT as u8)
+        .filter_map(Tile::from_id)
+        .filter(|&tile| all_tiles(hand, tile).iter().filter(|&&t| t == tile).count() <= 4)
+        .filter(|&tile| is_winning_hand(hand, tile))
+        .collect()
+}
+
+/// 组合龙中某一组的三张牌
+fn knitted_tiles(order: &[Suit; 3], index: usize) -> [Tile; 3] {
+    let start = index as u8 + 1;
+    [start, start + 3, start + 6].map(|n| Tile::Suit(order[index], n))
+}
+
+/// 数牌全部取自同一组组合龙时返回该组合龙的花色排列
+fn knitted_order(counts: &[u8; TILE_KIND_COUNT]) -> Option<&'static [Suit; 3]> {
+    KNITTED_SUIT_ORDERS.iter().find(|order| {
+        (0..TILE_KIND_COUNT as u8)
+            .filter_map(Tile::from_id)
+            .filter(|t| counts[t.to_id() as usize] > 0 && t.is_suit())
+            .all(|t| (0..3).any(|index| knitted_tiles(order, index).contains(&t)))
+    })
+}
+
+/// 没有面子的特殊牌形(七对和全不靠)，和牌张记为单骑
+fn single_form(form: WinForm, win_tile: Tile) -> WinPattern {
+    WinPattern { form, pair: win_tile.to_normal(), blocks: Vec::new(), wait: WaitType::Tanki, win_block: None }
+}
+
+/// 组合龙加一组面子一个雀头的所有拆分
+///
+/// 和牌张落在组合龙中时记为嵌张，`win_block`指向含和牌张的那一组。
+fn knitted_straight_patterns(hand: &Hand, counts: &[u8; TILE_KIND_COUNT], win_id: usize, tsumo: bool) -> Vec<WinPattern> {
+    let mut patterns = Vec::new();
+    if hand.melds().len() > 1 {
+        return patterns;
+    }
+    let melds = hand.melds().len() + 3;
+
+    for order in &KNITTED_SUIT_ORDERS {
+        let knitted: Vec<Tile> = (0..3).flat_map(|index| knitted_tiles(order, index)).collect();
+        let mut remainder = *counts;
+        let mut complete = true;
+        for tile in &knitted {
+            let id = tile.to_id() as usize;
+            if remainder[id] == 0 {
+                complete = false;
+                break;
+            }
+            remainder[id] -= 1;
+        }
+        if !complete {
+            continue;
+        }
+        let blocks: Vec<Block> = (0..3).map(|index| Block::knitted(knitted_tiles(order, index)[0])).collect();
+        let build = |decomposition: &Decomposition| {
+            let mut pattern = WinPattern::from_decomposition(hand, decomposition, tsumo);
+            let at = decomposition.sets.len();
+            pattern.blocks.splice(at..at, blocks.iter().copied());
+            pattern
+        };
+
+        // 和牌张在面子或雀头中
+        if remainder[win_id] > 0 {
+            patterns.extend(decompose_counts(&mut remainder, win_id, melds).iter().map(build));
+        }
+
+        // 和牌张在组合龙中：剩余部分的拆分与和牌张无关，取其中任意一种牌代入后去重
+        let Some(win_index) = (0..3).find(|&index| knitted_tiles(order, index).iter().any(|t| t.to_id() as usize == win_id))
+        else {
+            continue;
+        };
+        let Some(any_id) = (0..TILE_KIND_COUNT).find(|&id| remainder[id] > 0) else {
+            continue;
+        };
+        let mut seen: Vec<Decomposition> = Vec::new();
+        for mut decomposition in decompose_counts(&mut remainder, any_id, melds) {
+            decomposition.wait = WaitType::Tanki;
+            decomposition.win_block = None;
+            if seen.contains(&decomposition) {
+                continue;
+            }
+            let mut pattern = build(&decomposition);
+            pattern.wait = WaitType::Kanchan;
+            pattern.win_block = Some(decomposition.sets.len() + win_index);
+            seen.push(decomposition);
+            patterns.push(pattern);
+        }
+    }
+    patterns
+}
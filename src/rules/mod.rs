@@ -0,0 +1,219 @@
+// src/rules/mod.rs
+//
+// 规则模块的入口文件
+// 不同的麻将规则在牌墙构成、能否吃碰立直、起和条件、计分和流局结算上各不相同。
+// `RuleSet`把这些差异集中到一个trait中，`GameState`持有一个`dyn RuleSet`，
+// 因此同一个状态机可以按立直麻将(含三人麻将)、国标麻将、上海麻将或四川麻将进行，大厅在开桌时选择规则即可。
+
+pub mod common;
+pub mod mcr;
+pub mod riichi;
+pub mod shanghai;
+pub mod sichuan;
+
+use std::fmt;
+
+use crate::action::{AbortiveDrawKind, ActionOptions, WinShape};
+use crate::errors::MajiangResult;
+use crate::game::{Event, GameState, RonPolicy, WinContext};
+use crate::hand::{is_winning_shape, Hand};
+use crate::meld::KanType;
+use crate::player::Player;
+use crate::tile::Tile;
+use crate::wall::{DeadWallConfig, ReplacementSource, Wall, WallConfig};
+
+pub use common::{AbortiveDrawOptions, DoraTiming, ExchangeDirection, Furiten};
+
+pub use mcr::McrRules;
+pub use riichi::{RiichiRules, SanmaRules};
+pub use shanghai::ShanghaiRules;
+pub use sichuan::SichuanRules;
+
+/// 花牌的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowerHandling {
+    /// 没有花牌
+    None,
+    /// 花牌亮出后补牌
+    Replace,
+}
+
+/// 一个计分项目(役、番种、花牌、符等)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreItem {
+    /// 项目名称，可直接展示给玩家
+    pub name: String,
+    /// 项目的值(番数、符数或花数，单位由规则决定)
+    pub value: u32,
+}
+
+impl ScoreItem {
+    /// 创建计分项目
+    pub fn new(name: impl Into<String>, value: u32) -> Self {
+        Self { name: name.into(), value }
+    }
+}
+
+impl fmt::Display for ScoreItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.value)
+    }
+}
+
+/// 一次和牌的得分
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WinScore {
+    /// 计分项目，按规则的展示顺序排列
+    pub items: Vec<ScoreItem>,
+    /// 各座位的点数变化，和牌者为正，支付者为负
+    pub payments: Vec<i32>,
+}
+
+impl WinScore {
+    /// 没有任何计分项目、也没有点数变化的得分
+    pub fn empty(player_count: u8) -> Self {
+        Self { items: Vec::new(), payments: vec![0; player_count as usize] }
+    }
+}
+
+/// 一种麻将规则
+///
+/// 只有牌墙、岭上牌区、动作选项和名称是必须提供的；其余方法都有只看牌形、
+/// 不产生点数变化的默认实现，具体规则按需覆盖。
+///
+/// # 示例
+/// ```
+/// use std::sync::Arc;
+/// use majiang_core::game::{GameConfig, GameState};
+/// use majiang_core::rules::{McrRules, RuleSet};
+///
+/// let rules: Arc<dyn RuleSet> = Arc::new(McrRules::default());
+/// let config = GameConfig::from_rules(rules.as_ref(), 42);
+/// let game = GameState::with_rules(config, rules).unwrap();
+/// assert_eq!(game.rules().name(), "国标麻将");
+/// assert!(!game.config().options.allow_riichi);
+/// ```
+pub trait RuleSet: fmt::Debug + Send + Sync {
+    /// 规则名称
+    fn name(&self) -> &'static str;
+
+    /// 牌墙配置
+    fn wall_config(&self) -> WallConfig;
+
+    /// 岭上牌区(补花区)配置
+    fn dead_wall_config(&self) -> Option<DeadWallConfig>;
+
+    /// 吃、立直、途中流局等动作选项
+    fn action_options(&self) -> ActionOptions;
+
+    /// 自摸和荣和时使用的和牌形判定，默认只承认一般形、七对子和国士无双
+    fn win_shape(&self) -> WinShape {
+        is_winning_shape
+    }
+
+    /// 多家同时荣和时的处理方式
+    fn ron_policy(&self) -> RonPolicy {
+        RonPolicy::HeadBump
+    }
+
+    /// 花牌的处理方式
+    fn flower_handling(&self) -> FlowerHandling {
+        FlowerHandling::None
+    }
+
+    /// 补花和杠后补牌的默认来源，可以在`GameConfig`中改变
+    fn replacement_source(&self) -> ReplacementSource {
+        ReplacementSource::DeadWall
+    }
+
+    /// 玩家此刻的振听状态(振听时不能荣和)，没有振听或不振听时返回`None`
+    fn furiten(&self, _player: &Player) -> Option<Furiten> {
+        None
+    }
+
+    /// 玩家放过了他家打出(或加杠)的`tile`，有振听的规则在这里记录同巡振听等状态
+    fn record_missed_win(&self, _player: &mut Player, _tile: Tile) {}
+
+    /// 立直时向供托支付的点数，设置了`GameConfig::points`时点数不足的玩家不能立直
+    fn riichi_deposit(&self) -> i32 {
+        0
+    }
+
+    /// 杠后翻开新宝牌指示牌的时机
+    fn kan_dora_timing(&self, _kan_type: KanType) -> DoraTiming {
+        DoraTiming::Immediate
+    }
+
+    /// 成立的途中流局；九种九牌能否宣言以`ActionOptions::allow_abortive_draws`为准
+    fn abortive_draws(&self) -> AbortiveDrawOptions {
+        AbortiveDrawOptions::NONE
+    }
+
+    /// 打出的牌无人荣和后成立的途中流局(如四风连打)，此时打出的牌已记入河中
+    fn abortive_after_discard(&self, _game: &GameState) -> Option<AbortiveDrawKind> {
+        None
+    }
+
+    /// 亮出配牌中的花牌并补牌，补花途中牌墙摸完时返回`false`
+    ///
+    /// 只在`flower_handling`为`Replace`时调用，`hand`为去掉花牌后的配牌。
+    fn replace_dealt_flowers(
+        &self,
+        wall: &mut Wall,
+        hand: &mut Hand,
+        seat: u8,
+        flowers: &[Tile],
+        source: ReplacementSource,
+        events: &mut Vec<Event>,
+    ) -> MajiangResult<bool> {
+        common::replace_dealt_flowers(wall, hand, seat, flowers, source, events)
+    }
+
+    /// 亮出摸到的花牌并补牌，补花途中牌墙摸完时返回`false`
+    fn declare_flower(
+        &self,
+        wall: &mut Wall,
+        hand: &mut Hand,
+        seat: u8,
+        flower: Tile,
+        source: ReplacementSource,
+        events: &mut Vec<Event>,
+    ) -> MajiangResult<bool> {
+        common::declare_flower(wall, hand, seat, flower, source, events)
+    }
+
+    /// 配牌后换三张的方向，`None`表示不换三张
+    fn tile_exchange(&self) -> Option<ExchangeDirection> {
+        None
+    }
+
+    /// 配牌(及换三张)后是否定缺
+    fn chooses_void_suit(&self) -> bool {
+        false
+    }
+
+    /// 是否血战到底：一家和牌后其余玩家继续，直到只剩一家未和牌或荒牌
+    fn bloody_battle(&self) -> bool {
+        false
+    }
+
+    /// 开杠时各座位的点数变化(如刮风下雨)，明杠的`from`为放杠者
+    fn kan_payments(&self, game: &GameState, _seat: u8, _kan_type: KanType, _from: Option<u8>) -> Vec<i32> {
+        vec![0; game.players().len()]
+    }
+
+    /// 牌形上已经和牌时，是否满足规则的起和条件(如有役、够番)
+    fn is_valid_win(&self, context: &WinContext) -> bool {
+        self.score_win(context).is_some()
+    }
+
+    /// 计算一次和牌的得分，不满足起和条件时返回`None`
+    fn score_win(&self, context: &WinContext) -> Option<WinScore> {
+        Some(WinScore::empty(context.player_count))
+    }
+
+    /// 荒牌流局时各座位的点数变化，此时`game.outcome()`已是荒牌流局的结果
+    fn exhaustive_draw_payments(&self, game: &GameState) -> Vec<i32> {
+        vec![0; game.players().len()]
+    }
+}
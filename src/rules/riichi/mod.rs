@@ -0,0 +1,126 @@
+// src/rules/riichi/mod.rs
+//
+// 日本立直麻将规则
+
+pub mod riichi_specific;
+pub mod sanma;
+pub mod score;
+pub mod yaku;
+
+use crate::action::{AbortiveDrawKind, ActionOptions};
+use crate::game::{GameState, RonPolicy, RoundOutcome, WinContext};
+use crate::meld::KanType;
+use crate::player::Player;
+use crate::rules::{RuleSet, WinScore};
+use crate::tile::Tile;
+use crate::wall::{DeadWallConfig, WallConfig};
+
+pub use riichi_specific::{AbortiveDrawOptions, Furiten, DoraTiming, RIICHI_DEPOSIT};
+pub use sanma::{sanma_payments, SanmaRules, TsumoPayment};
+pub use score::{
+    base_points, fu, is_nagashi_mangan, nagashi_payments, noten_payments, payments, score_hand, score_result, Limit,
+    ScoreBreakdown, ScoreOptions, NOTEN_PENALTY,
+};
+pub use yaku::{dora_from_indicator, evaluate_all, evaluate_pattern, evaluate_yaku, Yaku, YakuOptions, YakuResult};
+
+/// 立直麻将规则：136张牌(含赤五)，14张岭上牌区，可吃可立直，和牌需要有役
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RiichiRules {
+    /// 多家荣和的处理方式
+    pub ron_policy: RonPolicy,
+    /// 成立的途中流局
+    pub abortive_draws: AbortiveDrawOptions,
+    /// 役种判定选项
+    pub yaku: YakuOptions,
+    /// 计分选项
+    pub score: ScoreOptions,
+}
+
+impl Default for RiichiRules {
+    /// 允许双响、所有途中流局都成立
+    fn default() -> Self {
+        Self {
+            ron_policy: RonPolicy::Multiple,
+            abortive_draws: AbortiveDrawOptions::default(),
+            yaku: YakuOptions::default(),
+            score: ScoreOptions::default(),
+        }
+    }
+}
+
+impl RuleSet for RiichiRules {
+    fn name(&self) -> &'static str {
+        "立直麻将"
+    }
+
+    fn wall_config(&self) -> WallConfig {
+        WallConfig::Riichi
+    }
+
+    fn dead_wall_config(&self) -> Option<DeadWallConfig> {
+        Some(DeadWallConfig::Riichi { dora_indicators: 5, uradora_indicators: 5 })
+    }
+
+    fn action_options(&self) -> ActionOptions {
+        ActionOptions {
+            riichi_min_wall: riichi_specific::RIICHI_MIN_WALL,
+            allow_abortive_draws: self.abortive_draws.nine_terminals,
+            ..ActionOptions::default()
+        }
+    }
+
+    fn abortive_draws(&self) -> AbortiveDrawOptions {
+        self.abortive_draws
+    }
+
+    fn abortive_after_discard(&self, game: &GameState) -> Option<AbortiveDrawKind> {
+        riichi_specific::abortive_after_discard(game, self.abortive_draws)
+    }
+
+    fn ron_policy(&self) -> RonPolicy {
+        self.ron_policy
+    }
+
+    fn furiten(&self, player: &Player) -> Option<Furiten> {
+        riichi_specific::furiten(player)
+    }
+
+    fn record_missed_win(&self, player: &mut Player, tile: Tile) {
+        riichi_specific::record_missed_win(player, tile);
+    }
+
+    fn riichi_deposit(&self) -> i32 {
+        RIICHI_DEPOSIT
+    }
+
+    /// 暗杠立即翻开杠宝牌指示牌，明杠和加杠在打牌时翻开
+    fn kan_dora_timing(&self, kan_type: KanType) -> DoraTiming {
+        riichi_specific::kan_dora_timing(kan_type)
+    }
+
+    fn is_valid_win(&self, context: &WinContext) -> bool {
+        !evaluate_all(context, &self.yaku).is_empty()
+    }
+
+    /// 列出役种和宝牌，点数变化含本场棒；符的明细见`score_hand`
+    fn score_win(&self, context: &WinContext) -> Option<WinScore> {
+        let breakdown = score_hand(context, &self.yaku, &self.score)?;
+        Some(WinScore { items: breakdown.yaku, payments: breakdown.payments })
+    }
+
+    /// 有流局满贯时按满贯自摸支付，否则支付不听罚符
+    fn exhaustive_draw_payments(&self, game: &GameState) -> Vec<i32> {
+        let count = game.players().len() as u8;
+        if self.score.nagashi_mangan {
+            let nagashi: Vec<u8> =
+                game.players().iter().filter(|p| is_nagashi_mangan(p.discards())).map(|p| p.seat()).collect();
+            if !nagashi.is_empty() {
+                return nagashi_payments(&nagashi, game.dealer(), count);
+            }
+        }
+        match game.outcome() {
+            Some(RoundOutcome::ExhaustiveDraw { tenpai }) => noten_payments(tenpai, count),
+            _ => vec![0; count as usize],
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// src/rules/riichi/riichi_specific.rs
+//
+// 立直麻将特有的机制
+// - 立直宣言：门前清、听牌、牌墙中至少还有4张牌，并向供托支付1000点；
+//   立直后手牌锁定，只能摸切，暗杠只在不改变听牌和面子构成时允许；
+// - 振听：舍张振听(听的牌在自己的河中)、同巡振听(放过和牌张后到自己下次打牌为止)
+//   和立直振听(立直后放过和牌张，本局不再解除)，振听时不能荣和；
+// - 杠宝牌：暗杠立即翻开新的宝牌指示牌，明杠和加杠在杠后打出一张牌时翻开；
+// - 途中流局：九种九牌、四风连打、四家立直、四杠散了和三家和，每种都可以单独关闭。
+//
+// `RiichiRules`和`SanmaRules`通过`RuleSet::furiten`、`RuleSet::record_missed_win`、
+// `RuleSet::kan_dora_timing`和`RuleSet::abortive_after_discard`把这里的判定交给`GameState`。立直宣言牌和立直后暗杠的牌形判定与规则无关，
+// 由`action::legal_actions`使用`hand::tenpai_discards`和`hand::kan_keeps_waits`完成。
+
+use crate::action::AbortiveDrawKind;
+use crate::game::GameState;
+use crate::hand::{is_winning_shape, winning_tiles, Hand};
+use crate::meld::{KanType, MeldType};
+use crate::player::{DiscardRecord, Player};
+use crate::tile::Tile;
+
+pub use crate::rules::common::{AbortiveDrawOptions, DoraTiming, Furiten};
+
+/// 立直时向供托支付的点数
+pub const RIICHI_DEPOSIT: i32 = 1000;
+
+/// 立直时牌墙中至少需要剩余的牌数，通过`ActionOptions::riichi_min_wall`交给动作判定
+pub const RIICHI_MIN_WALL: usize = 4;
+
+/// 玩家此刻的振听状态，不振听时返回`None`
+///
+/// 同时满足多种振听时按立直振听、舍张振听、同巡振听的顺序返回其一。
+pub fn furiten(player: &Player) -> Option<Furiten> {
+    if player.riichi_furiten {
+        return Some(Furiten::Riichi);
+    }
+    if is_discard_furiten(&player.hand, &player.discards) {
+        return Some(Furiten::Discard);
+    }
+    player.temporary_furiten.then_some(Furiten::Temporary)
+}
+
+/// 是否舍张振听：听的牌中有一张在自己的河中(含被鸣走的牌)
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::{parse_tiles, Hand};
+/// use majiang_core::player::DiscardRecord;
+/// use majiang_core::rules::riichi::riichi_specific::is_discard_furiten;
+///
+/// // 听1万和4万，河中有4万
+/// let hand: Hand = "23m456p789s11122z".parse().unwrap();
+/// let tile = parse_tiles("4m").unwrap()[0];
+/// let discards = [DiscardRecord { tile, tsumogiri: false, riichi: false, claimed: true }];
+/// assert!(is_discard_furiten(&hand, &discards));
+/// assert!(!is_discard_furiten(&hand, &[]));
+/// ```
+pub fn is_discard_furiten(hand: &Hand, discards: &[DiscardRecord]) -> bool {
+    let waits = winning_tiles(hand);
+    discards.iter().any(|d| waits.iter().any(|w| w.is_same_kind(&d.tile)))
+}
+
+/// 放过了一张和牌张：记为同巡振听，立直后记为立直振听
+///
+/// `tile`不是玩家听的牌时不做任何改变。
+pub(crate) fn record_missed_win(player: &mut Player, tile: Tile) {
+    if !is_winning_shape(&player.hand, tile) {
+        return;
+    }
+    player.temporary_furiten = true;
+    if player.riichi {
+        player.riichi_furiten = true;
+    }
+}
+
+/// 各种杠翻开杠宝牌指示牌的时机：暗杠立即翻开，明杠和加杠在打牌时翻开
+pub fn kan_dora_timing(kan_type: KanType) -> DoraTiming {
+    match kan_type {
+        KanType::Closed => DoraTiming::Immediate,
+        KanType::Open | KanType::Added => DoraTiming::AfterDiscard,
+    }
+}
+
+/// 四风连打：四人对局中每家的河里只有一张牌，且都是同一种风牌
+///
+/// 第一巡是否被鸣牌打断由调用方判断。
+///
+/// # 示例
+/// ```
+/// use majiang_core::player::DiscardRecord;
+/// use majiang_core::rules::riichi::riichi_specific::is_four_winds;
+/// use majiang_core::tile::{Tile, Wind};
+///
+/// let east = DiscardRecord { tile: Tile::Wind(Wind::East), tsumogiri: false, riichi: false, claimed: false };
+/// let river = [east];
+/// assert!(is_four_winds(&[&river, &river, &river, &river]));
+/// assert!(!is_four_winds(&[&river, &river, &river, &[]]));
+/// ```
+pub fn is_four_winds(rivers: &[&[DiscardRecord]]) -> bool {
+    let Some(first) = rivers.first().and_then(|r| r.first()) else {
+        return false;
+    };
+    rivers.len() == 4
+        && first.tile.is_wind()
+        && rivers.iter().all(|r| r.len() == 1 && r[0].tile.is_same_kind(&first.tile))
+}
+
+/// 四杠散了：场上合计四个以上的杠且不是同一位玩家所开(一人四杠时可能成立四杠子)
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::riichi::riichi_specific::is_four_kans;
+///
+/// let two: Hand = "(1111m)[2222p]123s5z".parse().unwrap();
+/// let four: Hand = "(1111m)[2222p][3333s](4444p)5z".parse().unwrap();
+/// let none: Hand = "123456789m1234p".parse().unwrap();
+/// assert!(is_four_kans(&[&two, &two, &none, &none]));
+/// assert!(!is_four_kans(&[&two, &none, &none, &none]));
+/// // 一人四杠时不流局
+/// assert!(!is_four_kans(&[&four, &none, &none, &none]));
+/// ```
+pub fn is_four_kans(hands: &[&Hand]) -> bool {
+    let kans: Vec<usize> = hands
+        .iter()
+        .map(|h| h.melds().iter().filter(|m| matches!(m.meld_type, MeldType::Kan(_))).count())
+        .collect();
+    kans.iter().sum::<usize>() >= 4 && !kans.contains(&4)
+}
+
+/// 打出的牌无人荣和后成立的途中流局：四风连打、四家立直、四杠散了
+///
+/// 同时成立多种时按上述顺序返回其一，`options`中关闭的途中流局不成立。
+pub fn abortive_after_discard(game: &GameState, options: AbortiveDrawOptions) -> Option<AbortiveDrawKind> {
+    let players = game.players();
+    let rivers: Vec<&[DiscardRecord]> = players.iter().map(|p| p.discards()).collect();
+    let hands: Vec<&Hand> = players.iter().map(|p| p.hand()).collect();
+    let kinds = [
+        (AbortiveDrawKind::FourWinds, game.uninterrupted && is_four_winds(&rivers)),
+        (AbortiveDrawKind::FourRiichi, players.len() == 4 && players.iter().all(|p| p.riichi)),
+        (AbortiveDrawKind::FourKans, is_four_kans(&hands)),
+    ];
+    kinds.into_iter().find(|&(kind, met)| met && options.allows(kind)).map(|(kind, _)| kind)
+}
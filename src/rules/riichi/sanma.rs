@@ -0,0 +1,155 @@
+// src/rules/riichi/sanma.rs
+//
+// 三人立直麻将(三麻)
+// - 牌墙去掉二万至八万共108张，一万指示九万为宝牌，岭上牌区与四人麻将相同；
+// - 不能吃；北可以拔出作为拔北宝牌，从岭上牌区补牌，海底前移一张；
+// - 自摸时没有北家支付，按规则选择自摸损(缺少的部分不补)或北家折半(由另外两家平分)。
+//
+// 役种、符数、振听和途中流局沿用`RiichiRules`的设置，需要四家的途中流局在三人时不会成立。
+
+use crate::action::{AbortiveDrawKind, ActionOptions};
+use crate::game::{GameState, RonPolicy, WinContext};
+use crate::meld::KanType;
+use crate::player::Player;
+use crate::rules::riichi::score::{payments, round_up, score_hand, HONBA_POINTS};
+use crate::rules::riichi::RiichiRules;
+use crate::rules::{AbortiveDrawOptions, DoraTiming, Furiten, RuleSet, WinScore};
+use crate::tile::Tile;
+use crate::wall::{DeadWallConfig, WallConfig};
+
+/// 三人麻将的玩家人数
+pub const SANMA_PLAYERS: u8 = 3;
+
+/// 自摸时缺少的北家支付的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TsumoPayment {
+    /// 自摸损：两家按四人麻将的份额支付，北家的份额不补
+    #[default]
+    TsumoLoss,
+    /// 北家折半：北家的份额由两家平分
+    NorthBisection,
+}
+
+/// 三人麻将的点数支付(含本场棒)
+///
+/// 荣和与四人麻将相同。自摸时本场棒按自摸损每家100点、北家折半每家150点计。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::WinContext;
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::riichi::sanma::{sanma_payments, TsumoPayment};
+/// use majiang_core::tile::{Suit, Tile};
+///
+/// let hand: Hand = "123p456p789s11z23s".parse().unwrap();
+/// let mut context = WinContext::new(hand, Tile::Suit(Suit::Bamboo, 1), None);
+/// context.player_count = 3;
+///
+/// // 庄家自摸满贯
+/// assert_eq!(sanma_payments(&context, 2000, TsumoPayment::TsumoLoss), [8000, -4000, -4000]);
+/// assert_eq!(sanma_payments(&context, 2000, TsumoPayment::NorthBisection), [12000, -6000, -6000]);
+/// ```
+pub fn sanma_payments(context: &WinContext, base_points: u32, tsumo: TsumoPayment) -> Vec<i32> {
+    if !context.is_tsumo() {
+        return payments(context, base_points);
+    }
+    let count = context.player_count;
+    let mut result = vec![0; count as usize];
+    let base = base_points as i32;
+    let dealer = context.dealer_seat();
+    // 不存在的北家是子家，庄家自摸时付2倍，子家自摸时付1倍
+    let absent = if context.is_dealer() { 2 } else { 1 };
+    let honba = context.honba as i32 * HONBA_POINTS;
+    for seat in (0..count).filter(|&s| s != context.seat) {
+        let multiplier = if context.is_dealer() || seat == dealer { 2 } else { 1 };
+        let (share, honba_share) = match tsumo {
+            TsumoPayment::TsumoLoss => (base * multiplier, honba / 3),
+            TsumoPayment::NorthBisection => (base * multiplier + base * absent / 2, honba / 2),
+        };
+        let amount = round_up(share as u32, 100) as i32 + honba_share;
+        result[seat as usize] -= amount;
+        result[context.seat as usize] += amount;
+    }
+    result
+}
+
+/// 三人立直麻将规则
+///
+/// # 示例
+/// ```
+/// use majiang_core::rules::{RuleSet, SanmaRules};
+/// use majiang_core::wall::WallConfig;
+///
+/// let rules = SanmaRules::default();
+/// assert_eq!(rules.wall_config(), WallConfig::Sanma);
+/// assert_eq!(rules.action_options().player_count, 3);
+/// assert!(rules.action_options().allow_kita && !rules.action_options().allow_chi);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SanmaRules {
+    /// 役种、计分和途中流局等与四人麻将共通的设置
+    pub riichi: RiichiRules,
+    /// 自摸时缺少的北家支付的处理方式
+    pub tsumo_payment: TsumoPayment,
+}
+
+impl RuleSet for SanmaRules {
+    fn name(&self) -> &'static str {
+        "三人立直麻将"
+    }
+
+    fn wall_config(&self) -> WallConfig {
+        WallConfig::Sanma
+    }
+
+    fn dead_wall_config(&self) -> Option<DeadWallConfig> {
+        self.riichi.dead_wall_config()
+    }
+
+    fn action_options(&self) -> ActionOptions {
+        ActionOptions { player_count: SANMA_PLAYERS, allow_chi: false, allow_kita: true, ..self.riichi.action_options() }
+    }
+
+    fn ron_policy(&self) -> RonPolicy {
+        self.riichi.ron_policy
+    }
+
+    fn furiten(&self, player: &Player) -> Option<Furiten> {
+        self.riichi.furiten(player)
+    }
+
+    fn record_missed_win(&self, player: &mut Player, tile: Tile) {
+        self.riichi.record_missed_win(player, tile);
+    }
+
+    fn riichi_deposit(&self) -> i32 {
+        self.riichi.riichi_deposit()
+    }
+
+    fn kan_dora_timing(&self, kan_type: KanType) -> DoraTiming {
+        self.riichi.kan_dora_timing(kan_type)
+    }
+
+    fn abortive_draws(&self) -> AbortiveDrawOptions {
+        self.riichi.abortive_draws
+    }
+
+    fn abortive_after_discard(&self, game: &GameState) -> Option<AbortiveDrawKind> {
+        self.riichi.abortive_after_discard(game)
+    }
+
+    fn is_valid_win(&self, context: &WinContext) -> bool {
+        self.riichi.is_valid_win(context)
+    }
+
+    /// 与四人麻将相同地列出役种和宝牌，自摸的支付按`tsumo_payment`计算
+    fn score_win(&self, context: &WinContext) -> Option<WinScore> {
+        let breakdown = score_hand(context, &self.riichi.yaku, &self.riichi.score)?;
+        let payments = sanma_payments(context, breakdown.base_points, self.tsumo_payment);
+        Some(WinScore { items: breakdown.yaku, payments })
+    }
+
+    fn exhaustive_draw_payments(&self, game: &GameState) -> Vec<i32> {
+        self.riichi.exhaustive_draw_payments(game)
+    }
+}
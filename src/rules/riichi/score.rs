@@ -0,0 +1,389 @@
+// src/rules/riichi/score.rs
+//
+// 立直麻将的符数计算与点数支付
+// 符数：副底20符(七对子固定25符)，加上门前清荣和、自摸、听牌形状、雀头和面子的符，
+// 最后进位到十位。平和自摸固定20符，副露的平和形荣和计为30符。
+//
+// 点数：基本点 = 符 × 2^(番+2)，达到满贯后按满贯、跳满、倍满、三倍满、累计役满封顶。
+// 荣和时放铳者支付基本点的4倍(庄家6倍)，自摸时庄家支付2倍、闲家各支付1倍
+// (庄家自摸时每家2倍)，均进位到百位。本场棒每本300点，自摸时由各家分摊。
+//
+// 荒牌流局：有流局满贯时按满贯自摸支付(不含本场棒)，否则不听的玩家向听牌的玩家
+// 支付共3000点的不听罚符。
+
+use std::fmt;
+
+use crate::game::WinContext;
+use crate::hand::{WaitType, WinForm};
+use crate::player::DiscardRecord;
+use crate::rules::common::{Block, BlockKind};
+use crate::rules::riichi::yaku::{evaluate_all, Yaku, YakuOptions, YakuResult};
+use crate::rules::ScoreItem;
+use crate::tile::Tile;
+
+/// 满贯的基本点
+const MANGAN_BASE: u32 = 2000;
+
+/// 每本场的点数
+pub(crate) const HONBA_POINTS: i32 = 300;
+
+/// 荒牌流局时不听罚符的总数
+pub const NOTEN_PENALTY: i32 = 3000;
+
+/// 计分的规则选项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoreOptions {
+    /// 4番30符和3番60符是否切上为满贯
+    pub kiriage_mangan: bool,
+    /// 13番以上是否计为累计役满(否则为三倍满)
+    pub counted_yakuman: bool,
+    /// 连风牌雀头是否计4符(否则计2符)
+    pub double_wind_pair_4_fu: bool,
+    /// 荒牌流局时是否有流局满贯
+    pub nagashi_mangan: bool,
+}
+
+impl Default for ScoreOptions {
+    /// 无切上满贯、有累计役满、连风雀头4符、有流局满贯
+    fn default() -> Self {
+        Self { kiriage_mangan: false, counted_yakuman: true, double_wind_pair_4_fu: true, nagashi_mangan: true }
+    }
+}
+
+/// 满贯以上的封顶等级
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// 满贯
+    Mangan,
+    /// 跳满
+    Haneman,
+    /// 倍满
+    Baiman,
+    /// 三倍满
+    Sanbaiman,
+    /// 累计役满(13番以上)
+    KazoeYakuman,
+    /// 役满，记录倍数
+    Yakuman(u8),
+}
+
+impl Limit {
+    /// 封顶时的基本点
+    pub fn base_points(self) -> u32 {
+        match self {
+            Limit::Mangan => MANGAN_BASE,
+            Limit::Haneman => MANGAN_BASE * 3 / 2,
+            Limit::Baiman => MANGAN_BASE * 2,
+            Limit::Sanbaiman => MANGAN_BASE * 3,
+            Limit::KazoeYakuman => MANGAN_BASE * 4,
+            Limit::Yakuman(multiplier) => MANGAN_BASE * 4 * multiplier as u32,
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limit::Mangan => write!(f, "满贯"),
+            Limit::Haneman => write!(f, "跳满"),
+            Limit::Baiman => write!(f, "倍满"),
+            Limit::Sanbaiman => write!(f, "三倍满"),
+            Limit::KazoeYakuman => write!(f, "累计役满"),
+            Limit::Yakuman(1) => write!(f, "役满"),
+            Limit::Yakuman(n) => write!(f, "{}倍役满", n),
+        }
+    }
+}
+
+/// 一次和牌的计分明细
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    /// 成立的役和宝牌，值为番数(役满时为倍数)
+    pub yaku: Vec<ScoreItem>,
+    /// 符的明细，值为符数(进位前)
+    pub fu_items: Vec<ScoreItem>,
+    /// 番数合计(含宝牌)，役满时为0
+    pub han: u8,
+    /// 进位后的符数
+    pub fu: u8,
+    /// 封顶等级，未达到满贯时为`None`
+    pub limit: Option<Limit>,
+    /// 基本点
+    pub base_points: u32,
+    /// 各座位的点数变化(含本场棒，不含立直供托)
+    pub payments: Vec<i32>,
+}
+
+impl ScoreBreakdown {
+    /// 和牌者得到的点数
+    pub fn total(&self, seat: u8) -> i32 {
+        self.payments.get(seat as usize).copied().unwrap_or(0)
+    }
+}
+
+/// 计算和牌的点数，在所有有役的拆分中取和牌者得点最高的一种
+///
+/// 没有役时返回`None`。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::WinContext;
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::riichi::{score_hand, ScoreOptions, YakuOptions};
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// // 子家立直平和荣和，1番以外再无役：2番30符，放铳者支付2000点
+/// let hand: Hand = "123m456p789s23m55p".parse().unwrap();
+/// let mut context = WinContext::new(hand, Tile::Suit(Suit::Character, 4), Some(0));
+/// context.seat = 1;
+/// context.seat_wind = majiang_core::tile::Wind::South;
+/// context.riichi = true;
+///
+/// let score = score_hand(&context, &YakuOptions::default(), &ScoreOptions::default()).unwrap();
+/// assert_eq!((score.han, score.fu), (2, 30));
+/// assert_eq!(score.payments, vec![-2000, 2000, 0, 0]);
+/// ```
+pub fn score_hand(context: &WinContext, yaku_options: &YakuOptions, options: &ScoreOptions) -> Option<ScoreBreakdown> {
+    evaluate_all(context, yaku_options)
+        .iter()
+        .map(|result| score_result(context, result, options))
+        .rev()
+        .max_by_key(|score| (score.total(context.seat), score.han, score.fu))
+}
+
+/// 按一种拆分的役种判定结果计分
+pub fn score_result(context: &WinContext, result: &YakuResult, options: &ScoreOptions) -> ScoreBreakdown {
+    let mut yaku: Vec<ScoreItem> =
+        result.yaku.iter().map(|&(y, han)| ScoreItem::new(y.name(), han as u32)).collect();
+    let dora = [
+        ("宝牌", result.dora),
+        ("赤宝牌", result.aka_dora),
+        ("里宝牌", result.ura_dora),
+        ("拔北宝牌", result.nuki_dora),
+    ];
+    yaku.extend(dora.iter().filter(|(_, n)| *n > 0).map(|&(name, n)| ScoreItem::new(name, n as u32)));
+
+    let yakuman = result.yakuman();
+    let (fu_items, fu) = if yakuman > 0 { (Vec::new(), 0) } else { fu(context, result, options) };
+    let han = result.han();
+    let (limit, base_points) = if yakuman > 0 {
+        let limit = Limit::Yakuman(yakuman);
+        (Some(limit), limit.base_points())
+    } else {
+        base_points(han, fu, options)
+    };
+
+    ScoreBreakdown {
+        yaku,
+        fu_items,
+        han,
+        fu,
+        limit,
+        base_points,
+        payments: payments(context, base_points),
+    }
+}
+
+/// 计算符数，返回明细和进位后的符数
+pub fn fu(context: &WinContext, result: &YakuResult, options: &ScoreOptions) -> (Vec<ScoreItem>, u8) {
+    let pattern = &result.pattern;
+    if pattern.form == WinForm::Chiitoitsu {
+        return (vec![ScoreItem::new("七对子", 25)], 25);
+    }
+
+    let closed = context.hand.is_closed();
+    let pinfu = result.yaku.iter().any(|&(y, _)| y == Yaku::Pinfu);
+    let mut items = vec![ScoreItem::new("副底", 20)];
+    if pinfu {
+        // 平和自摸不计自摸符，平和荣和只有门前清荣和的10符
+        if !context.is_tsumo() {
+            items.push(ScoreItem::new("门前清荣和", 10));
+        }
+        return (items, if context.is_tsumo() { 20 } else { 30 });
+    }
+
+    if closed && !context.is_tsumo() {
+        items.push(ScoreItem::new("门前清荣和", 10));
+    }
+    if context.is_tsumo() {
+        items.push(ScoreItem::new("自摸", 2));
+    }
+    match pattern.wait {
+        WaitType::Kanchan => items.push(ScoreItem::new("嵌张", 2)),
+        WaitType::Penchan => items.push(ScoreItem::new("边张", 2)),
+        WaitType::Tanki => items.push(ScoreItem::new("单骑", 2)),
+        WaitType::Ryanmen | WaitType::Shanpon => {}
+    }
+
+    let pair = pair_fu(context, pattern.pair, options);
+    if pair > 0 {
+        items.push(ScoreItem::new(format!("雀头{}", pattern.pair), pair as u32));
+    }
+    for block in &pattern.blocks {
+        let fu = block_fu(block);
+        if fu > 0 {
+            items.push(ScoreItem::new(block_name(block), fu as u32));
+        }
+    }
+
+    let total: u32 = items.iter().map(|item| item.value).sum();
+    // 副露的平和形荣和没有任何加符，按30符计算
+    if total == 20 && !closed {
+        items.push(ScoreItem::new("副露平和形", 10));
+        return (items, 30);
+    }
+    (items, round_up(total, 10) as u8)
+}
+
+/// 番数和符数对应的封顶等级和基本点
+pub fn base_points(han: u8, fu: u8, options: &ScoreOptions) -> (Option<Limit>, u32) {
+    let limit = match han {
+        0..=4 => {
+            let base = fu as u32 * (1 << (han as u32 + 2));
+            let kiriage = options.kiriage_mangan && ((han == 4 && fu == 30) || (han == 3 && fu == 60));
+            if base < MANGAN_BASE && !kiriage {
+                return (None, base);
+            }
+            Limit::Mangan
+        }
+        5 => Limit::Mangan,
+        6 | 7 => Limit::Haneman,
+        8..=10 => Limit::Baiman,
+        11 | 12 => Limit::Sanbaiman,
+        _ if options.counted_yakuman => Limit::KazoeYakuman,
+        _ => Limit::Sanbaiman,
+    };
+    (Some(limit), limit.base_points())
+}
+
+/// 按基本点计算各座位的点数变化(含本场棒)
+///
+/// 荣和时放铳者支付基本点的4倍(和牌者为庄家时6倍)；自摸时庄家支付2倍、闲家支付1倍，
+/// 和牌者为庄家时每家支付2倍。本场棒每本300点，荣和时由放铳者支付，自摸时由各家平分。
+pub fn payments(context: &WinContext, base_points: u32) -> Vec<i32> {
+    let count = context.player_count;
+    let mut payments = vec![0; count as usize];
+    let base = base_points as i32;
+    let honba = context.honba as i32 * HONBA_POINTS;
+    let dealer = context.dealer_seat();
+
+    match context.from {
+        Some(from) => {
+            let multiplier = if context.is_dealer() { 6 } else { 4 };
+            let amount = round_up((base * multiplier) as u32, 100) as i32 + honba;
+            payments[from as usize] -= amount;
+            payments[context.seat as usize] += amount;
+        }
+        None => {
+            let payers = count.saturating_sub(1).max(1) as i32;
+            for seat in (0..count).filter(|&s| s != context.seat) {
+                let multiplier = if context.is_dealer() || seat == dealer { 2 } else { 1 };
+                let amount = round_up((base * multiplier) as u32, 100) as i32 + honba / payers;
+                payments[seat as usize] -= amount;
+                payments[context.seat as usize] += amount;
+            }
+        }
+    }
+    payments
+}
+
+/// 荒牌流局时的不听罚符：不听的玩家平分支付3000点，听牌的玩家平分收取
+///
+/// 全员听牌或全员不听时没有点数变化。
+///
+/// # 示例
+/// ```
+/// use majiang_core::rules::riichi::noten_payments;
+///
+/// assert_eq!(noten_payments(&[2], 4), [-1000, -1000, 3000, -1000]);
+/// assert_eq!(noten_payments(&[0, 3], 4), [1500, -1500, -1500, 1500]);
+/// assert_eq!(noten_payments(&[], 4), [0; 4]);
+/// ```
+pub fn noten_payments(tenpai: &[u8], player_count: u8) -> Vec<i32> {
+    let mut payments = vec![0; player_count as usize];
+    let winners = tenpai.len() as i32;
+    let losers = player_count as i32 - winners;
+    if winners == 0 || losers <= 0 {
+        return payments;
+    }
+    for (seat, payment) in payments.iter_mut().enumerate() {
+        *payment = if tenpai.contains(&(seat as u8)) { NOTEN_PENALTY / winners } else { -NOTEN_PENALTY / losers };
+    }
+    payments
+}
+
+/// 是否流局满贯：河中全是幺九牌，且没有一张被其他玩家鸣走
+pub fn is_nagashi_mangan(discards: &[DiscardRecord]) -> bool {
+    !discards.is_empty() && discards.iter().all(|d| !d.claimed && d.tile.is_terminal_or_honor())
+}
+
+/// 流局满贯的点数变化：每位达成者按满贯自摸收取，不含本场棒
+///
+/// # 示例
+/// ```
+/// use majiang_core::rules::riichi::nagashi_payments;
+///
+/// // 庄家为座位0，子家座位2流局满贯
+/// assert_eq!(nagashi_payments(&[2], 0, 4), [-4000, -2000, 8000, -2000]);
+/// assert_eq!(nagashi_payments(&[0], 0, 4), [12000, -4000, -4000, -4000]);
+/// ```
+pub fn nagashi_payments(nagashi: &[u8], dealer: u8, player_count: u8) -> Vec<i32> {
+    let mut payments = vec![0; player_count as usize];
+    let base = MANGAN_BASE as i32;
+    for &winner in nagashi {
+        for seat in (0..player_count).filter(|&s| s != winner) {
+            let amount = if winner == dealer || seat == dealer { base * 2 } else { base };
+            payments[seat as usize] -= amount;
+            payments[winner as usize] += amount;
+        }
+    }
+    payments
+}
+
+/// 雀头的符：三元牌、自风、场风各2符，连风牌按选项计4符或2符
+fn pair_fu(context: &WinContext, pair: Tile, options: &ScoreOptions) -> u8 {
+    if pair.is_dragon() {
+        return 2;
+    }
+    let seat = pair == Tile::Wind(context.seat_wind);
+    let round = pair == Tile::Wind(context.round_wind);
+    match (seat, round) {
+        (true, true) if options.double_wind_pair_4_fu => 4,
+        (true, _) | (_, true) => 2,
+        _ => 0,
+    }
+}
+
+/// 面子的符：明刻2符，暗刻4符，明杠8符，暗杠16符；幺九牌加倍
+fn block_fu(block: &Block) -> u8 {
+    let base = match (block.kind, block.open) {
+        (BlockKind::Sequence | BlockKind::Knitted, _) => return 0,
+        (BlockKind::Triplet, true) => 2,
+        (BlockKind::Triplet, false) => 4,
+        (BlockKind::Kan, true) => 8,
+        (BlockKind::Kan, false) => 16,
+    };
+    if block.tile.is_terminal_or_honor() {
+        base * 2
+    } else {
+        base
+    }
+}
+
+/// 面子在符明细中的名称，如"暗刻中"、"明杠5万"
+fn block_name(block: &Block) -> String {
+    let kind = match (block.kind, block.open) {
+        (BlockKind::Triplet, true) => "明刻",
+        (BlockKind::Triplet, false) => "暗刻",
+        (BlockKind::Kan, true) => "明杠",
+        (BlockKind::Kan, false) => "暗杠",
+        (BlockKind::Sequence, _) => "顺子",
+        (BlockKind::Knitted, _) => "组合龙",
+    };
+    format!("{}{}", kind, block.tile)
+}
+
+/// 向上取整到`unit`的倍数
+pub(crate) fn round_up(value: u32, unit: u32) -> u32 {
+    value.div_ceil(unit) * unit
+}
@@ -0,0 +1,585 @@
+// src/rules/riichi/yaku.rs
+//
+// 立直麻将的役种判定
+// 对和牌的每一种拆分(`WinPattern`)分别判定成立的役和役满，加上宝牌、赤宝牌、里宝牌和拔北宝牌，
+// 最后取番数最高的拆分。役满成立时不再计算普通役和宝牌。
+//
+// 副露后不成立的役(门前清限定)不计；三色同顺、一气通贯、混全带幺九、纯全带幺九、
+// 混一色和清一色副露时减一番。
+
+use std::fmt;
+
+use crate::game::WinContext;
+use crate::hand::{WaitType, WinForm};
+use crate::rules::common::{all_tiles, win_patterns, Block, WinPattern};
+use crate::tile::{Dragon, Suit, Tile, Wind};
+
+/// 绿一色可以使用的牌：二三四六八索和发
+const GREEN_TILES: [Tile; 6] = [
+    Tile::Suit(Suit::Bamboo, 2),
+    Tile::Suit(Suit::Bamboo, 3),
+    Tile::Suit(Suit::Bamboo, 4),
+    Tile::Suit(Suit::Bamboo, 6),
+    Tile::Suit(Suit::Bamboo, 8),
+    Tile::Dragon(Dragon::Green),
+];
+
+/// 九莲宝灯要求的一到九每种牌的最少张数
+const NINE_GATES: [u8; 9] = [3, 1, 1, 1, 1, 1, 1, 1, 3];
+
+/// 立直麻将的役
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Yaku {
+    /// 立直
+    Riichi,
+    /// 两立直
+    DoubleRiichi,
+    /// 一发
+    Ippatsu,
+    /// 门前清自摸和
+    MenzenTsumo,
+    /// 平和
+    Pinfu,
+    /// 断幺九
+    Tanyao,
+    /// 一杯口
+    Iipeikou,
+    /// 役牌：自风
+    SeatWind,
+    /// 役牌：场风
+    RoundWind,
+    /// 役牌：白
+    Haku,
+    /// 役牌：发
+    Hatsu,
+    /// 役牌：中
+    Chun,
+    /// 海底摸月
+    Haitei,
+    /// 河底捞鱼
+    Houtei,
+    /// 岭上开花
+    Rinshan,
+    /// 抢杠
+    Chankan,
+    /// 三色同顺
+    Sanshoku,
+    /// 三色同刻
+    SanshokuDoukou,
+    /// 一气通贯
+    Ittsu,
+    /// 混全带幺九
+    Chanta,
+    /// 纯全带幺九
+    Junchan,
+    /// 对对和
+    Toitoi,
+    /// 三暗刻
+    Sanankou,
+    /// 三杠子
+    Sankantsu,
+    /// 小三元
+    Shousangen,
+    /// 混老头
+    Honroutou,
+    /// 七对子
+    Chiitoitsu,
+    /// 两杯口
+    Ryanpeikou,
+    /// 混一色
+    Honitsu,
+    /// 清一色
+    Chinitsu,
+    /// 国士无双
+    Kokushi,
+    /// 四暗刻
+    Suuankou,
+    /// 大三元
+    Daisangen,
+    /// 小四喜
+    Shousuushii,
+    /// 大四喜
+    Daisuushii,
+    /// 字一色
+    Tsuuiisou,
+    /// 清老头
+    Chinroutou,
+    /// 绿一色
+    Ryuuiisou,
+    /// 九莲宝灯
+    Chuuren,
+    /// 四杠子
+    Suukantsu,
+    /// 天和
+    Tenhou,
+    /// 地和
+    Chiihou,
+    /// 人和(仅在`YakuOptions::renhou_yakuman`开启时作为役满)
+    Renhou,
+}
+
+impl Yaku {
+    /// 役的中文名称
+    pub fn name(self) -> &'static str {
+        match self {
+            Yaku::Riichi => "立直",
+            Yaku::DoubleRiichi => "两立直",
+            Yaku::Ippatsu => "一发",
+            Yaku::MenzenTsumo => "门前清自摸和",
+            Yaku::Pinfu => "平和",
+            Yaku::Tanyao => "断幺九",
+            Yaku::Iipeikou => "一杯口",
+            Yaku::SeatWind => "自风",
+            Yaku::RoundWind => "场风",
+            Yaku::Haku => "役牌白",
+            Yaku::Hatsu => "役牌发",
+            Yaku::Chun => "役牌中",
+            Yaku::Haitei => "海底摸月",
+            Yaku::Houtei => "河底捞鱼",
+            Yaku::Rinshan => "岭上开花",
+            Yaku::Chankan => "抢杠",
+            Yaku::Sanshoku => "三色同顺",
+            Yaku::SanshokuDoukou => "三色同刻",
+            Yaku::Ittsu => "一气通贯",
+            Yaku::Chanta => "混全带幺九",
+            Yaku::Junchan => "纯全带幺九",
+            Yaku::Toitoi => "对对和",
+            Yaku::Sanankou => "三暗刻",
+            Yaku::Sankantsu => "三杠子",
+            Yaku::Shousangen => "小三元",
+            Yaku::Honroutou => "混老头",
+            Yaku::Chiitoitsu => "七对子",
+            Yaku::Ryanpeikou => "两杯口",
+            Yaku::Honitsu => "混一色",
+            Yaku::Chinitsu => "清一色",
+            Yaku::Kokushi => "国士无双",
+            Yaku::Suuankou => "四暗刻",
+            Yaku::Daisangen => "大三元",
+            Yaku::Shousuushii => "小四喜",
+            Yaku::Daisuushii => "大四喜",
+            Yaku::Tsuuiisou => "字一色",
+            Yaku::Chinroutou => "清老头",
+            Yaku::Ryuuiisou => "绿一色",
+            Yaku::Chuuren => "九莲宝灯",
+            Yaku::Suukantsu => "四杠子",
+            Yaku::Tenhou => "天和",
+            Yaku::Chiihou => "地和",
+            Yaku::Renhou => "人和",
+        }
+    }
+
+    /// 是否为役满
+    pub fn is_yakuman(self) -> bool {
+        matches!(
+            self,
+            Yaku::Kokushi
+                | Yaku::Suuankou
+                | Yaku::Daisangen
+                | Yaku::Shousuushii
+                | Yaku::Daisuushii
+                | Yaku::Tsuuiisou
+                | Yaku::Chinroutou
+                | Yaku::Ryuuiisou
+                | Yaku::Chuuren
+                | Yaku::Suukantsu
+                | Yaku::Tenhou
+                | Yaku::Chiihou
+                | Yaku::Renhou
+        )
+    }
+}
+
+impl fmt::Display for Yaku {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// 役种判定的规则选项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YakuOptions {
+    /// 副露后断幺九是否成立(食断)
+    pub open_tanyao: bool,
+    /// 国士无双十三面、四暗刻单骑、纯正九莲宝灯和大四喜是否计为双倍役满
+    pub double_yakuman: bool,
+    /// 人和是否作为役满
+    pub renhou_yakuman: bool,
+}
+
+impl Default for YakuOptions {
+    /// 有食断、有双倍役满、人和不计
+    fn default() -> Self {
+        Self { open_tanyao: true, double_yakuman: true, renhou_yakuman: false }
+    }
+}
+
+/// 一种拆分下的役种判定结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YakuResult {
+    /// 成立的役及其番数；役满时为役满及其倍数
+    pub yaku: Vec<(Yaku, u8)>,
+    /// 宝牌数
+    pub dora: u8,
+    /// 赤宝牌数
+    pub aka_dora: u8,
+    /// 里宝牌数(仅立直时计算)
+    pub ura_dora: u8,
+    /// 拔北宝牌数(三人麻将中每拔出一张北计一番)
+    pub nuki_dora: u8,
+    /// 判定所用的和牌形
+    pub pattern: WinPattern,
+}
+
+impl YakuResult {
+    /// 是否至少有一个役(宝牌不算役)
+    pub fn has_yaku(&self) -> bool {
+        !self.yaku.is_empty()
+    }
+
+    /// 役满倍数，不是役满时为0
+    pub fn yakuman(&self) -> u8 {
+        self.yaku.iter().filter(|(y, _)| y.is_yakuman()).map(|&(_, n)| n).sum()
+    }
+
+    /// 普通役与宝牌的番数合计，役满时为0
+    pub fn han(&self) -> u8 {
+        if self.yakuman() > 0 {
+            return 0;
+        }
+        self.yaku.iter().map(|&(_, han)| han).sum::<u8>() + self.dora + self.aka_dora + self.ura_dora + self.nuki_dora
+    }
+}
+
+/// 判定和牌的役种，返回番数最高的拆分
+///
+/// 没有役(只有宝牌)或牌形不能和牌时返回`None`。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::WinContext;
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::riichi::{evaluate_yaku, Yaku, YakuOptions};
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let hand: Hand = "234567m234p34s55p".parse().unwrap();
+/// let mut context = WinContext::new(hand, Tile::Suit(Suit::Bamboo, 2), None);
+/// context.riichi = true;
+///
+/// let result = evaluate_yaku(&context, &YakuOptions::default()).unwrap();
+/// assert_eq!(result.yaku, vec![
+///     (Yaku::Riichi, 1), (Yaku::MenzenTsumo, 1), (Yaku::Pinfu, 1), (Yaku::Tanyao, 1), (Yaku::Sanshoku, 2),
+/// ]);
+/// assert_eq!(result.han(), 6);
+/// ```
+pub fn evaluate_yaku(context: &WinContext, options: &YakuOptions) -> Option<YakuResult> {
+    evaluate_all(context, options)
+        .into_iter()
+        .rev()
+        .max_by_key(|result| (result.yakuman(), result.han()))
+}
+
+/// 判定和牌每一种拆分的役种，只返回有役的拆分
+///
+/// 符数不同时番数最高的拆分未必点数最高，计分时应在这些结果中比较点数。
+pub fn evaluate_all(context: &WinContext, options: &YakuOptions) -> Vec<YakuResult> {
+    win_patterns(&context.hand, context.win_tile, context.is_tsumo())
+        .iter()
+        .map(|pattern| evaluate_pattern(context, pattern, options))
+        .filter(YakuResult::has_yaku)
+        .collect()
+}
+
+/// 判定一种拆分下成立的役种
+pub fn evaluate_pattern(context: &WinContext, pattern: &WinPattern, options: &YakuOptions) -> YakuResult {
+    let tiles = all_tiles(&context.hand, context.win_tile);
+    let yakuman = yakuman(context, pattern, &tiles, options);
+    if !yakuman.is_empty() {
+        return YakuResult { yaku: yakuman, dora: 0, aka_dora: 0, ura_dora: 0, nuki_dora: 0, pattern: pattern.clone() };
+    }
+
+    let yaku = regular_yaku(context, pattern, &tiles, options);
+    // 拔出的北也是手牌的一部分，宝牌指示牌指示北时同样计为宝牌
+    let nuki = context.hand.nuki();
+    let count_dora = |indicators: &[Tile]| -> u8 {
+        indicators
+            .iter()
+            .map(|&indicator| {
+                let dora = dora_for_players(indicator, context.player_count);
+                tiles.iter().chain(nuki).filter(|&&t| t == dora).count() as u8
+            })
+            .sum()
+    };
+    let aka_dora = context.hand.red_five_count() as u8 + u8::from(context.win_tile.is_red());
+    YakuResult {
+        yaku,
+        dora: count_dora(&context.dora_indicators),
+        aka_dora,
+        ura_dora: if context.riichi { count_dora(&context.ura_dora_indicators) } else { 0 },
+        nuki_dora: nuki.len() as u8,
+        pattern: pattern.clone(),
+    }
+}
+
+/// 按人数确定宝牌：三人麻将没有二万至八万，一万指示九万、九万指示一万
+fn dora_for_players(indicator: Tile, player_count: u8) -> Tile {
+    match indicator.suit_and_number() {
+        Some((Suit::Character, 1)) if player_count == 3 => Tile::Suit(Suit::Character, 9),
+        _ => dora_from_indicator(indicator),
+    }
+}
+
+/// 宝牌指示牌指示的宝牌：数牌为下一张(九指示一)，风牌按东南西北、三元牌按白发中循环
+pub fn dora_from_indicator(indicator: Tile) -> Tile {
+    match indicator.to_normal() {
+        Tile::Suit(suit, number) => Tile::Suit(suit, number % 9 + 1),
+        Tile::Wind(wind) => Tile::Wind(match wind {
+            Wind::East => Wind::South,
+            Wind::South => Wind::West,
+            Wind::West => Wind::North,
+            Wind::North => Wind::East,
+        }),
+        Tile::Dragon(dragon) => Tile::Dragon(match dragon {
+            Dragon::White => Dragon::Green,
+            Dragon::Green => Dragon::Red,
+            Dragon::Red => Dragon::White,
+        }),
+        other => other,
+    }
+}
+
+/// 役满
+fn yakuman(context: &WinContext, pattern: &WinPattern, tiles: &[Tile], options: &YakuOptions) -> Vec<(Yaku, u8)> {
+    let double = if options.double_yakuman { 2 } else { 1 };
+    let mut yaku = Vec::new();
+
+    if context.tenhou {
+        yaku.push((Yaku::Tenhou, 1));
+    }
+    if context.chiihou {
+        yaku.push((Yaku::Chiihou, 1));
+    }
+    if context.renhou && options.renhou_yakuman {
+        yaku.push((Yaku::Renhou, 1));
+    }
+
+    match pattern.form {
+        WinForm::Kokushi => {
+            // 和牌前已有十三种幺九牌时为十三面听
+            let thirteen_wait = context.hand.count_of(context.win_tile) == 1;
+            yaku.push((Yaku::Kokushi, if thirteen_wait { double } else { 1 }));
+        }
+        WinForm::Standard => {
+            if pattern.concealed_triplets() == 4 {
+                yaku.push((Yaku::Suuankou, if pattern.wait == WaitType::Tanki { double } else { 1 }));
+            }
+            let dragons = count_triplets(pattern, Tile::is_dragon);
+            if dragons == 3 {
+                yaku.push((Yaku::Daisangen, 1));
+            }
+            let winds = count_triplets(pattern, Tile::is_wind);
+            if winds == 4 {
+                yaku.push((Yaku::Daisuushii, double));
+            } else if winds == 3 && pattern.pair.is_wind() {
+                yaku.push((Yaku::Shousuushii, 1));
+            }
+            if pattern.kans() == 4 {
+                yaku.push((Yaku::Suukantsu, 1));
+            }
+        }
+        WinForm::Chiitoitsu | WinForm::HonorsAndKnitted => {}
+    }
+
+    if tiles.iter().all(Tile::is_honor) {
+        yaku.push((Yaku::Tsuuiisou, 1));
+    }
+    if tiles.iter().all(Tile::is_terminal) {
+        yaku.push((Yaku::Chinroutou, 1));
+    }
+    if tiles.iter().all(|t| GREEN_TILES.contains(t)) {
+        yaku.push((Yaku::Ryuuiisou, 1));
+    }
+    if let Some(pure) = nine_gates(context, tiles) {
+        yaku.push((Yaku::Chuuren, if pure { double } else { 1 }));
+    }
+    yaku
+}
+
+/// 九莲宝灯：门前清一色，一和九各三张以上、二到八各一张以上
+///
+/// 成立时返回是否为纯正九莲宝灯(和牌前恰为1112345678999的九面听)
+fn nine_gates(context: &WinContext, tiles: &[Tile]) -> Option<bool> {
+    if !context.hand.melds().is_empty() || tiles.len() != 14 {
+        return None;
+    }
+    let (suit, _) = context.win_tile.suit_and_number()?;
+    let mut counts = [0u8; 9];
+    for tile in tiles {
+        match tile.suit_and_number() {
+            Some((s, n)) if s == suit => counts[n as usize - 1] += 1,
+            _ => return None,
+        }
+    }
+    if counts.iter().zip(NINE_GATES).any(|(&c, need)| c < need) {
+        return None;
+    }
+    let (_, win_number) = context.win_tile.suit_and_number()?;
+    counts[win_number as usize - 1] -= 1;
+    Some(counts == NINE_GATES)
+}
+
+/// 普通役
+fn regular_yaku(context: &WinContext, pattern: &WinPattern, tiles: &[Tile], options: &YakuOptions) -> Vec<(Yaku, u8)> {
+    let closed = context.hand.is_closed();
+    // 副露减一番的役
+    let reduced = |han: u8| if closed { han } else { han - 1 };
+    let mut yaku = Vec::new();
+
+    if context.double_riichi {
+        yaku.push((Yaku::DoubleRiichi, 2));
+    } else if context.riichi {
+        yaku.push((Yaku::Riichi, 1));
+    }
+    if context.ippatsu && context.riichi {
+        yaku.push((Yaku::Ippatsu, 1));
+    }
+    if closed && context.is_tsumo() {
+        yaku.push((Yaku::MenzenTsumo, 1));
+    }
+
+    let standard = pattern.form == WinForm::Standard;
+    if standard && closed && is_pinfu(context, pattern) {
+        yaku.push((Yaku::Pinfu, 1));
+    }
+    if (closed || options.open_tanyao) && tiles.iter().all(|t| !t.is_terminal_or_honor()) {
+        yaku.push((Yaku::Tanyao, 1));
+    }
+    let peikou = if standard && closed { identical_sequence_pairs(pattern) } else { 0 };
+    if peikou == 1 {
+        yaku.push((Yaku::Iipeikou, 1));
+    }
+
+    for block in pattern.blocks.iter().filter(|b| b.is_triplet_like()) {
+        match block.tile {
+            Tile::Dragon(Dragon::White) => yaku.push((Yaku::Haku, 1)),
+            Tile::Dragon(Dragon::Green) => yaku.push((Yaku::Hatsu, 1)),
+            Tile::Dragon(Dragon::Red) => yaku.push((Yaku::Chun, 1)),
+            Tile::Wind(wind) => {
+                if wind == context.seat_wind {
+                    yaku.push((Yaku::SeatWind, 1));
+                }
+                if wind == context.round_wind {
+                    yaku.push((Yaku::RoundWind, 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if context.haitei {
+        yaku.push((Yaku::Haitei, 1));
+    }
+    if context.houtei {
+        yaku.push((Yaku::Houtei, 1));
+    }
+    if context.rinshan {
+        yaku.push((Yaku::Rinshan, 1));
+    }
+    if context.chankan {
+        yaku.push((Yaku::Chankan, 1));
+    }
+
+    if standard {
+        if (1..=7).any(|n| all_suits(|suit| has_block(pattern, Block::is_sequence, Tile::Suit(suit, n)))) {
+            yaku.push((Yaku::Sanshoku, reduced(2)));
+        }
+        if (1..=9).any(|n| all_suits(|suit| has_block(pattern, Block::is_triplet_like, Tile::Suit(suit, n)))) {
+            yaku.push((Yaku::SanshokuDoukou, 2));
+        }
+        let straight = |suit| [1, 4, 7].iter().all(|&n| has_block(pattern, Block::is_sequence, Tile::Suit(suit, n)));
+        if [Suit::Character, Suit::Dot, Suit::Bamboo].into_iter().any(straight) {
+            yaku.push((Yaku::Ittsu, reduced(2)));
+        }
+
+        let outside = pattern.blocks.iter().all(Block::has_terminal_or_honor) && pattern.pair.is_terminal_or_honor();
+        if outside && pattern.blocks.iter().any(Block::is_sequence) {
+            if tiles.iter().any(Tile::is_honor) {
+                yaku.push((Yaku::Chanta, reduced(2)));
+            } else {
+                yaku.push((Yaku::Junchan, reduced(3)));
+            }
+        }
+        if pattern.blocks.iter().all(Block::is_triplet_like) {
+            yaku.push((Yaku::Toitoi, 2));
+        }
+        if pattern.concealed_triplets() == 3 {
+            yaku.push((Yaku::Sanankou, 2));
+        }
+        if pattern.kans() == 3 {
+            yaku.push((Yaku::Sankantsu, 2));
+        }
+        if count_triplets(pattern, Tile::is_dragon) == 2 && pattern.pair.is_dragon() {
+            yaku.push((Yaku::Shousangen, 2));
+        }
+    }
+
+    if tiles.iter().all(Tile::is_terminal_or_honor) {
+        yaku.push((Yaku::Honroutou, 2));
+    }
+    if pattern.form == WinForm::Chiitoitsu {
+        yaku.push((Yaku::Chiitoitsu, 2));
+    }
+    if peikou == 2 {
+        yaku.push((Yaku::Ryanpeikou, 3));
+    }
+
+    let mut suits = tiles.iter().filter_map(|t| t.suit_and_number().map(|(suit, _)| suit));
+    let single_suit = suits.next().is_some_and(|first| suits.all(|suit| suit == first));
+    if single_suit {
+        if tiles.iter().any(Tile::is_honor) {
+            yaku.push((Yaku::Honitsu, reduced(3)));
+        } else {
+            yaku.push((Yaku::Chinitsu, reduced(6)));
+        }
+    }
+    yaku
+}
+
+/// 平和：四组顺子、雀头不是役牌、两面听
+fn is_pinfu(context: &WinContext, pattern: &WinPattern) -> bool {
+    let yakuhai_pair = pattern.pair.is_dragon()
+        || pattern.pair == Tile::Wind(context.seat_wind)
+        || pattern.pair == Tile::Wind(context.round_wind);
+    pattern.blocks.iter().all(Block::is_sequence) && !yakuhai_pair && pattern.wait == WaitType::Ryanmen
+}
+
+/// 完全相同的顺子对数(一杯口为1，两杯口为2)
+fn identical_sequence_pairs(pattern: &WinPattern) -> usize {
+    let mut sequences: Vec<Tile> = pattern.blocks.iter().filter(|b| b.is_sequence()).map(|b| b.tile).collect();
+    sequences.sort_by_key(Tile::to_id);
+    let mut pairs = 0;
+    let mut i = 0;
+    while i + 1 < sequences.len() {
+        if sequences[i] == sequences[i + 1] {
+            pairs += 1;
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    pairs
+}
+
+/// 某类牌组成的刻子(含杠子)数
+fn count_triplets(pattern: &WinPattern, kind: fn(&Tile) -> bool) -> usize {
+    pattern.blocks.iter().filter(|b| b.is_triplet_like() && kind(&b.tile)).count()
+}
+
+/// 是否有以`tile`为首的某种面子
+fn has_block(pattern: &WinPattern, kind: fn(&Block) -> bool, tile: Tile) -> bool {
+    pattern.blocks.iter().any(|b| kind(b) && b.tile == tile)
+}
+
+/// 三种花色是否都满足条件
+fn all_suits(check: impl Fn(Suit) -> bool) -> bool {
+    [Suit::Character, Suit::Dot, Suit::Bamboo].into_iter().all(check)
+}
@@ -0,0 +1,7 @@
+// src/rules/shanghai/flower.rs
+//
+// 上海麻将的补花流程
+// 上海麻将的花牌与国标麻将一样不参与组牌，开局和对局中的亮花、补花以及连续补花
+// 都与国标麻将相同，这里直接复用各规则共用的实现。
+
+pub use crate::rules::common::flower::{declare_flower, replace_dealt_flowers};
@@ -0,0 +1,87 @@
+// src/rules/shanghai/mod.rs
+//
+// 上海麻将(上海敲麻)规则
+
+pub mod flower;
+pub mod scoring;
+pub mod win_check;
+
+use crate::action::ActionOptions;
+use crate::game::{RoundOutcome, WinContext};
+use crate::rules::{FlowerHandling, RuleSet, WinScore};
+use crate::wall::{DeadWallConfig, WallConfig};
+
+pub use scoring::{evaluate_hua, evaluate_pattern, payments, BigWin, Hua, HuaResult, BASE_HUA};
+pub use win_check::{is_winning_hand, winning_decompositions, ShanghaiPattern};
+
+/// 默认的勒子(每家一次最多支付的点数)
+pub const DEFAULT_CAP: u32 = 50;
+
+/// 上海麻将规则：带花牌，可选百搭，可吃，没有立直和途中流局，截和
+///
+/// 百搭可以代替任意一张牌，但打出的百搭不能被吃、碰、杠或和。
+/// 荒庄不结算，下一局为"敲"，所有支付翻倍，见`next_round`。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::RoundOutcome;
+/// use majiang_core::rules::ShanghaiRules;
+///
+/// let rules = ShanghaiRules::default();
+/// let next = rules.next_round(&RoundOutcome::ExhaustiveDraw { tenpai: vec![] });
+/// assert!(next.knocked);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShanghaiRules {
+    /// 是否使用百搭牌
+    pub with_joker: bool,
+    /// 勒子：每家一次最多支付的点数(敲时翻倍前)
+    pub cap: u32,
+    /// 敲：本局是荒庄后的一局，所有支付翻倍
+    pub knocked: bool,
+}
+
+impl Default for ShanghaiRules {
+    /// 使用百搭牌，勒子50，不敲
+    fn default() -> Self {
+        Self { with_joker: true, cap: DEFAULT_CAP, knocked: false }
+    }
+}
+
+impl ShanghaiRules {
+    /// 根据本局结果得到下一局的规则：荒庄后敲，其他结果后不敲
+    pub fn next_round(&self, outcome: &RoundOutcome) -> Self {
+        Self { knocked: matches!(outcome, RoundOutcome::ExhaustiveDraw { .. }), ..*self }
+    }
+}
+
+impl RuleSet for ShanghaiRules {
+    fn name(&self) -> &'static str {
+        "上海麻将"
+    }
+
+    fn wall_config(&self) -> WallConfig {
+        WallConfig::Shanghai { with_joker: self.with_joker }
+    }
+
+    fn dead_wall_config(&self) -> Option<DeadWallConfig> {
+        Some(DeadWallConfig::Shanghai { replacement_count: 8 })
+    }
+
+    fn action_options(&self) -> ActionOptions {
+        ActionOptions { allow_riichi: false, allow_abortive_draws: false, ..ActionOptions::default() }
+    }
+
+    fn flower_handling(&self) -> FlowerHandling {
+        FlowerHandling::Replace
+    }
+
+    /// 列出花和大胡；打出的百搭不能和，没有门清和无百搭以外的大胡时至少要有一花
+    fn score_win(&self, context: &WinContext) -> Option<WinScore> {
+        if !context.is_tsumo() && context.win_tile.is_joker() {
+            return None;
+        }
+        let result = evaluate_hua(context, self).filter(HuaResult::is_valid)?;
+        Some(WinScore { items: result.items(), payments: payments(context, result.value(), self) })
+    }
+}
@@ -0,0 +1,306 @@
+// src/rules/shanghai/scoring.rs
+//
+// 上海麻将(上海敲麻)的计分
+// 得分由"花"和"大胡"两部分组成：
+// - 花：和牌的底花，加上花牌、字牌刻子和杠子带来的花(见`Hua`)；
+// - 大胡：清一色、碰碰和、杠开、海底捞月等，每成立一项得分翻一番(见`BigWin`)。
+// 一次和牌的得分为总花数乘以2的大胡项数次方。没有大胡时至少要有一花(不含底花)才能和牌；
+// 门清和无百搭只翻番，不能单独满足起和条件。
+//
+// 百搭和牌时对每种替代方案分别计分，取得分最高的一种。
+// 点数支付按`ShanghaiRules`的勒子封顶，敲(荒庄后的下一局)时封顶后再翻倍。
+
+use std::fmt;
+
+use crate::game::WinContext;
+use crate::hand::WinForm;
+use crate::rules::common::{BlockKind, WinPattern};
+use crate::rules::shanghai::win_check::{winning_decompositions, ShanghaiPattern};
+use crate::rules::shanghai::ShanghaiRules;
+use crate::rules::ScoreItem;
+
+/// 和牌的底花
+pub const BASE_HUA: u32 = 2;
+
+/// 花的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hua {
+    /// 底花
+    Base,
+    /// 花牌，每张一花
+    Flower,
+    /// 字牌暗杠
+    ConcealedHonorKong,
+    /// 字牌明杠
+    HonorKong,
+    /// 字牌暗刻
+    ConcealedHonorPung,
+    /// 字牌明刻
+    HonorPung,
+    /// 数牌暗杠
+    ConcealedKong,
+    /// 数牌明杠
+    Kong,
+}
+
+impl Hua {
+    /// 所有花的来源，按展示顺序排列
+    pub const ALL: [Hua; 8] = [
+        Hua::Base,
+        Hua::Flower,
+        Hua::ConcealedHonorKong,
+        Hua::HonorKong,
+        Hua::ConcealedHonorPung,
+        Hua::HonorPung,
+        Hua::ConcealedKong,
+        Hua::Kong,
+    ];
+
+    /// 中文名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Hua::Base => "底花",
+            Hua::Flower => "花牌",
+            Hua::ConcealedHonorKong => "字牌暗杠",
+            Hua::HonorKong => "字牌明杠",
+            Hua::ConcealedHonorPung => "字牌暗刻",
+            Hua::HonorPung => "字牌明刻",
+            Hua::ConcealedKong => "暗杠",
+            Hua::Kong => "明杠",
+        }
+    }
+
+    /// 每项的花数
+    pub fn points(&self) -> u32 {
+        match self {
+            Hua::Base => BASE_HUA,
+            Hua::Flower | Hua::HonorPung | Hua::Kong => 1,
+            Hua::ConcealedHonorPung | Hua::ConcealedKong => 2,
+            Hua::HonorKong => 3,
+            Hua::ConcealedHonorKong => 4,
+        }
+    }
+}
+
+impl fmt::Display for Hua {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 大胡，每项使得分翻一番
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BigWin {
+    /// 清一色：只有一种花色的数牌
+    FullFlush,
+    /// 混一色：一种花色的数牌加字牌
+    HalfFlush,
+    /// 碰碰和：四组刻子(杠子)
+    AllPungs,
+    /// 七对
+    SevenPairs,
+    /// 大吊车：四组明副露后单吊和牌
+    AllMelded,
+    /// 门清：没有吃、碰和明杠
+    ConcealedHand,
+    /// 杠开：杠后补牌自摸
+    KongBloom,
+    /// 海底捞月：摸最后一张牌自摸
+    LastTileDraw,
+    /// 抢杠：荣和他家加杠的牌
+    RobbingKong,
+    /// 无百搭：使用百搭的对局中手里没有百搭
+    NoJoker,
+}
+
+impl BigWin {
+    /// 所有大胡，按展示顺序排列
+    pub const ALL: [BigWin; 10] = [
+        BigWin::FullFlush,
+        BigWin::HalfFlush,
+        BigWin::AllPungs,
+        BigWin::SevenPairs,
+        BigWin::AllMelded,
+        BigWin::ConcealedHand,
+        BigWin::KongBloom,
+        BigWin::LastTileDraw,
+        BigWin::RobbingKong,
+        BigWin::NoJoker,
+    ];
+
+    /// 能否单独满足起和条件
+    ///
+    /// 门清和无百搭几乎每手牌都会成立，只翻番，起和时仍需要其他大胡或一花。
+    pub fn qualifies(&self) -> bool {
+        !matches!(self, BigWin::ConcealedHand | BigWin::NoJoker)
+    }
+
+    /// 中文名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            BigWin::FullFlush => "清一色",
+            BigWin::HalfFlush => "混一色",
+            BigWin::AllPungs => "碰碰和",
+            BigWin::SevenPairs => "七对",
+            BigWin::AllMelded => "大吊车",
+            BigWin::ConcealedHand => "门清",
+            BigWin::KongBloom => "杠开",
+            BigWin::LastTileDraw => "海底捞月",
+            BigWin::RobbingKong => "抢杠",
+            BigWin::NoJoker => "无百搭",
+        }
+    }
+}
+
+impl fmt::Display for BigWin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 一次和牌的计分结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HuaResult {
+    /// 花的来源及其次数，按`Hua::ALL`的顺序排列
+    pub hua: Vec<(Hua, u8)>,
+    /// 成立的大胡，按`BigWin::ALL`的顺序排列
+    pub big_wins: Vec<BigWin>,
+    /// 计分所用的和牌形(百搭已替代)
+    pub pattern: WinPattern,
+}
+
+impl HuaResult {
+    /// 总花数(含底花)
+    pub fn total_hua(&self) -> u32 {
+        self.hua.iter().map(|&(hua, count)| hua.points() * count as u32).sum()
+    }
+
+    /// 得分：总花数乘以2的大胡项数次方
+    pub fn value(&self) -> u32 {
+        self.total_hua() << self.big_wins.len()
+    }
+
+    /// 是否满足起和条件：有门清和无百搭以外的大胡，或者除底花外至少有一花
+    pub fn is_valid(&self) -> bool {
+        self.big_wins.iter().any(BigWin::qualifies) || self.total_hua() > BASE_HUA
+    }
+
+    /// 可以展示给玩家的计分明细：花的值为花数，大胡的值为翻的番数(每项1番)
+    pub fn items(&self) -> Vec<ScoreItem> {
+        let hua = self.hua.iter().map(|&(hua, count)| {
+            let name = if count > 1 { format!("{}×{}", hua.name(), count) } else { hua.name().to_string() };
+            ScoreItem::new(name, hua.points() * count as u32)
+        });
+        hua.chain(self.big_wins.iter().map(|b| ScoreItem::new(b.name(), 1))).collect()
+    }
+}
+
+/// 计算和牌的得分，返回得分最高的和牌形(含百搭的替代方案)
+///
+/// 牌形不能和牌时返回`None`；是否满足起和条件见`HuaResult::is_valid`。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::WinContext;
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::shanghai::scoring::{evaluate_hua, BigWin};
+/// use majiang_core::rules::ShanghaiRules;
+/// use majiang_core::tile::{Tile, Suit};
+///
+/// let hand: Hand = "1112345678999m".parse().unwrap();
+/// let context = WinContext::new(hand, Tile::Suit(Suit::Character, 5), Some(1));
+/// let result = evaluate_hua(&context, &ShanghaiRules::default()).unwrap();
+/// assert!(result.big_wins.contains(&BigWin::FullFlush));
+/// assert!(result.is_valid());
+/// ```
+pub fn evaluate_hua(context: &WinContext, rules: &ShanghaiRules) -> Option<HuaResult> {
+    let mut best: Option<HuaResult> = None;
+    for pattern in winning_decompositions(&context.hand, context.win_tile, context.is_tsumo()) {
+        let result = evaluate_pattern(context, &pattern, rules);
+        if best.as_ref().is_none_or(|b| result.value() > b.value()) {
+            best = Some(result);
+        }
+    }
+    best
+}
+
+/// 计算一种和牌形的得分
+pub fn evaluate_pattern(context: &WinContext, pattern: &ShanghaiPattern, rules: &ShanghaiRules) -> HuaResult {
+    let mut hua: Vec<(Hua, u8)> = vec![(Hua::Base, 1)];
+    let flowers = context.hand.flowers().len() as u8;
+    if flowers > 0 {
+        hua.push((Hua::Flower, flowers));
+    }
+    for block in &pattern.pattern.blocks {
+        let source = match (block.kind, block.tile.is_honor(), block.open) {
+            (BlockKind::Kan, true, false) => Hua::ConcealedHonorKong,
+            (BlockKind::Kan, true, true) => Hua::HonorKong,
+            (BlockKind::Triplet, true, false) => Hua::ConcealedHonorPung,
+            (BlockKind::Triplet, true, true) => Hua::HonorPung,
+            (BlockKind::Kan, false, false) => Hua::ConcealedKong,
+            (BlockKind::Kan, false, true) => Hua::Kong,
+            _ => continue,
+        };
+        match hua.iter_mut().find(|(h, _)| *h == source) {
+            Some((_, count)) => *count += 1,
+            None => hua.push((source, 1)),
+        }
+    }
+    hua.sort_by_key(|&(h, _)| Hua::ALL.iter().position(|&x| x == h));
+
+    HuaResult { hua, big_wins: big_wins(context, pattern, rules), pattern: pattern.pattern.clone() }
+}
+
+/// 上海麻将的点数变化：放铳者(自摸时为其他所有人)各付得分，每家以勒子封顶，敲时再翻倍
+pub fn payments(context: &WinContext, value: u32, rules: &ShanghaiRules) -> Vec<i32> {
+    let count = context.player_count as usize;
+    let mut payments = vec![0; count];
+    let seat = context.seat as usize % count;
+    let amount = value.min(rules.cap) * if rules.knocked { 2 } else { 1 };
+    for (payer, payment) in payments.iter_mut().enumerate() {
+        if payer != seat && context.from.is_none_or(|from| from as usize == payer) {
+            *payment = -(amount as i32);
+        }
+    }
+    payments[seat] = -payments.iter().sum::<i32>();
+    payments
+}
+
+/// 成立的大胡
+fn big_wins(context: &WinContext, pattern: &ShanghaiPattern, rules: &ShanghaiRules) -> Vec<BigWin> {
+    let mut wins = Vec::new();
+    let blocks = &pattern.pattern.blocks;
+    let melds = context.hand.melds();
+
+    let mut suits = pattern.tiles.iter().filter_map(|t| t.suit_and_number()).map(|(suit, _)| suit);
+    let single_suit = suits.next().is_some_and(|first| suits.all(|suit| suit == first));
+    let has_honors = pattern.tiles.iter().any(|t| t.is_honor());
+    if single_suit {
+        wins.push(if has_honors { BigWin::HalfFlush } else { BigWin::FullFlush });
+    }
+
+    match pattern.pattern.form {
+        WinForm::Chiitoitsu => wins.push(BigWin::SevenPairs),
+        _ if blocks.iter().all(|b| b.is_triplet_like()) => wins.push(BigWin::AllPungs),
+        _ => {}
+    }
+    if melds.len() == 4 && melds.iter().all(|m| m.is_open()) {
+        wins.push(BigWin::AllMelded);
+    }
+    if melds.iter().all(|m| !m.is_open()) {
+        wins.push(BigWin::ConcealedHand);
+    }
+    if context.rinshan {
+        wins.push(BigWin::KongBloom);
+    }
+    if context.haitei {
+        wins.push(BigWin::LastTileDraw);
+    }
+    if context.chankan {
+        wins.push(BigWin::RobbingKong);
+    }
+    if rules.with_joker && context.hand.joker_count() == 0 && !context.win_tile.is_joker() {
+        wins.push(BigWin::NoJoker);
+    }
+    wins
+}
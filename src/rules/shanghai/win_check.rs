@@ -0,0 +1,60 @@
+// src/rules/shanghai/win_check.rs
+//
+// 上海麻将的和牌判定
+// 上海麻将只承认四面子一雀头和七对两种和牌形，没有十三幺。
+// 百搭可以代替任意一张牌(包括雀头和顺子中的牌)，和牌时枚举百搭的所有替代方案，
+// 每种方案连同替代后的全部牌一起交给计分，由计分取得分最高的一种。
+
+use crate::hand::{joker_substitutions, Hand, JokerRules, WinForm};
+use crate::rules::common::{all_tiles, WinPattern};
+use crate::tile::Tile;
+
+/// 上海麻将的百搭不受位置限制
+pub const JOKER_RULES: JokerRules = JokerRules { in_pair: true, in_sequence: true };
+
+/// 上海麻将的一种和牌形
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShanghaiPattern {
+    /// 百搭替代后的和牌形
+    pub pattern: WinPattern,
+    /// 和牌的全部牌(含和牌张和副露)，百搭已换成它所代替的牌
+    pub tiles: Vec<Tile>,
+}
+
+/// 手牌加入和牌张后是否构成上海麻将的和牌形(不考虑起和条件)
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::shanghai::win_check::is_winning_hand;
+/// use majiang_core::tile::{Tile, Wind};
+///
+/// // 百搭代替3万
+/// let hand: Hand = "12m0z456p789s1112z".parse().unwrap();
+/// assert!(is_winning_hand(&hand, Tile::Wind(Wind::South)));
+/// assert!(!is_winning_hand(&hand, Tile::Wind(Wind::West)));
+/// ```
+pub fn is_winning_hand(hand: &Hand, win_tile: Tile) -> bool {
+    !winning_decompositions(hand, win_tile, false).is_empty()
+}
+
+/// 列出手牌加入和牌张后上海麻将的所有和牌形
+///
+/// 手牌或和牌张含百搭时，每种替代方案分别给出。
+///
+/// # 参数
+/// * `hand` - 和牌前的手牌(不含和牌张)
+/// * `win_tile` - 和牌张，可以是百搭
+/// * `tsumo` - 是否自摸
+pub fn winning_decompositions(hand: &Hand, win_tile: Tile, tsumo: bool) -> Vec<ShanghaiPattern> {
+    let natural: Vec<Tile> = all_tiles(hand, win_tile).into_iter().filter(|t| !t.is_joker()).collect();
+    joker_substitutions(hand, win_tile, &JOKER_RULES)
+        .into_iter()
+        .filter(|s| s.decomposition.form != WinForm::Kokushi)
+        .map(|s| {
+            let mut tiles = natural.clone();
+            tiles.extend(s.tiles.iter().copied());
+            ShanghaiPattern { pattern: WinPattern::from_decomposition(hand, &s.decomposition, tsumo), tiles }
+        })
+        .collect()
+}
@@ -0,0 +1,55 @@
+// src/rules/sichuan/exchange.rs
+//
+// 四川麻将的换三张与定缺
+// - 换三张：配牌后每家选出同一花色的三张牌，四家同时按规则的方向交给另一家；
+// - 定缺：换牌后每家选择一门花色，手中还有这门牌时只能打这门牌，也不能和牌、碰或杠这门牌；
+// - 花猪：荒牌时手中仍有缺门的牌(没有定缺时为三门牌都有)，查花猪时需要赔付。
+//
+// `GameState`在`RuleSet::tile_exchange`和`RuleSet::chooses_void_suit`开启时
+// 依次进入`Phase::Exchanging`和`Phase::ChoosingVoid`，这里只提供判定。
+// 换三张的候选和定缺的动作限制与其他动作一样由`action::legal_actions`列出。
+
+use crate::hand::Hand;
+use crate::tile::{Suit, Tile};
+
+pub use crate::action::exchange_candidates;
+pub use crate::rules::common::ExchangeDirection;
+
+/// 三门数牌，按定缺选项的顺序排列
+pub const SUITS: [Suit; 3] = [Suit::Character, Suit::Dot, Suit::Bamboo];
+
+/// 暗手(含摸牌)中是否还有某一门花色的牌
+pub fn holds_suit(hand: &Hand, suit: Suit) -> bool {
+    hand.all_concealed_tiles().into_iter().any(|t| tile_suit(t) == Some(suit))
+}
+
+/// 是否为花猪：定缺时手中还有缺门的牌，没有定缺时三门牌都有
+///
+/// # 示例
+/// ```
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::sichuan::is_flower_pig;
+/// use majiang_core::tile::Suit;
+///
+/// let three_suits: Hand = "123m456p789p1122s".parse().unwrap();
+/// assert!(is_flower_pig(&three_suits, Some(Suit::Bamboo)));
+/// assert!(is_flower_pig(&three_suits, None));
+///
+/// let two_suits: Hand = "123m456p789p1122m".parse().unwrap();
+/// assert!(!is_flower_pig(&two_suits, Some(Suit::Bamboo)));
+/// assert!(!is_flower_pig(&two_suits, None));
+/// ```
+pub fn is_flower_pig(hand: &Hand, void_suit: Option<Suit>) -> bool {
+    match void_suit {
+        Some(suit) => holds_suit(hand, suit),
+        None => SUITS.iter().all(|&suit| {
+            holds_suit(hand, suit)
+                || hand.melds().iter().any(|m| m.tiles.iter().any(|&t| tile_suit(t) == Some(suit)))
+        }),
+    }
+}
+
+/// 数牌的花色，字牌、花牌和百搭为`None`
+fn tile_suit(tile: Tile) -> Option<Suit> {
+    tile.suit_and_number().map(|(suit, _)| suit)
+}
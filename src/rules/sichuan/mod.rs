@@ -0,0 +1,131 @@
+// src/rules/sichuan/mod.rs
+//
+// 四川麻将(血战到底)规则
+
+pub mod exchange;
+pub mod scoring;
+
+use crate::action::ActionOptions;
+use crate::game::{GameState, RonPolicy, WinContext};
+use crate::hand::winning_tiles;
+use crate::meld::KanType;
+use crate::rules::{RuleSet, WinScore};
+use crate::wall::{DeadWallConfig, ReplacementSource, WallConfig};
+
+pub use exchange::{exchange_candidates, holds_suit, is_flower_pig, ExchangeDirection, SUITS};
+pub use scoring::{draw_payments, evaluate_fan, hand_value, kan_payments, payments, DrawStatus, Fan, FanResult};
+
+/// 默认的封顶番数
+pub const DEFAULT_MAX_FAN: u32 = 4;
+
+/// 四川麻将规则：108张数牌，不能吃，没有立直和途中流局，一炮多响
+///
+/// 配牌后换三张并定缺，和牌必须缺一门。一家和牌后其余玩家继续，
+/// 直到三家和牌或荒牌(血战到底)；开杠时立即收取刮风下雨，荒牌时查花猪和查大叫。
+///
+/// # 示例
+/// ```
+/// use majiang_core::rules::{RuleSet, SichuanRules};
+/// use majiang_core::rules::sichuan::ExchangeDirection;
+///
+/// let rules = SichuanRules::default();
+/// assert_eq!(rules.tile_exchange(), Some(ExchangeDirection::ToNext));
+/// assert!(rules.chooses_void_suit() && rules.bloody_battle());
+/// assert!(!rules.action_options().allow_chi);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SichuanRules {
+    /// 底分
+    pub base: u32,
+    /// 封顶番数
+    pub max_fan: u32,
+    /// 换三张的方向，`None`表示不换三张
+    pub exchange: Option<ExchangeDirection>,
+}
+
+impl Default for SichuanRules {
+    /// 底分1，四番封顶，换三张交给下家
+    fn default() -> Self {
+        Self { base: 1, max_fan: DEFAULT_MAX_FAN, exchange: Some(ExchangeDirection::ToNext) }
+    }
+}
+
+impl RuleSet for SichuanRules {
+    fn name(&self) -> &'static str {
+        "四川麻将"
+    }
+
+    fn wall_config(&self) -> WallConfig {
+        WallConfig::Sichuan
+    }
+
+    fn dead_wall_config(&self) -> Option<DeadWallConfig> {
+        None
+    }
+
+    fn action_options(&self) -> ActionOptions {
+        ActionOptions { allow_chi: false, allow_riichi: false, allow_abortive_draws: false, ..ActionOptions::default() }
+    }
+
+    fn ron_policy(&self) -> RonPolicy {
+        RonPolicy::Multiple
+    }
+
+    /// 杠后从牌墙末尾补牌
+    fn replacement_source(&self) -> ReplacementSource {
+        ReplacementSource::LiveWallBack
+    }
+
+    fn tile_exchange(&self) -> Option<ExchangeDirection> {
+        self.exchange
+    }
+
+    fn chooses_void_suit(&self) -> bool {
+        true
+    }
+
+    fn bloody_battle(&self) -> bool {
+        true
+    }
+
+    /// 列出番型，点炮由放铳者支付，自摸加底
+    fn score_win(&self, context: &WinContext) -> Option<WinScore> {
+        let result = evaluate_fan(context)?;
+        Some(WinScore { items: result.items(), payments: payments(context, result.total_fan(), self) })
+    }
+
+    /// 刮风下雨，只向尚未和牌的玩家收取
+    fn kan_payments(&self, game: &GameState, seat: u8, kan_type: KanType, from: Option<u8>) -> Vec<i32> {
+        let active: Vec<bool> = game.players().iter().map(|p| !p.has_won()).collect();
+        kan_payments(seat, kan_type, from, &active, self.base)
+    }
+
+    /// 查花猪和查大叫，听牌玩家按可能和到的最大番(荣和，不计海底)计算
+    fn exhaustive_draw_payments(&self, game: &GameState) -> Vec<i32> {
+        let count = game.players().len() as u8;
+        let statuses: Vec<DrawStatus> = game
+            .players()
+            .iter()
+            .map(|player| {
+                if player.has_won() {
+                    return DrawStatus::Won;
+                }
+                if is_flower_pig(player.hand(), player.void_suit()) {
+                    return DrawStatus::FlowerPig;
+                }
+                let seat = player.seat();
+                let from = (seat + 1) % count;
+                winning_tiles(player.hand())
+                    .into_iter()
+                    .filter_map(|tile| {
+                        let mut context = game.build_win_context(seat, Some(from), tile, false);
+                        context.houtei = false;
+                        evaluate_fan(&context).map(|result| hand_value(result.total_fan(), self))
+                    })
+                    .max()
+                    .map_or(DrawStatus::NotReady, |value| DrawStatus::Ready { value })
+            })
+            .collect();
+        draw_payments(&statuses, hand_value(self.max_fan, self))
+    }
+}
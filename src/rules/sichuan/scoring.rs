@@ -0,0 +1,324 @@
+// src/rules/sichuan/scoring.rs
+//
+// 四川麻将(血战到底)的计分
+// - 和牌必须缺一门(最多两门数牌)，番型见`Fan`，每一番使得分翻倍，超过封顶番数按封顶计；
+// - 点炮由放铳者支付，自摸由其他未和牌的玩家各支付一份并加一底(自摸加底)；
+// - 刮风下雨：明杠由放杠者付两底，加杠由其他未和牌的玩家各付一底，暗杠各付两底；
+// - 荒牌时查花猪和查大叫：花猪向其他未和牌且不是花猪的玩家赔封顶，
+//   没有听牌的玩家按听牌玩家可能和到的最大番赔付。
+//
+// 已经和牌的玩家不再支付，由`GameState`在结算每次和牌时处理。
+
+use std::fmt;
+
+use crate::game::WinContext;
+use crate::hand::WinForm;
+use crate::meld::KanType;
+use crate::rules::common::{all_tiles, win_patterns, WinPattern};
+use crate::rules::sichuan::{SichuanRules, SUITS};
+use crate::rules::ScoreItem;
+use crate::tile::Tile;
+
+/// 番型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fan {
+    /// 清一色：只有一门数牌
+    FullFlush,
+    /// 对对胡：四组刻子(杠子)
+    AllPungs,
+    /// 七对
+    SevenPairs,
+    /// 带幺九：每组面子和将牌都含一或九
+    Outside,
+    /// 将对：对对胡且全部是二、五、八
+    AllTwoFiveEight,
+    /// 金钩钓：四组副露后单吊和牌
+    SingleWait,
+    /// 根：四张相同的牌(含杠子)，每组一番
+    Root,
+    /// 杠上花：杠后补牌自摸
+    KongBloom,
+    /// 抢杠胡：荣和他家加杠的牌
+    RobbingKong,
+    /// 海底：摸最后一张牌自摸，或荣和最后一张打出的牌
+    LastTile,
+}
+
+impl Fan {
+    /// 所有番型，按展示顺序排列
+    pub const ALL: [Fan; 10] = [
+        Fan::FullFlush,
+        Fan::AllPungs,
+        Fan::SevenPairs,
+        Fan::Outside,
+        Fan::AllTwoFiveEight,
+        Fan::SingleWait,
+        Fan::Root,
+        Fan::KongBloom,
+        Fan::RobbingKong,
+        Fan::LastTile,
+    ];
+
+    /// 中文名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            Fan::FullFlush => "清一色",
+            Fan::AllPungs => "对对胡",
+            Fan::SevenPairs => "七对",
+            Fan::Outside => "带幺九",
+            Fan::AllTwoFiveEight => "将对",
+            Fan::SingleWait => "金钩钓",
+            Fan::Root => "根",
+            Fan::KongBloom => "杠上花",
+            Fan::RobbingKong => "抢杠胡",
+            Fan::LastTile => "海底",
+        }
+    }
+
+    /// 每项的番数
+    pub fn fan(&self) -> u32 {
+        match self {
+            Fan::FullFlush | Fan::SevenPairs | Fan::Outside | Fan::AllTwoFiveEight => 2,
+            Fan::AllPungs | Fan::SingleWait | Fan::Root | Fan::KongBloom | Fan::RobbingKong | Fan::LastTile => 1,
+        }
+    }
+}
+
+impl fmt::Display for Fan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// 一次和牌的计分结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FanResult {
+    /// 成立的番型及其次数，按`Fan::ALL`的顺序排列；平胡时为空
+    pub fans: Vec<(Fan, u8)>,
+    /// 计分所用的和牌形
+    pub pattern: WinPattern,
+}
+
+impl FanResult {
+    /// 总番数(封顶前)
+    pub fn total_fan(&self) -> u32 {
+        self.fans.iter().map(|&(fan, count)| fan.fan() * count as u32).sum()
+    }
+
+    /// 可以展示给玩家的计分明细，值为番数；平胡时为"平胡 0"
+    pub fn items(&self) -> Vec<ScoreItem> {
+        if self.fans.is_empty() {
+            return vec![ScoreItem::new("平胡", 0)];
+        }
+        self.fans
+            .iter()
+            .map(|&(fan, count)| {
+                let name = if count > 1 { format!("{}×{}", fan.name(), count) } else { fan.name().to_string() };
+                ScoreItem::new(name, fan.fan() * count as u32)
+            })
+            .collect()
+    }
+}
+
+/// 计算和牌的番数，返回番数最高的和牌形
+///
+/// 牌形不能和牌，或和牌时三门数牌都有(没有缺一门)时返回`None`。
+///
+/// # 示例
+/// ```
+/// use majiang_core::game::WinContext;
+/// use majiang_core::hand::Hand;
+/// use majiang_core::rules::sichuan::{evaluate_fan, Fan};
+/// use majiang_core::tile::{Suit, Tile};
+///
+/// // 拆成四组刻子时番数最高：清一色对对胡
+/// let hand: Hand = "1112223334445p".parse().unwrap();
+/// let context = WinContext::new(hand, Tile::Suit(Suit::Dot, 5), Some(1));
+/// let result = evaluate_fan(&context).unwrap();
+/// assert_eq!(result.fans, [(Fan::FullFlush, 1), (Fan::AllPungs, 1)]);
+/// assert_eq!(result.total_fan(), 3);
+///
+/// // 三门都有不能和牌
+/// let hand: Hand = "123m456p789s1122m".parse().unwrap();
+/// assert!(evaluate_fan(&WinContext::new(hand, Tile::Suit(Suit::Character, 1), None)).is_none());
+/// ```
+pub fn evaluate_fan(context: &WinContext) -> Option<FanResult> {
+    let tiles = all_tiles(&context.hand, context.win_tile);
+    let suits = SUITS.iter().filter(|&&suit| tiles.iter().any(|t| matches!(t.suit_and_number(), Some((s, _)) if s == suit)));
+    let suit_count = suits.count();
+    if suit_count > 2 || tiles.iter().any(|t| t.is_honor()) {
+        return None;
+    }
+
+    let mut best: Option<FanResult> = None;
+    for pattern in win_patterns(&context.hand, context.win_tile, context.is_tsumo()) {
+        if pattern.form == WinForm::Kokushi {
+            continue;
+        }
+        let result = FanResult { fans: fans(context, &pattern, &tiles, suit_count == 1), pattern };
+        if best.as_ref().is_none_or(|b| result.total_fan() > b.total_fan()) {
+            best = Some(result);
+        }
+    }
+    best
+}
+
+/// 和牌的得分：底分乘以2的番数次方，番数超过`max_fan`时按`max_fan`计
+///
+/// # 示例
+/// ```
+/// use majiang_core::rules::sichuan::{hand_value, SichuanRules};
+///
+/// let rules = SichuanRules { base: 1, max_fan: 3, ..SichuanRules::default() };
+/// assert_eq!(hand_value(0, &rules), 1);
+/// assert_eq!(hand_value(2, &rules), 4);
+/// assert_eq!(hand_value(5, &rules), 8);
+/// ```
+pub fn hand_value(fan: u32, rules: &SichuanRules) -> u32 {
+    rules.base << fan.min(rules.max_fan)
+}
+
+/// 四川麻将的点数变化：点炮由放铳者支付得分，自摸时其他玩家各付得分加一底
+///
+/// 已经和牌的玩家也按支付者列出，由`GameState`在结算时免除。
+pub fn payments(context: &WinContext, fan: u32, rules: &SichuanRules) -> Vec<i32> {
+    let count = context.player_count as usize;
+    let mut payments = vec![0; count];
+    let seat = context.seat as usize % count;
+    let value = hand_value(fan, rules) as i32;
+    for (payer, payment) in payments.iter_mut().enumerate() {
+        match context.from {
+            Some(from) if from as usize == payer => *payment = -value,
+            None if payer != seat => *payment = -(value + rules.base as i32),
+            _ => {}
+        }
+    }
+    payments[seat] = -payments.iter().sum::<i32>();
+    payments
+}
+
+/// 刮风下雨：开杠时各座位的点数变化
+///
+/// # 参数
+/// * `seat` - 开杠者
+/// * `kan_type` - 杠的种类
+/// * `from` - 明杠的放杠者
+/// * `active` - 各座位是否仍在本局中(未和牌)，只有仍在本局中的玩家支付
+/// * `base` - 底分
+///
+/// # 示例
+/// ```
+/// use majiang_core::meld::KanType;
+/// use majiang_core::rules::sichuan::kan_payments;
+///
+/// let active = [true, true, false, true];
+/// assert_eq!(kan_payments(0, KanType::Open, Some(3), &active, 1), [2, 0, 0, -2]);
+/// assert_eq!(kan_payments(0, KanType::Added, None, &active, 1), [2, -1, 0, -1]);
+/// assert_eq!(kan_payments(0, KanType::Closed, None, &active, 1), [4, -2, 0, -2]);
+/// ```
+pub fn kan_payments(seat: u8, kan_type: KanType, from: Option<u8>, active: &[bool], base: u32) -> Vec<i32> {
+    let base = base as i32;
+    let mut payments = vec![0; active.len()];
+    for (payer, payment) in payments.iter_mut().enumerate() {
+        if payer == seat as usize || !active[payer] {
+            continue;
+        }
+        *payment = match (kan_type, from) {
+            (KanType::Open, Some(from)) if from as usize == payer => -2 * base,
+            (KanType::Open, _) => 0,
+            (KanType::Added, _) => -base,
+            (KanType::Closed, _) => -2 * base,
+        };
+    }
+    let total = -payments.iter().sum::<i32>();
+    if let Some(slot) = payments.get_mut(seat as usize) {
+        *slot = total;
+    }
+    payments
+}
+
+/// 荒牌时一位玩家的状况
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawStatus {
+    /// 已经和牌，不参与荒牌结算
+    Won,
+    /// 花猪：手中还有缺门的牌
+    FlowerPig,
+    /// 没有听牌(无叫)
+    NotReady,
+    /// 听牌(有叫)，`value`为可能和到的最大得分
+    Ready { value: u32 },
+}
+
+/// 查花猪和查大叫：荒牌时各座位的点数变化
+///
+/// 花猪向每位未和牌且不是花猪的玩家赔付`max_value`，
+/// 没有听牌的玩家向每位听牌的玩家赔付对方可能和到的最大得分。
+///
+/// # 示例
+/// ```
+/// use majiang_core::rules::sichuan::{draw_payments, DrawStatus};
+///
+/// let statuses = [DrawStatus::Won, DrawStatus::FlowerPig, DrawStatus::NotReady, DrawStatus::Ready { value: 4 }];
+/// assert_eq!(draw_payments(&statuses, 16), [0, -32, 12, 20]);
+/// ```
+pub fn draw_payments(statuses: &[DrawStatus], max_value: u32) -> Vec<i32> {
+    let mut payments = vec![0; statuses.len()];
+    for (payer, status) in statuses.iter().enumerate() {
+        for (receiver, other) in statuses.iter().enumerate() {
+            let amount = match (status, other) {
+                (DrawStatus::FlowerPig, DrawStatus::NotReady | DrawStatus::Ready { .. }) => max_value,
+                (DrawStatus::NotReady, DrawStatus::Ready { value }) => *value,
+                _ => continue,
+            } as i32;
+            payments[payer] -= amount;
+            payments[receiver] += amount;
+        }
+    }
+    payments
+}
+
+/// 成立的番型
+fn fans(context: &WinContext, pattern: &WinPattern, tiles: &[Tile], single_suit: bool) -> Vec<(Fan, u8)> {
+    let mut fans = Vec::new();
+    let blocks = &pattern.blocks;
+    if single_suit {
+        fans.push((Fan::FullFlush, 1));
+    }
+    let all_pungs = pattern.form == WinForm::Standard && blocks.iter().all(|b| b.is_triplet_like());
+    if all_pungs {
+        fans.push((Fan::AllPungs, 1));
+    }
+    if pattern.form == WinForm::Chiitoitsu {
+        fans.push((Fan::SevenPairs, 1));
+    }
+    if pattern.form == WinForm::Standard && pattern.pair.is_terminal() && blocks.iter().all(|b| b.has_terminal_or_honor()) {
+        fans.push((Fan::Outside, 1));
+    }
+    let two_five_eight = |tile: Tile| matches!(tile.suit_and_number(), Some((_, 2 | 5 | 8)));
+    if all_pungs && two_five_eight(pattern.pair) && blocks.iter().all(|b| two_five_eight(b.tile)) {
+        fans.push((Fan::AllTwoFiveEight, 1));
+    }
+    if context.hand.melds().len() == 4 {
+        fans.push((Fan::SingleWait, 1));
+    }
+
+    let mut kinds = tiles.to_vec();
+    kinds.sort_by_key(|t| t.to_id());
+    kinds.dedup();
+    let roots = kinds.iter().filter(|&&kind| tiles.iter().filter(|&&t| t == kind).count() == 4).count() as u8;
+    if roots > 0 {
+        fans.push((Fan::Root, roots));
+    }
+
+    if context.rinshan {
+        fans.push((Fan::KongBloom, 1));
+    }
+    if context.chankan {
+        fans.push((Fan::RobbingKong, 1));
+    }
+    if context.haitei || context.houtei {
+        fans.push((Fan::LastTile, 1));
+    }
+    fans
+}
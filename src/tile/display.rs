@@ -69,6 +69,7 @@ impl TileDisplay for Tile {
 /// 
 /// 采用国际象棋记谱法类似的简洁表示：
 /// - 数字+花色首字母（m=万/man, p=筒/pin, s=索/sou）
+/// - 红五沿用通行的MPSZ记法，用0代替5（如0m）
 /// - 单字母表示字牌（E=东, S=南, W=西, N=北）
 /// - 字母表示三元牌（W=白, G=绿, R=红）
 /// - F+数字表示花牌
@@ -77,6 +78,9 @@ fn compact_display(tile: &Tile) -> String {
         Tile::Suit(Suit::Character, n) => format!("{}m", n),
         Tile::Suit(Suit::Dot, n) => format!("{}p", n),
         Tile::Suit(Suit::Bamboo, n) => format!("{}s", n),
+        Tile::RedFive(Suit::Character) => "0m".to_string(),
+        Tile::RedFive(Suit::Dot) => "0p".to_string(),
+        Tile::RedFive(Suit::Bamboo) => "0s".to_string(),
         Tile::Wind(Wind::East) => "E".to_string(),
         Tile::Wind(Wind::South) => "S".to_string(),
         Tile::Wind(Wind::West) => "W".to_string(),
@@ -102,6 +106,7 @@ fn compact_display(tile: &Tile) -> String {
 /// 优点是直观，一个符号即可表示一张牌，缺点是需要终端支持这些Unicode符号。
 /// 
 /// 注意：花牌使用通用Unicode符号，因为麻将Unicode区块不包含花牌专用符号。
+/// 红五同样没有专用符号，与普通五共用牌面，需配合颜色显示区分。
 fn unicode_display(tile: &Tile) -> String {
     // 使用静态映射表替代大量模式匹配，提高可维护性和性能
     // 这种实现更符合"避免频繁分配内存"和"函数短小，复杂逻辑拆分"的原则
@@ -121,9 +126,9 @@ fn unicode_display(tile: &Tile) -> String {
     // 三元牌映射 (白发中)
     static DRAGON_TILES: [&str; 3] = ["🀆", "🀅", "🀄"];
     
-    match tile {
-        Tile::Suit(suit, n) if *n >= 1 && *n <= 9 => {
-            let index = (*n - 1) as usize;
+    match tile.to_normal() {
+        Tile::Suit(suit, n) if (1..=9).contains(&n) => {
+            let index = (n - 1) as usize;
             match suit {
                 Suit::Character => MAN_TILES[index],
                 Suit::Dot => PIN_TILES[index],
@@ -160,14 +165,8 @@ fn unicode_display(tile: &Tile) -> String {
 fn ascii_display(tile: &Tile) -> String {
     // 简单的ASCII牌面实现，可以根据需要扩展为多行艺术
     match tile {
-        Tile::Suit(suit, n) => {
-            let suit_char = match suit {
-                Suit::Character => 'm',
-                Suit::Dot => 'p',
-                Suit::Bamboo => 's',
-            };
-            format!("|{}{}|", n, suit_char)
-        },
+        Tile::Suit(suit, n) => format!("|{}{}|", n, suit_letter(*suit)),
+        Tile::RedFive(suit) => format!("|0{}|", suit_letter(*suit)),
         Tile::Wind(wind) => {
             let wind_char = match wind {
                 Wind::East => 'E',
@@ -202,6 +201,15 @@ fn ascii_display(tile: &Tile) -> String {
     }
 }
 
+/// 数牌花色对应的MPSZ字母
+fn suit_letter(suit: Suit) -> char {
+    match suit {
+        Suit::Character => 'm',
+        Suit::Dot => 'p',
+        Suit::Bamboo => 's',
+    }
+}
+
 /// 用于在终端中显示带颜色的牌
 /// 
 /// 颜色增强了牌面的可读性，特别是在复杂牌组中快速识别不同类型的牌。
@@ -238,6 +246,7 @@ pub trait ColoredTileDisplay: TileDisplay {
 /// 为Tile实现ColoredTileDisplay特质
 /// 
 /// 不同牌型使用不同颜色表示，增强可读性：
+/// - 红五：亮红色（加粗），与普通万子区分
 /// - 万子：红色
 /// - 筒子：绿色
 /// - 索子：蓝色
@@ -256,6 +265,7 @@ impl ColoredTileDisplay for Tile {
         
         // 根据牌的类型添加颜色
         match self {
+            Tile::RedFive(_) => format!("\x1b[1;91m{}\x1b[0m", display_text),              // 亮红色
             Tile::Suit(Suit::Character, _) => format!("\x1b[31m{}\x1b[0m", display_text), // 红色
             Tile::Suit(Suit::Dot, _) => format!("\x1b[32m{}\x1b[0m", display_text),       // 绿色
             Tile::Suit(Suit::Bamboo, _) => format!("\x1b[34m{}\x1b[0m", display_text),    // 蓝色
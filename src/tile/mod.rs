@@ -7,11 +7,14 @@
 // - tile.rs: 实现牌的核心操作和判断
 // - display.rs: 负责牌的可视化表示
 // - serialization.rs: 提供序列化辅助方法
+// - physical.rs: 定义实体牌编号，区分同种牌的不同副本
 
 mod types;
+#[allow(clippy::module_inception)]
 mod tile;
 mod display;
 mod serialization;
+mod physical;
 
 // 直接从 types 模块导出所需的类型
 pub use types::{Tile, Suit, Wind, Dragon, Flower};
@@ -31,4 +34,10 @@ pub use serialization::{
     to_id, from_id,
     to_data, from_data,
     tiles_to_ids, ids_to_tiles
+};
+
+// 从 physical 模块导出实体牌相关功能
+pub use physical::{
+    PhysicalTile, InstanceId, COPIES_PER_KIND,
+    physical_to_tiles, physical_to_ids
 };
\ No newline at end of file
@@ -0,0 +1,160 @@
+// src/tile/physical.rs
+//
+// 定义实体牌(物理牌)标识，用于区分同一种牌的不同副本。
+// TileId只描述"是什么牌"(34种+花牌+百搭)，而牌谱回放、红宝牌追踪等场景
+// 需要知道"是哪一张牌"，例如四张3万中的第几张。
+//
+// 实体牌ID采用天凤牌谱的通行编码，便于与外部牌谱互相转换：
+// - 0-135:   种类ID*4+副本序号(0-3)，覆盖万筒索与字牌
+// - 136-143: 八张花牌(春夏秋冬梅兰竹菊)
+// - 144:     百搭牌
+// 按天凤约定，使用红宝牌时每种花色五的第0号副本(16/52/88)为红五。
+
+use crate::errors::{MajiangError, MajiangResult};
+use crate::tile::types::{FLOWER_START, MAX_HONOR_ID};
+use crate::tile::{Tile, TileId};
+
+/// 实体牌的唯一编号，便于序列化和牌谱记录
+pub type InstanceId = u8;
+
+/// 每种牌的最大副本数
+pub const COPIES_PER_KIND: u8 = 4;
+
+/// 花牌实体ID起始值(春对应136)
+const FLOWER_INSTANCE_START: InstanceId = 136;
+
+/// 百搭牌实体ID
+const JOKER_INSTANCE_ID: InstanceId = 144;
+
+/// 红五在实体编码中对应的副本序号
+const RED_FIVE_COPY: u8 = 0;
+
+/// 实体牌，即牌墙中真实存在的某一张牌
+///
+/// 同时记录实体编号和牌面，牌面中保留了红五标记，
+/// 因此无论规则是否启用红宝牌，都能无损地在两者之间转换。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhysicalTile {
+    /// 实体编号
+    pub(crate) id: InstanceId,
+    /// 牌面
+    pub(crate) tile: Tile,
+}
+
+impl PhysicalTile {
+    /// 由牌面和副本序号创建实体牌
+    ///
+    /// # 参数
+    /// * `tile` - 牌面，可以是红五(按天凤约定红五应使用第0号副本)
+    /// * `copy` - 副本序号，数牌和字牌为0-3，花牌和百搭只有一张，必须为0
+    ///
+    /// # 错误
+    /// 副本序号超出范围时返回`InvalidTile`
+    pub fn new(tile: Tile, copy: u8) -> MajiangResult<Self> {
+        let kind_id = tile.to_id();
+
+        let id = if kind_id <= MAX_HONOR_ID {
+            if copy >= COPIES_PER_KIND {
+                return Err(MajiangError::InvalidTile(format!(
+                    "{}的副本序号{}超出范围(0-{})", tile, copy, COPIES_PER_KIND - 1
+                )));
+            }
+            kind_id * COPIES_PER_KIND + copy
+        } else {
+            if copy != 0 {
+                return Err(MajiangError::InvalidTile(format!(
+                    "{}只有一张，副本序号必须为0", tile
+                )));
+            }
+            if tile.is_joker() {
+                JOKER_INSTANCE_ID
+            } else {
+                FLOWER_INSTANCE_START + (kind_id - FLOWER_START)
+            }
+        };
+
+        Ok(Self { id, tile })
+    }
+
+    /// 从实体编号还原实体牌
+    ///
+    /// 实体编号本身不携带红牌信息，因此需要由调用方说明牌组是否使用红五。
+    ///
+    /// # 参数
+    /// * `id` - 实体编号
+    /// * `red_fives` - 牌组是否使用红五(如日麻)，为true时五的第0号副本还原为红五
+    ///
+    /// # 返回
+    /// * `Some(PhysicalTile)` - 有效的实体编号
+    /// * `None` - 无效编号
+    pub fn from_instance_id(id: InstanceId, red_fives: bool) -> Option<Self> {
+        let tile = match id {
+            0..=135 => {
+                let kind = Tile::from_id(id / COPIES_PER_KIND)?;
+                let copy = id % COPIES_PER_KIND;
+                match kind {
+                    Tile::Suit(suit, 5) if red_fives && copy == RED_FIVE_COPY => Tile::new_red_five(suit),
+                    other => other,
+                }
+            }
+            136..=143 => Tile::from_id(FLOWER_START + (id - FLOWER_INSTANCE_START))?,
+            JOKER_INSTANCE_ID => Tile::Joker,
+            _ => return None,
+        };
+
+        Some(Self { id, tile })
+    }
+
+    /// 获取实体编号
+    pub fn id(&self) -> InstanceId {
+        self.id
+    }
+
+    /// 获取牌面
+    pub fn tile(&self) -> Tile {
+        self.tile
+    }
+
+    /// 获取牌的种类ID(与`Tile::to_id`一致)
+    pub fn kind_id(&self) -> TileId {
+        self.tile.to_id()
+    }
+
+    /// 获取副本序号(0-3)，花牌和百搭总是0
+    pub fn copy_index(&self) -> u8 {
+        if self.id < FLOWER_INSTANCE_START {
+            self.id % COPIES_PER_KIND
+        } else {
+            0
+        }
+    }
+
+    /// 判断是否为红五
+    pub fn is_red(&self) -> bool {
+        self.tile.is_red()
+    }
+}
+
+/// 实体牌可以直接退化为牌面
+impl From<PhysicalTile> for Tile {
+    fn from(physical: PhysicalTile) -> Self {
+        physical.tile
+    }
+}
+
+/// 显示格式与牌面一致，并附带实体编号，如"3万#9"
+impl std::fmt::Display for PhysicalTile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}#{}", self.tile, self.id)
+    }
+}
+
+/// 将一组实体牌转换为牌面
+pub fn physical_to_tiles(tiles: &[PhysicalTile]) -> Vec<Tile> {
+    tiles.iter().map(|t| t.tile).collect()
+}
+
+/// 将一组实体牌转换为实体编号序列，适合牌谱记录
+pub fn physical_to_ids(tiles: &[PhysicalTile]) -> Vec<InstanceId> {
+    tiles.iter().map(|t| t.id).collect()
+}
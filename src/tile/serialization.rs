@@ -5,6 +5,7 @@
 // 使外部库可以更容易地实现自己的序列化逻辑。
 
 use crate::tile::{Tile, Suit, Wind, Dragon, Flower};
+use crate::tile::types::{RED_MAN5_ID, RED_PIN5_ID, RED_SOU5_ID};
 
 /// 表示牌的简单整数形式，便于序列化和网络传输
 /// 直接使用牌的ID值
@@ -22,8 +23,12 @@ pub type TileId = u8;
 /// - 34-41: 花牌
 /// - 42:    百搭牌
 /// 
+/// 红五与普通五共享ID，单独的ID无法区分红牌，需要时请使用`to_data`。
+/// 
 /// # 示例
 /// ```
+/// use majiang_core::tile::{Tile, Suit, to_id};
+/// 
 /// let tile = Tile::Suit(Suit::Character, 5); // 五万
 /// let id = to_id(&tile); // id = 4
 /// ```
@@ -38,6 +43,8 @@ pub fn to_id(tile: &Tile) -> TileId {
 /// 
 /// # 示例
 /// ```
+/// use majiang_core::tile::from_id;
+/// 
 /// let id = 4; // 五万的ID
 /// if let Some(tile) = from_id(id) {
 ///     println!("恢复的牌: {}", tile); // 显示"5万"
@@ -73,6 +80,8 @@ pub struct TileData {
 /// 
 /// # 示例
 /// ```
+/// use majiang_core::tile::{Tile, Wind, to_data};
+/// 
 /// let tile = Tile::Wind(Wind::East);
 /// let data = to_data(&tile);
 /// // data.kind = "风牌", data.value = "东", data.id = 27
@@ -82,7 +91,9 @@ pub fn to_data(tile: &Tile) -> TileData {
     let is_red = tile.is_red();
     
     match tile {
-        Tile::Suit(suit, num) => {
+        // 红五的种类信息与普通五一致，仅is_red不同
+        Tile::Suit(suit, _) | Tile::RedFive(suit) => {
+            let num = tile.suit_and_number().map_or(5, |(_, n)| n);
             let kind = match suit {
                 Suit::Character => "万子",
                 Suit::Dot => "筒子",
@@ -152,10 +163,13 @@ pub fn to_data(tile: &Tile) -> TileData {
 /// 从序列化数据重建牌对象
 /// 
 /// 主要使用id字段来重建，其他字段作为备用或验证。
+/// `is_red`为true且ID属于某一花色的五时，还原为红五。
 /// 如果ID无效，返回None。
 /// 
 /// # 示例
 /// ```
+/// use majiang_core::tile::{TileData, from_data};
+/// 
 /// let data = TileData {
 ///     id: 27,
 ///     kind: "风牌".to_string(),
@@ -167,7 +181,15 @@ pub fn to_data(tile: &Tile) -> TileData {
 /// }
 /// ```
 pub fn from_data(data: &TileData) -> Option<Tile> {
-    from_id(data.id)
+    let tile = from_id(data.id)?;
+    
+    if data.is_red && matches!(data.id, RED_MAN5_ID | RED_PIN5_ID | RED_SOU5_ID) {
+        if let Tile::Suit(suit, _) = tile {
+            return Some(Tile::new_red_five(suit));
+        }
+    }
+    
+    Some(tile)
 }
 
 /// 创建一组牌的ID序列，适合批量序列化
@@ -176,6 +198,8 @@ pub fn from_data(data: &TileData) -> Option<Tile> {
 /// 
 /// # 示例
 /// ```
+/// use majiang_core::tile::{Tile, Suit, Wind, tiles_to_ids};
+/// 
 /// let tiles = vec![
 ///     Tile::Suit(Suit::Character, 1),
 ///     Tile::Suit(Suit::Character, 2),
@@ -184,7 +208,7 @@ pub fn from_data(data: &TileData) -> Option<Tile> {
 /// let ids = tiles_to_ids(&tiles); // [0, 1, 27]
 /// ```
 pub fn tiles_to_ids(tiles: &[Tile]) -> Vec<TileId> {
-    tiles.iter().map(|t| to_id(t)).collect()
+    tiles.iter().map(to_id).collect()
 }
 
 /// 从ID序列重建一组牌，适合批量反序列化
@@ -193,6 +217,8 @@ pub fn tiles_to_ids(tiles: &[Tile]) -> Vec<TileId> {
 /// 
 /// # 示例
 /// ```
+/// use majiang_core::tile::ids_to_tiles;
+/// 
 /// let ids = vec![0, 1, 27];
 /// let tiles = ids_to_tiles(&ids);
 /// // tiles包含[一万, 二万, 东]
@@ -1,6 +1,5 @@
 use crate::tile::types::{
-    DRAGON_START, FLOWER_START, JOKER_ID, CHARACTER_START, DOT_START, RED_MAN5_ID, RED_PIN5_ID,
-    RED_SOU5_ID, BAMBOO_START, WIND_START,
+    DRAGON_START, FLOWER_START, JOKER_ID, CHARACTER_START, DOT_START, BAMBOO_START, WIND_START,
 };
 #[allow(unused_imports)]
 use crate::tile::{Dragon, Flower, Suit, Tile, Wind};
@@ -33,8 +32,21 @@ impl Tile {
         }
     }
 
+    /// 创建指定花色的红宝牌（赤五）
+    ///
+    /// 红五只存在于数牌中，因此以花色为唯一参数，不会构造出非法牌。
+    ///
+    /// # 参数
+    /// * `suit` - 花色（万、筒、条）
+    pub fn new_red_five(suit: Suit) -> Self {
+        Tile::RedFive(suit)
+    }
+
     /// 从牌ID转换为牌类型
     ///
+    /// 注意：红五与普通五共享ID，因此本方法总是返回普通五；
+    /// 需要还原红牌时请使用带`is_red`标记的`TileData`。
+    ///
     /// ID系统是内部优化的关键，允许将复杂的牌类型映射到简单的整数，
     /// 便于快速比较、排序和存储。
     ///
@@ -95,6 +107,7 @@ impl Tile {
     ///
     /// 是from_id的反向操作，将牌对象转换回唯一ID。
     /// 这种双向转换确保了ID系统的一致性。
+    /// 红五返回其所属种类（普通五）的ID。
     ///
     /// # 返回
     /// * `u8` - 牌的唯一ID
@@ -103,6 +116,9 @@ impl Tile {
             Tile::Suit(Suit::Character, n) => CHARACTER_START + n - 1,
             Tile::Suit(Suit::Dot, n) => DOT_START + n - 1,
             Tile::Suit(Suit::Bamboo, n) => BAMBOO_START + n - 1,
+            Tile::RedFive(Suit::Character) => CHARACTER_START + 4,
+            Tile::RedFive(Suit::Dot) => DOT_START + 4,
+            Tile::RedFive(Suit::Bamboo) => BAMBOO_START + 4,
             Tile::Wind(Wind::East) => WIND_START,
             Tile::Wind(Wind::South) => WIND_START + 1,
             Tile::Wind(Wind::West) => WIND_START + 2,
@@ -125,9 +141,43 @@ impl Tile {
     /// 判断是否为数牌
     ///
     /// 数牌是麻将中最基础的牌型，由三种花色(万、筒、条)的1-9点组成。
-    /// 在和牌构成中扮演重要角色。
+    /// 在和牌构成中扮演重要角色。红五同样属于数牌。
     pub fn is_suit(&self) -> bool {
-        matches!(self, Tile::Suit(_, _))
+        matches!(self, Tile::Suit(_, _) | Tile::RedFive(_))
+    }
+
+    /// 获取数牌的花色和点数
+    ///
+    /// 红五会被视为点数为5的普通数牌，调用方无需单独处理红牌变体。
+    ///
+    /// # 返回
+    /// * `Some((Suit, u8))` - 数牌的花色和点数
+    /// * `None` - 非数牌
+    pub fn suit_and_number(&self) -> Option<(Suit, u8)> {
+        match *self {
+            Tile::Suit(suit, n) => Some((suit, n)),
+            Tile::RedFive(suit) => Some((suit, 5)),
+            _ => None,
+        }
+    }
+
+    /// 去掉红宝牌标记，返回同种类的普通牌
+    ///
+    /// 手牌分析、吃碰判定等只关心种类的场景应先调用此方法，
+    /// 非红牌原样返回。
+    pub fn to_normal(&self) -> Tile {
+        match *self {
+            Tile::RedFive(suit) => Tile::Suit(suit, 5),
+            other => other,
+        }
+    }
+
+    /// 判断两张牌是否为同一种类
+    ///
+    /// 与`==`不同，红五和普通五被视为同一种牌，
+    /// 副露验证、听牌判断等规则逻辑都应使用此方法比较。
+    pub fn is_same_kind(&self, other: &Tile) -> bool {
+        self.to_id() == other.to_id()
     }
 
     /// 判断是否为风牌
@@ -163,6 +213,18 @@ impl Tile {
         self.is_wind() || self.is_dragon()
     }
 
+    /// 判断是否为老头牌（数牌的一和九）
+    pub fn is_terminal(&self) -> bool {
+        matches!(self.suit_and_number(), Some((_, 1 | 9)))
+    }
+
+    /// 判断是否为幺九牌（老头牌或字牌）
+    ///
+    /// 幺九牌决定断幺九、混全带幺九、国士无双等役种，以及九种九牌和流局满贯的判定。
+    pub fn is_terminal_or_honor(&self) -> bool {
+        self.is_honor() || self.is_terminal()
+    }
+
     /// 判断是否为百搭牌
     ///
     /// 百搭牌是一种特殊牌型，在某些规则变体中允许替代其他牌型。
@@ -176,15 +238,10 @@ impl Tile {
     /// 红宝牌是一种特殊计分牌型，通常是红色的五万、五筒或五条，
     /// 在日式和部分中式规则中可提供额外得分。
     ///
-    /// 实现时使用ID比较而非直接匹配，为将来支持多种红牌配置预留空间。
+    /// 红色是具体某一张牌的属性而非种类属性，因此只有`Tile::RedFive`返回true，
+    /// 普通的五即使与红五同种类也不是红牌。
     pub fn is_red(&self) -> bool {
-        match self {
-            Tile::Suit(_suit, 5) => {
-                let id = self.to_id();
-                id == RED_MAN5_ID || id == RED_PIN5_ID || id == RED_SOU5_ID
-            }
-            _ => false,
-        }
+        matches!(self, Tile::RedFive(_))
     }
 }
 
@@ -194,11 +251,13 @@ impl Tile {
 /// - 数牌：数字+花色，如"1万"
 /// - 字牌：直接显示，如"东"、"中"
 /// - 花牌：直接显示，如"春"
+/// - 红五：在数牌前加"红"，如"红5万"
 /// - 百搭：显示为"百搭"
 impl std::fmt::Display for Tile {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Tile::Suit(suit, n) => write!(f, "{}{}", n, suit),
+            Tile::RedFive(suit) => write!(f, "红5{}", suit),
             Tile::Wind(wind) => write!(f, "{}", wind),
             Tile::Dragon(dragon) => write!(f, "{}", dragon),
             Tile::Flower(flower) => write!(f, "{}", flower),
@@ -6,7 +6,8 @@
 // 麻将牌的ID系统设计：
 // - 每种牌分配唯一ID，便于高效存储和比较
 // - ID规则有意设计为连续区间，便于批量操作和范围检查
-// - 红宝牌（红五）使用特殊常量标识，便于规则判定
+// - 红宝牌（红五）是独立的牌变体，但与普通五共享同一个种类ID，
+//   这样既能区分"这一张是红牌"，又不影响吃碰杠等按种类比较的规则判定
 
 #![allow(dead_code)]
 use strum_macros::{EnumString, EnumIter, AsRefStr, EnumCount, Display};
@@ -120,9 +121,12 @@ impl Default for Flower {
 ///
 /// # 示例
 /// ```
+/// use majiang_core::tile::{Tile, Suit, Wind, Dragon};
+///
 /// let man5 = Tile::Suit(Suit::Character, 5);
 /// let east = Tile::Wind(Wind::East);
 /// let red_dragon = Tile::Dragon(Dragon::Red);
+/// let red_man5 = Tile::RedFive(Suit::Character);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumString)]
 pub enum Tile {
@@ -130,6 +134,13 @@ pub enum Tile {
     #[strum(serialize = "数牌")]
     Suit(Suit, u8),
     
+    /// 红宝牌（赤五）：某一花色中被染红的那一张五
+    ///
+    /// 与`Suit(suit, 5)`属于同一种类（共享牌ID），仅在宝牌计数和显示上有区别。
+    /// 比较两张牌是否"同一种牌"时应使用`Tile::is_same_kind`而非`==`。
+    #[strum(serialize = "赤五")]
+    RedFive(Suit),
+    
     /// 风牌：东南西北
     #[strum(serialize = "风牌")]
     Wind(Wind),
@@ -191,9 +202,9 @@ pub(crate) const MAX_FLOWER_ID: u8 = 41;
 /// 百搭牌ID
 pub(crate) const JOKER_ID: u8 = 42;
 
-/// 红五万ID - 用于表示红宝牌
+/// 红五万ID - 红宝牌与普通五万共享此ID，反序列化时配合`is_red`标记还原
 pub(crate) const RED_MAN5_ID: u8 = CHARACTER_START + 5 - 1; // 4
-/// 红五筒ID - 用于表示红宝牌
+/// 红五筒ID - 红宝牌与普通五筒共享此ID，反序列化时配合`is_red`标记还原
 pub(crate) const RED_PIN5_ID: u8 = DOT_START + 5 - 1; // 13
-/// 红五索ID - 用于表示红宝牌
+/// 红五索ID - 红宝牌与普通五索共享此ID，反序列化时配合`is_red`标记还原
 pub(crate) const RED_SOU5_ID: u8 = BAMBOO_START + 5 - 1; // 22
@@ -2,16 +2,27 @@
 // 负责按照指定规则初始化麻将牌，洗牌并构建初始牌墙
 
 use rand::prelude::*;
-use crate::tile::{Tile, Suit, Wind, Dragon, Flower};
+use crate::tile::{Tile, Suit, Wind, Dragon, Flower, PhysicalTile, COPIES_PER_KIND};
 use crate::errors::{MajiangError, MajiangResult};
 
 /// 表示不同类型的麻将牌组构成
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum WallConfig {
     /// 日本麻将: 无花牌，四副基本牌(万、筒、条、字牌)，共136张
+    /// 
+    /// 五万、五筒、五索中各有一张为红宝牌(赤五)
     Riichi,
     
+    /// 三人日本麻将: 在日本麻将的基础上去掉二万至八万，共108张
+    /// 
+    /// 五筒、五索中各有一张为红宝牌
+    Sanma,
+    
+    /// 四川麻将: 只有万子、筒子、索子三门数牌，没有字牌和花牌，共108张
+    Sichuan,
+    
     /// 中国官方规则(MCR): 有花牌，四副基本牌，共144张
+    #[default]
     MCR,
     
     /// 上海麻将: 有花牌，可能还有百搭牌，根据配置可能有其他特殊牌
@@ -33,24 +44,59 @@ pub enum WallConfig {
     },
 }
 
-impl Default for WallConfig {
-    fn default() -> Self {
-        WallConfig::MCR
-    }
-}
+/// 牌面及其副本序号(同种牌中的第几张)
+type TileCopy = (Tile, u8);
 
 /// 构建指定配置的完整麻将牌集
 /// 
+/// 只生成牌面，不分配实体编号，因此自定义配置可以超过4副。
+/// 
 /// # 参数
 /// * `config` - 牌墙配置，指定使用哪种规则的牌组
 /// 
 /// # 返回值
 /// * `MaijiangResult<Vec<Tile>>` - 成功则返回构建好的牌集，失败则返回错误
 pub fn build_tiles(config: WallConfig) -> MajiangResult<Vec<Tile>> {
+    let tiles = build_tile_copies(config)?;
+    Ok(tiles.into_iter().map(|(tile, _)| tile).collect())
+}
+
+/// 构建指定配置的完整实体牌集
+/// 
+/// 与`build_tiles`的牌面和顺序完全一致，但每张牌都分配了实体编号，
+/// 牌墙、岭上牌区和副露都以实体牌为单位保存，便于牌谱回放区分同种牌的不同副本。
+/// 
+/// # 参数
+/// * `config` - 牌墙配置，指定使用哪种规则的牌组
+/// 
+/// # 返回值
+/// * `MajiangResult<Vec<PhysicalTile>>` - 成功则返回构建好的实体牌集，失败则返回错误
+/// 
+/// # 错误
+/// 实体编号为每种牌预留了4个副本，自定义配置超过4副时返回`RuleViolation`
+pub fn build_physical_tiles(config: WallConfig) -> MajiangResult<Vec<PhysicalTile>> {
+    if let WallConfig::Custom { suit_sets, honor_sets, .. } = config {
+        if suit_sets > COPIES_PER_KIND || honor_sets > COPIES_PER_KIND {
+            return Err(MajiangError::RuleViolation(
+                format!("实体牌每种最多{}副", COPIES_PER_KIND)
+            ));
+        }
+    }
+    
+    build_tile_copies(config)?
+        .into_iter()
+        .map(|(tile, copy)| PhysicalTile::new(tile, copy))
+        .collect()
+}
+
+/// 按配置列出所有牌面及其副本序号
+fn build_tile_copies(config: WallConfig) -> MajiangResult<Vec<TileCopy>> {
     match config {
-        WallConfig::Riichi => build_riichi_tiles(),
-        WallConfig::MCR => build_mcr_tiles(),
-        WallConfig::Shanghai { with_joker } => build_shanghai_tiles(with_joker),
+        WallConfig::Riichi => Ok(build_riichi_tiles()),
+        WallConfig::Sanma => Ok(build_sanma_tiles()),
+        WallConfig::Sichuan => Ok(build_sichuan_tiles()),
+        WallConfig::MCR => Ok(build_mcr_tiles()),
+        WallConfig::Shanghai { with_joker } => Ok(build_shanghai_tiles(with_joker)),
         WallConfig::Custom { flowers, with_joker, suit_sets, honor_sets } => {
             build_custom_tiles(flowers, with_joker, suit_sets, honor_sets)
         }
@@ -58,20 +104,43 @@ pub fn build_tiles(config: WallConfig) -> MajiangResult<Vec<Tile>> {
 }
 
 /// 构建日本麻将使用的牌组(无花牌)
-fn build_riichi_tiles() -> MajiangResult<Vec<Tile>> {
+fn build_riichi_tiles() -> Vec<TileCopy> {
     let mut tiles = Vec::with_capacity(136);
     
     // 添加4副数牌: 万子、筒子、索子
     add_suit_tiles(&mut tiles, 4);
     
+    // 每种花色各有一张五是红宝牌
+    mark_red_fives(&mut tiles);
+    
     // 添加4副字牌: 风牌(东南西北)和三元牌(中发白)
     add_honor_tiles(&mut tiles, 4);
     
-    Ok(tiles)
+    tiles
+}
+
+/// 构建三人日本麻将使用的牌组(无二万至八万)
+fn build_sanma_tiles() -> Vec<TileCopy> {
+    let mut tiles = build_riichi_tiles();
+    
+    // 万子只保留一万和九万(红五万随五万一起去掉)
+    tiles.retain(|(t, _)| !matches!(t.suit_and_number(), Some((Suit::Character, 2..=8))));
+    
+    tiles
+}
+
+/// 构建四川麻将使用的牌组(只有数牌)
+fn build_sichuan_tiles() -> Vec<TileCopy> {
+    let mut tiles = Vec::with_capacity(108);
+    
+    // 添加4副数牌: 万子、筒子、索子
+    add_suit_tiles(&mut tiles, 4);
+    
+    tiles
 }
 
 /// 构建中国官方麻将(MCR)使用的牌组(含花牌)
-fn build_mcr_tiles() -> MajiangResult<Vec<Tile>> {
+fn build_mcr_tiles() -> Vec<TileCopy> {
     let mut tiles = Vec::with_capacity(144);
     
     // 添加4副数牌: 万子、筒子、索子
@@ -83,11 +152,11 @@ fn build_mcr_tiles() -> MajiangResult<Vec<Tile>> {
     // 添加8张花牌: 春夏秋冬梅兰竹菊
     add_flower_tiles(&mut tiles);
     
-    Ok(tiles)
+    tiles
 }
 
 /// 构建上海麻将使用的牌组(含花牌，可选百搭)
-fn build_shanghai_tiles(with_joker: bool) -> MajiangResult<Vec<Tile>> {
+fn build_shanghai_tiles(with_joker: bool) -> Vec<TileCopy> {
     let mut tiles = Vec::with_capacity(if with_joker { 145 } else { 144 });
     
     // 添加4副数牌: 万子、筒子、索子
@@ -101,14 +170,14 @@ fn build_shanghai_tiles(with_joker: bool) -> MajiangResult<Vec<Tile>> {
     
     // 如果需要，添加百搭牌
     if with_joker {
-        tiles.push(Tile::Joker);
+        tiles.push((Tile::Joker, 0));
     }
     
-    Ok(tiles)
+    tiles
 }
 
 /// 构建自定义配置的牌组
-fn build_custom_tiles(flowers: u8, with_joker: bool, suit_sets: u8, honor_sets: u8) -> MajiangResult<Vec<Tile>> {
+fn build_custom_tiles(flowers: u8, with_joker: bool, suit_sets: u8, honor_sets: u8) -> MajiangResult<Vec<TileCopy>> {
     if flowers > 8 {
         return Err(MajiangError::RuleViolation("花牌数量不能超过8张".to_string()));
     }
@@ -128,81 +197,87 @@ fn build_custom_tiles(flowers: u8, with_joker: bool, suit_sets: u8, honor_sets:
     
     // 如果需要，添加百搭牌
     if with_joker {
-        tiles.push(Tile::Joker);
+        tiles.push((Tile::Joker, 0));
     }
     
     Ok(tiles)
 }
 
 /// 添加指定副数的数牌(万子、筒子、索子)到牌集
-fn add_suit_tiles(tiles: &mut Vec<Tile>, sets: u8) {
+/// 
+/// 第几副即为该牌的副本序号
+fn add_suit_tiles(tiles: &mut Vec<TileCopy>, sets: u8) {
     let suits: [Suit; 3] = [Suit::Character, Suit::Dot, Suit::Bamboo];
     
     for &suit in &suits {
-        for _ in 0..sets {
+        for copy in 0..sets {
             for n in 1..=9 {
                 if let Some(tile) = Tile::new_suit(suit, n) {
-                    tiles.push(tile);
+                    tiles.push((tile, copy));
                 }
             }
         }
     }
 }
 
+/// 将每种花色第0号副本的五替换为红五
+/// 
+/// 红五是具体某一张牌的属性，因此只替换一张，其余三张仍为普通五。
+/// 选择第0号副本与天凤牌谱的红五编号(16/52/88)保持一致。
+fn mark_red_fives(tiles: &mut [TileCopy]) {
+    for suit in [Suit::Character, Suit::Dot, Suit::Bamboo] {
+        let five = Tile::Suit(suit, 5);
+        if let Some(slot) = tiles.iter_mut().find(|&&mut (t, copy)| t == five && copy == 0) {
+            slot.0 = Tile::new_red_five(suit);
+        }
+    }
+}
+
 /// 添加指定副数的字牌(风牌和三元牌)到牌集
-fn add_honor_tiles(tiles: &mut Vec<Tile>, sets: u8) {
+fn add_honor_tiles(tiles: &mut Vec<TileCopy>, sets: u8) {
     // 添加风牌: 东南西北
     let winds = [Wind::East, Wind::South, Wind::West, Wind::North];
     for &wind in &winds {
-        for _ in 0..sets {
-            tiles.push(Tile::Wind(wind));
+        for copy in 0..sets {
+            tiles.push((Tile::Wind(wind), copy));
         }
     }
     
     // 添加三元牌: 中发白
     let dragons = [Dragon::Red, Dragon::Green, Dragon::White];
     for &dragon in &dragons {
-        for _ in 0..sets {
-            tiles.push(Tile::Dragon(dragon));
+        for copy in 0..sets {
+            tiles.push((Tile::Dragon(dragon), copy));
         }
     }
 }
 
 /// 添加所有8张花牌到牌集
-fn add_flower_tiles(tiles: &mut Vec<Tile>) {
-    // 季节牌: 春夏秋冬
-    tiles.push(Tile::Flower(Flower::Spring));
-    tiles.push(Tile::Flower(Flower::Summer));
-    tiles.push(Tile::Flower(Flower::Autumn));
-    tiles.push(Tile::Flower(Flower::Winter));
-    
-    // 花牌: 梅兰竹菊
-    tiles.push(Tile::Flower(Flower::Plum));
-    tiles.push(Tile::Flower(Flower::Orchid));
-    tiles.push(Tile::Flower(Flower::Bamboo));
-    tiles.push(Tile::Flower(Flower::Chrysanthemum));
+fn add_flower_tiles(tiles: &mut Vec<TileCopy>) {
+    // 季节牌: 春夏秋冬; 花牌: 梅兰竹菊
+    add_partial_flower_tiles(tiles, 8)
 }
 
 /// 添加指定数量的花牌到牌集
-fn add_partial_flower_tiles(tiles: &mut Vec<Tile>, count: u8) {
+fn add_partial_flower_tiles(tiles: &mut Vec<TileCopy>, count: u8) {
     let all_flowers = [
         Flower::Spring, Flower::Summer, Flower::Autumn, Flower::Winter,
         Flower::Plum, Flower::Orchid, Flower::Bamboo, Flower::Chrysanthemum
     ];
     
-    for i in 0..(count as usize) {
-        if i < all_flowers.len() {
-            tiles.push(Tile::Flower(all_flowers[i]));
-        }
+    for &flower in all_flowers.iter().take(count as usize) {
+        tiles.push((Tile::Flower(flower), 0));
     }
 }
 
 /// 洗牌函数，随机打乱牌的顺序
 /// 
+/// 对牌面和实体牌都适用，相同的随机数生成器状态产生相同的排列
+/// 
 /// # 参数
 /// * `tiles` - 要洗牌的牌集合
 /// * `rng` - 随机数生成器
-pub fn shuffle_tiles<R: Rng>(tiles: &mut [Tile], rng: &mut R) {
+pub fn shuffle_tiles<T, R: Rng>(tiles: &mut [T], rng: &mut R) {
     tiles.shuffle(rng);
 }
 
@@ -216,19 +291,38 @@ pub fn shuffle_tiles<R: Rng>(tiles: &mut [Tile], rng: &mut R) {
 /// * `MaijiangResult<Vec<Tile>>` - 洗好牌的麻将牌墙
 pub fn create_shuffled_tiles(config: WallConfig, seed: Option<u64>) -> MajiangResult<Vec<Tile>> {
     let mut tiles = build_tiles(config)?;
-    
+    shuffle_with_seed(&mut tiles, seed);
+    Ok(tiles)
+}
+
+/// 创建一个洗好牌的实体牌墙
+/// 
+/// 与`create_shuffled_tiles`使用同一洗牌过程，相同种子得到相同的牌序
+/// 
+/// # 参数
+/// * `config` - 牌墙配置
+/// * `seed` - 可选的随机数种子，用于测试或复现
+/// 
+/// # 返回值
+/// * `MajiangResult<Vec<PhysicalTile>>` - 洗好牌的实体牌墙
+pub fn create_shuffled_physical_tiles(config: WallConfig, seed: Option<u64>) -> MajiangResult<Vec<PhysicalTile>> {
+    let mut tiles = build_physical_tiles(config)?;
+    shuffle_with_seed(&mut tiles, seed);
+    Ok(tiles)
+}
+
+/// 按种子洗牌，没有种子时使用系统随机源
+fn shuffle_with_seed<T>(tiles: &mut [T], seed: Option<u64>) {
     match seed {
         Some(seed_value) => {
             // 使用提供的种子创建随机数生成器
             let mut rng = StdRng::seed_from_u64(seed_value);
-            shuffle_tiles(&mut tiles, &mut rng);
+            shuffle_tiles(tiles, &mut rng);
         }
         None => {
             // 使用系统随机源
             let mut rng = rand::thread_rng();
-            shuffle_tiles(&mut tiles, &mut rng);
+            shuffle_tiles(tiles, &mut rng);
         }
     }
-    
-    Ok(tiles)
-}
\ No newline at end of file
+}
@@ -1,7 +1,7 @@
 // 岭上牌区模块
 // 处理麻将中的岭上牌、宝牌指示牌和杠后补牌等特殊牌区
 
-use crate::tile::Tile;
+use crate::tile::{Tile, PhysicalTile};
 use crate::errors::{MajiangError, MajiangResult};
 
 /// 岭上牌区的配置选项
@@ -41,8 +41,8 @@ impl Default for DeadWallConfig {
 /// 在不同的麻将规则中有不同的实现
 #[derive(Debug, Clone)]
 pub struct DeadWall {
-    /// 岭上牌区的所有牌
-    tiles: Vec<Tile>,
+    /// 岭上牌区的所有牌(以实体牌保存，便于牌谱记录宝牌指示牌和岭上牌的具体副本)
+    tiles: Vec<PhysicalTile>,
     
     /// 岭上牌区配置
     config: DeadWallConfig,
@@ -60,7 +60,7 @@ impl DeadWall {
     /// 
     /// # 返回值
     /// * `MajiangResult<Self>` - 成功则返回创建的岭上牌区，失败则返回错误
-    pub fn new(tiles: &mut Vec<Tile>, config: DeadWallConfig) -> MajiangResult<Self> {
+    pub fn new(tiles: &mut Vec<PhysicalTile>, config: DeadWallConfig) -> MajiangResult<Self> {
         match config {
             DeadWallConfig::None => Ok(Self {
                 tiles: Vec::new(),
@@ -68,7 +68,7 @@ impl DeadWall {
                 revealed_indicator_indices: Vec::new(),
             }),
             
-            DeadWallConfig::Riichi { dora_indicators, uradora_indicators } => {
+            DeadWallConfig::Riichi { dora_indicators, uradora_indicators: _ } => {
                 let dead_wall_size = 14;  // 日麻标准岭上牌数量
                 
                 if tiles.len() < dead_wall_size {
@@ -138,11 +138,7 @@ impl DeadWall {
                 let tiles_taken = 16 - self.tiles.len(); // 岭上牌初始16张，减去当前数量得到已取走的数量
                 
                 // 确保不会返回负数
-                if tiles_taken >= original_size {
-                    0
-                } else {
-                    original_size - tiles_taken
-                }
+                original_size.saturating_sub(tiles_taken)
             }
         }
     }
@@ -152,6 +148,14 @@ impl DeadWall {
     /// # 返回值
     /// * `MajiangResult<Tile>` - 成功则返回取出的牌，失败则返回错误
     pub fn draw_replacement_tile(&mut self) -> MajiangResult<Tile> {
+        self.draw_physical_replacement_tile().map(Tile::from)
+    }
+    
+    /// 从岭上牌区取一张补牌，保留实体编号
+    /// 
+    /// # 返回值
+    /// * `MajiangResult<PhysicalTile>` - 成功则返回取出的实体牌，失败则返回错误
+    pub fn draw_physical_replacement_tile(&mut self) -> MajiangResult<PhysicalTile> {
         match self.config {
             DeadWallConfig::None => {
                 Err(MajiangError::InvalidOperation("没有配置岭上牌区".to_string()))
@@ -179,6 +183,13 @@ impl DeadWall {
         }
     }
     
+    /// 向岭上牌区补入一张牌，它会成为下一张补牌
+    /// 
+    /// 用于立直麻将开杠和三人麻将拔北后从主牌墙末尾补充岭上牌区，宝牌指示牌的位置不受影响。
+    pub fn replenish(&mut self, tile: PhysicalTile) {
+        self.tiles.push(tile);
+    }
+    
     /// 获取当前的宝牌指示牌(仅适用于日麻)
     /// 
     /// # 返回值
@@ -190,7 +201,7 @@ impl DeadWall {
                 
                 for &index in &self.revealed_indicator_indices {
                     if index < self.tiles.len() {
-                        indicators.push(&self.tiles[index]);
+                        indicators.push(&self.tiles[index].tile);
                     } else {
                         return Err(MajiangError::InvalidOperation("宝牌指示牌索引越界".to_string()));
                     }
@@ -216,7 +227,7 @@ impl DeadWall {
                     // 计算里宝牌指示牌的索引：2*i+1
                     let index = i * 2 + 1;
                     if index < self.tiles.len() {
-                        indicators.push(&self.tiles[index]);
+                        indicators.push(&self.tiles[index].tile);
                     } else {
                         return Err(MajiangError::InvalidOperation("里宝牌指示牌索引越界".to_string()));
                     }
@@ -248,14 +259,21 @@ impl DeadWall {
                 }
                 
                 self.revealed_indicator_indices.push(next_index);
-                Ok(&self.tiles[next_index])
+                Ok(&self.tiles[next_index].tile)
             },
             _ => Err(MajiangError::InvalidOperation("该规则没有宝牌指示牌".to_string()))
         }
     }
     
+    /// 获取当前宝牌指示牌对应的实体牌(用于牌谱记录)
+    pub fn get_dora_indicator_instances(&self) -> Vec<PhysicalTile> {
+        self.revealed_indicator_indices.iter()
+            .filter_map(|&index| self.tiles.get(index).copied())
+            .collect()
+    }
+    
     /// 获取所有岭上牌区的牌(用于调试或测试)
-    pub fn get_all_tiles(&self) -> &[Tile] {
+    pub fn get_all_tiles(&self) -> &[PhysicalTile] {
         &self.tiles
     }
 }
\ No newline at end of file
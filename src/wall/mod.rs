@@ -2,7 +2,7 @@
 // 提供牌墙的基本功能，包括初始化、洗牌、发牌和摸牌
 
 use std::collections::VecDeque;
-use crate::tile::Tile;
+use crate::tile::{Tile, PhysicalTile};
 use crate::errors::{MajiangError, MajiangResult};
 
 // 导入子模块
@@ -13,12 +13,22 @@ pub mod dead_wall;
 pub use builder::WallConfig;
 pub use dead_wall::{DeadWall, DeadWallConfig};
 
+/// 补牌(补花和杠后补牌)的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReplacementSource {
+    /// 从岭上牌区(补花区)摸，补牌区用完后从主牌墙前端摸
+    #[default]
+    DeadWall,
+    /// 从主牌墙的末尾摸
+    LiveWallBack,
+}
+
 /// 麻将牌墙，管理游戏中的所有牌
 /// 包括主牌墙和岭上牌区(若规则需要)
 #[derive(Debug, Clone)]
 pub struct Wall {
-    /// 主牌墙，存储所有可摸牌
-    wall: VecDeque<Tile>,
+    /// 主牌墙，存储所有可摸牌(以实体牌保存，摸牌时可取回实体编号)
+    wall: VecDeque<PhysicalTile>,
     
     /// 岭上牌区，存储特殊用途牌(如宝牌指示牌、花牌补充等)
     dead_wall: Option<DeadWall>,
@@ -43,13 +53,15 @@ impl Wall {
     /// 
     /// # 返回值
     /// * `Result<Self>` - 成功则返回创建的牌墙，失败则返回错误
+    /// 
+    /// 牌墙以实体牌保存，自定义配置超过4副时无法编号，返回`RuleViolation`
     pub fn new(
         wall_config: WallConfig,
         dead_wall_config: Option<DeadWallConfig>,
         seed: Option<u64>
     ) -> MajiangResult<Self> {
         // 创建洗好的牌集
-        let mut tiles = builder::create_shuffled_tiles(wall_config, seed)?;
+        let mut tiles = builder::create_shuffled_physical_tiles(wall_config, seed)?;
         
         // 如果配置了岭上牌区，则从牌集末尾创建
         let dead_wall = match dead_wall_config {
@@ -89,6 +101,14 @@ impl Wall {
     /// # 返回值
     /// * `Result<Tile>` - 成功则返回摸到的牌，失败则返回错误
     pub fn draw_tile(&mut self) -> MajiangResult<Tile> {
+        self.draw_physical_tile().map(Tile::from)
+    }
+    
+    /// 从牌墙摸一张牌，保留实体编号
+    /// 
+    /// # 返回值
+    /// * `Result<PhysicalTile>` - 成功则返回摸到的实体牌，失败则返回错误
+    pub fn draw_physical_tile(&mut self) -> MajiangResult<PhysicalTile> {
         if !self.game_started {
             return Err(MajiangError::InvalidOperation("游戏尚未开始，不能摸牌".to_string()));
         }
@@ -99,12 +119,73 @@ impl Wall {
         
         // 从牌墙前端取牌
         let tile = self.wall.pop_front()
-            .ok_or_else(|| MajiangError::NotEnoughTiles)?;
+            .ok_or(MajiangError::NotEnoughTiles)?;
         
         self.drawn_count += 1;
         Ok(tile)
     }
     
+    /// 从主牌墙的末尾摸一张牌(补花或杠后补牌)
+    /// 
+    /// # 返回值
+    /// * `Result<Tile>` - 成功则返回摸到的牌，失败则返回错误
+    pub fn draw_tile_from_back(&mut self) -> MajiangResult<Tile> {
+        if !self.game_started {
+            return Err(MajiangError::InvalidOperation("游戏尚未开始，不能摸牌".to_string()));
+        }
+        
+        let tile = self.wall.pop_back()
+            .ok_or(MajiangError::NotEnoughTiles)?;
+        
+        self.drawn_count += 1;
+        Ok(tile.into())
+    }
+    
+    /// 按来源摸一张补牌
+    /// 
+    /// # 参数
+    /// * `source` - 补牌来源；从岭上牌区摸时，补牌区用完后改从主牌墙前端摸
+    /// 
+    /// # 返回值
+    /// * `Result<Tile>` - 成功则返回摸到的补牌，牌已摸完时返回`NotEnoughTiles`
+    pub fn draw_replacement_from(&mut self, source: ReplacementSource) -> MajiangResult<Tile> {
+        match source {
+            ReplacementSource::DeadWall => {
+                let has_replacement = self.dead_wall.as_ref()
+                    .is_some_and(|d| d.remaining_replacement_tiles() > 0);
+                if has_replacement {
+                    self.draw_replacement_tile()
+                } else {
+                    self.draw_tile()
+                }
+            },
+            ReplacementSource::LiveWallBack => self.draw_tile_from_back(),
+        }
+    }
+    
+    /// 从岭上牌区摸一张补牌并补充岭上牌区(立直麻将的杠和拔北)
+    /// 
+    /// 从岭上牌区摸牌后，把主牌墙末尾的一张移入岭上牌区，岭上牌区的张数保持不变，
+    /// 海底牌因此前移一张。
+    /// 
+    /// # 返回值
+    /// * `Result<Tile>` - 成功则返回摸到的补牌，主牌墙已摸完时返回`NotEnoughTiles`
+    pub fn draw_replenished_replacement(&mut self) -> MajiangResult<Tile> {
+        if self.wall.is_empty() {
+            return Err(MajiangError::NotEnoughTiles);
+        }
+        let tile = self.draw_physical_replacement_tile()?;
+        if let (Some(back), Some(dead_wall)) = (self.wall.pop_back(), self.dead_wall.as_mut()) {
+            dead_wall.replenish(back);
+        }
+        Ok(tile.into())
+    }
+    
+    /// 拔北后摸一张补牌，见`draw_replenished_replacement`
+    pub fn draw_kita_replacement(&mut self) -> MajiangResult<Tile> {
+        self.draw_replenished_replacement()
+    }
+    
     /// 从牌墙发初始手牌(通常是13张)
     /// 
     /// # 参数
@@ -113,6 +194,18 @@ impl Wall {
     /// # 返回值
     /// * `Result<Vec<Tile>>` - 成功则返回发出的牌组，失败则返回错误
     pub fn deal_initial_hand(&mut self, count: usize) -> MajiangResult<Vec<Tile>> {
+        let hand = self.deal_initial_physical_hand(count)?;
+        Ok(hand.into_iter().map(Tile::from).collect())
+    }
+    
+    /// 从牌墙发初始手牌，保留实体编号
+    /// 
+    /// # 参数
+    /// * `count` - 要发的牌数量，通常为13
+    /// 
+    /// # 返回值
+    /// * `Result<Vec<PhysicalTile>>` - 成功则返回发出的实体牌组，失败则返回错误
+    pub fn deal_initial_physical_hand(&mut self, count: usize) -> MajiangResult<Vec<PhysicalTile>> {
         if self.game_started {
             return Err(MajiangError::InvalidOperation("游戏已经开始，不能发初始手牌".to_string()));
         }
@@ -141,13 +234,21 @@ impl Wall {
     /// # 返回值
     /// * `Result<Tile>` - 成功则返回摸到的补牌，失败则返回错误
     pub fn draw_replacement_tile(&mut self) -> MajiangResult<Tile> {
+        self.draw_physical_replacement_tile().map(Tile::from)
+    }
+    
+    /// 杠后从岭上牌区摸一张补牌，保留实体编号
+    /// 
+    /// # 返回值
+    /// * `Result<PhysicalTile>` - 成功则返回摸到的实体补牌，失败则返回错误
+    pub fn draw_physical_replacement_tile(&mut self) -> MajiangResult<PhysicalTile> {
         if !self.game_started {
             return Err(MajiangError::InvalidOperation("游戏尚未开始，不能摸补牌".to_string()));
         }
         
         match &mut self.dead_wall {
             Some(dead_wall) => {
-                let tile = dead_wall.draw_replacement_tile()?;
+                let tile = dead_wall.draw_physical_replacement_tile()?;
                 self.drawn_count += 1;
                 Ok(tile)
             },
@@ -210,7 +311,7 @@ impl Wall {
     
     /// 获取主牌墙中的所有牌(仅用于测试)
     #[cfg(test)]
-    pub fn get_all_wall_tiles(&self) -> &VecDeque<Tile> {
+    pub fn get_all_wall_tiles(&self) -> &VecDeque<PhysicalTile> {
         &self.wall
     }
 }
\ No newline at end of file
@@ -0,0 +1,5 @@
+// tests/action/mod.rs
+//
+// 玩家动作模块的测试入口文件
+
+mod test_legal_actions;
@@ -0,0 +1,201 @@
+// tests/action/test_legal_actions.rs
+//
+// 动作合法性枚举的测试用例
+// 覆盖自己回合(打牌、立直、自摸、杠、九种九牌、补花)与应对他家打牌(荣和、吃碰杠)
+
+use majiang_core::action::{legal_actions, is_legal, may_react, AbortiveDrawKind, Action, PlayerView, Situation};
+use majiang_core::hand::Hand;
+use majiang_core::meld::KanType;
+use majiang_core::tile::{Tile, Suit, Wind, Dragon, Flower};
+
+fn m(n: u8) -> Tile {
+    Tile::Suit(Suit::Character, n)
+}
+
+fn p(n: u8) -> Tile {
+    Tile::Suit(Suit::Dot, n)
+}
+
+/// 解析13张手牌并摸入一张牌
+fn drawn_hand(notation: &str, drawn: Tile) -> Hand {
+    let mut hand: Hand = notation.parse().unwrap();
+    hand.draw(drawn).unwrap();
+    hand
+}
+
+fn turn_view(hand: &Hand) -> PlayerView<'_> {
+    PlayerView::new(0, hand, Situation::Turn { after_call: false }, 50)
+}
+
+/// 测试摸牌阶段
+#[test]
+fn test_draw_phase() {
+    let hand: Hand = "123m456p789s1122z".parse().unwrap();
+    let view = PlayerView::new(0, &hand, Situation::Draw, 10);
+    assert_eq!(legal_actions(&view), vec![Action::Draw]);
+
+    // 牌墙摸完后没有可执行的动作
+    let view = PlayerView::new(0, &hand, Situation::Draw, 0);
+    assert!(legal_actions(&view).is_empty());
+}
+
+/// 测试打牌候选：每种牌一个手切选项，摸牌单独作为摸切选项
+#[test]
+fn test_discard_candidates() {
+    let hand = drawn_hand("1139m05p19s1234z", m(1));
+    let mut view = turn_view(&hand);
+    view.options.allow_riichi = false;
+    let actions = legal_actions(&view);
+
+    assert!(actions.contains(&Action::Discard { tile: m(1), tsumogiri: false }));
+    assert!(actions.contains(&Action::Discard { tile: m(1), tsumogiri: true }));
+    // 红五与普通五分别列出
+    assert!(actions.contains(&Action::Discard { tile: Tile::new_red_five(Suit::Dot), tsumogiri: false }));
+    assert!(actions.contains(&Action::Discard { tile: p(5), tsumogiri: false }));
+    // 11种不同的暗手牌加一个摸切
+    assert_eq!(actions.iter().filter(|a| a.is_discard()).count(), 12);
+    assert!(!actions.contains(&Action::Tsumo));
+}
+
+/// 测试立直：只列出打出后听牌的打法
+#[test]
+fn test_riichi_options() {
+    let hand = drawn_hand("123m456p789s23m11z", m(5));
+    let actions = legal_actions(&turn_view(&hand));
+
+    let riichi: Vec<Action> = actions.iter().copied().filter(|a| matches!(a, Action::Riichi { .. })).collect();
+    assert_eq!(riichi, vec![
+        Action::Riichi { tile: m(2), tsumogiri: false },
+        Action::Riichi { tile: m(5), tsumogiri: true },
+    ]);
+
+    // 牌墙不足一巡时不能立直
+    let mut view = turn_view(&hand);
+    view.wall_remaining = 3;
+    assert!(!legal_actions(&view).iter().any(|a| matches!(a, Action::Riichi { .. })));
+    // 立直所需的剩余牌数由规则选项决定
+    view.options.riichi_min_wall = 3;
+    assert!(legal_actions(&view).iter().any(|a| matches!(a, Action::Riichi { .. })));
+
+    // 立直后只能摸切
+    let mut view = turn_view(&hand);
+    view.in_riichi = true;
+    assert_eq!(legal_actions(&view), vec![Action::Discard { tile: m(5), tsumogiri: true }]);
+
+    // 副露后不能立直
+    let hand = drawn_hand("456p789s23m11z[123m]", m(5));
+    assert!(!legal_actions(&turn_view(&hand)).iter().any(|a| matches!(a, Action::Riichi { .. })));
+}
+
+/// 测试自摸、九种九牌与补花
+#[test]
+fn test_tsumo_abortive_and_flower() {
+    let hand = drawn_hand("123m456p789s1122z", Tile::Wind(Wind::South));
+    let actions = legal_actions(&turn_view(&hand));
+    assert_eq!(actions[0], Action::Tsumo);
+
+    // 鸣牌后只能打牌
+    let hand: Hand = "23m456p789s11z[123m]".parse().unwrap();
+    let view = PlayerView::new(0, &hand, Situation::Turn { after_call: true }, 50);
+    assert!(legal_actions(&view).iter().all(|a| a.is_discard()));
+
+    let hand = drawn_hand("19m19p19s1234z258m", Tile::Dragon(Dragon::Red));
+    let nine = Action::AbortiveDraw(AbortiveDrawKind::NineTerminals);
+    let mut view = turn_view(&hand);
+    assert!(!is_legal(&view, &nine));
+    view.first_turn = true;
+    assert!(is_legal(&view, &nine));
+    view.options.allow_abortive_draws = false;
+    assert!(!is_legal(&view, &nine));
+
+    // 摸到花牌必须先补花
+    let hand = drawn_hand("123m456p789s1122z", Tile::Flower(Flower::Plum));
+    assert_eq!(
+        legal_actions(&turn_view(&hand)),
+        vec![Action::DeclareFlower { tile: Tile::Flower(Flower::Plum) }]
+    );
+}
+
+/// 测试暗杠与加杠，以及立直后的暗杠限制
+#[test]
+fn test_self_kans() {
+    let hand = drawn_hand("111m456p789s1122z", m(1));
+    let closed = Action::Kan { tile: m(1), kan_type: KanType::Closed };
+    let mut view = turn_view(&hand);
+    assert!(is_legal(&view, &closed));
+
+    // 场上已有四个杠时不能再杠
+    view.kan_count = 4;
+    assert!(!is_legal(&view, &closed));
+
+    let hand = drawn_hand("456p789s1122z[555m]", m(9));
+    assert!(!legal_actions(&turn_view(&hand)).iter().any(|a| matches!(a, Action::Kan { .. })));
+    let hand = drawn_hand("456p789s1122z[555m]", Tile::new_red_five(Suit::Character));
+    assert!(is_legal(&turn_view(&hand), &Action::Kan { tile: m(5), kan_type: KanType::Added }));
+
+    // 立直后暗杠不改变听牌时可以杠
+    let hand = drawn_hand("111m456p789s1122z", m(1));
+    let mut view = turn_view(&hand);
+    view.in_riichi = true;
+    assert!(is_legal(&view, &closed));
+
+    // 会改变听牌的暗杠不允许(1112m的1万参与了听牌)
+    let hand = drawn_hand("1112m456p789s111z", m(1));
+    let mut view = turn_view(&hand);
+    view.in_riichi = true;
+    assert!(!is_legal(&view, &closed));
+}
+
+/// 测试应对他家打牌
+#[test]
+fn test_discard_reactions() {
+    let hand: Hand = "23445m555p789s11z".parse().unwrap();
+
+    // 下家(座位1)打出的牌不能吃，只能碰杠
+    let view = PlayerView::new(0, &hand, Situation::Discard { tile: p(5), from: 1 }, 30);
+    assert_eq!(
+        legal_actions(&view),
+        vec![Action::Kan { tile: p(5), kan_type: KanType::Open }, Action::Pon { tile: p(5) }, Action::Pass]
+    );
+
+    // 上家(座位3)打出的牌可以吃，每种组合一个选项
+    let view = PlayerView::new(0, &hand, Situation::Discard { tile: m(3), from: 3 }, 30);
+    let chis: Vec<Action> = legal_actions(&view).into_iter().filter(|a| matches!(a, Action::Chi { .. })).collect();
+    assert_eq!(chis.len(), 2);
+    assert!(chis.contains(&Action::Chi { tile: m(3), combination: [m(2), m(3), m(4)] }));
+    assert!(chis.contains(&Action::Chi { tile: m(3), combination: [m(3), m(4), m(5)] }));
+
+    assert!(may_react(&view));
+
+    let view = PlayerView::new(0, &hand, Situation::Discard { tile: m(1), from: 1 }, 30);
+    assert_eq!(legal_actions(&view), vec![Action::Pass]);
+    assert!(!may_react(&view));
+
+    // 河底牌和立直后只能荣和或放弃
+    let view = PlayerView::new(0, &hand, Situation::Discard { tile: p(5), from: 1 }, 0);
+    assert_eq!(legal_actions(&view), vec![Action::Pass]);
+    assert!(!may_react(&view));
+
+    // 自己打出的牌没有应对动作
+    let view = PlayerView::new(0, &hand, Situation::Discard { tile: p(5), from: 0 }, 30);
+    assert!(legal_actions(&view).is_empty());
+}
+
+/// 测试荣和与抢杠
+#[test]
+fn test_ron_and_chankan() {
+    let hand: Hand = "123m456p789s23m11z".parse().unwrap();
+    let mut view = PlayerView::new(2, &hand, Situation::Discard { tile: m(4), from: 0 }, 30);
+    view.in_riichi = true;
+    assert_eq!(legal_actions(&view), vec![Action::Ron { tile: m(4) }, Action::Pass]);
+
+    let view = PlayerView::new(2, &hand, Situation::AddedKan { tile: m(1), from: 3 }, 30);
+    assert_eq!(legal_actions(&view), vec![Action::Ron { tile: m(1) }, Action::Pass]);
+
+    let view = PlayerView::new(2, &hand, Situation::AddedKan { tile: m(7), from: 3 }, 30);
+    assert_eq!(legal_actions(&view), vec![Action::Pass]);
+
+    // 动作的显示
+    assert_eq!(Action::Ron { tile: m(4) }.to_string(), format!("荣和{}", m(4)));
+    assert_eq!(Action::Pass.to_string(), "过");
+}
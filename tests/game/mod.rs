@@ -0,0 +1,10 @@
+// tests/game/mod.rs
+//
+// 游戏流程模块的测试入口文件
+
+mod test_claims;
+mod test_state;
+mod test_match;
+mod test_context;
+mod test_abortive;
+mod test_exhaustive_draw;
@@ -0,0 +1,147 @@
+// tests/game/test_abortive.rs
+//
+// 途中流局的测试用例
+// 覆盖九种九牌、四风连打、四家立直的判定和事件，按规则关闭途中流局，以及流局后的连庄与本场
+
+use std::sync::Arc;
+
+use majiang_core::action::{AbortiveDrawKind, Action};
+use majiang_core::game::{Event, GameConfig, GameState, Match, MatchConfig, Phase, RoundOutcome, RoundResult};
+use majiang_core::hand::{discard_efficiency, VisibleTiles};
+use majiang_core::rules::riichi::AbortiveDrawOptions;
+use majiang_core::rules::{McrRules, RiichiRules, RuleSet};
+use majiang_core::tile::{Tile, Wind};
+
+use crate::helpers::next_actor;
+
+/// 按给定的途中流局选项创建立直麻将的一局
+fn game_with(options: AbortiveDrawOptions, seed: u64) -> GameState {
+    let rules: Arc<dyn RuleSet> = Arc::new(RiichiRules { abortive_draws: options, ..RiichiRules::default() });
+    GameState::with_rules(GameConfig::from_rules(rules.as_ref(), seed), rules).unwrap()
+}
+
+/// 能立直就立直，否则打出牌效率最好的牌，不和牌也不鸣牌
+fn riichi_happy(game: &GameState, seat: u8, actions: &[Action]) -> Action {
+    let find = |pred: &dyn Fn(&Action) -> bool| actions.iter().copied().find(|a| pred(a));
+    if let Some(riichi) = find(&|a| matches!(a, Action::Riichi { .. })) {
+        return riichi;
+    }
+    let hand = game.player(seat).unwrap().hand();
+    let best = discard_efficiency(hand, &VisibleTiles::new()).ok().map(|options| options[0].discard);
+    find(&|a| matches!(a, Action::Discard { tile, .. } if Some(*tile) == best))
+        .or_else(|| find(&|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. })))
+        .unwrap_or(actions[actions.len() - 1])
+}
+
+/// 第一巡四家都打出`wind`，有玩家手中没有这张风牌时返回`None`
+fn discard_wind_four_times(game: &mut GameState, wind: Tile) -> Option<Vec<Event>> {
+    let mut events = Vec::new();
+    for _ in 0..4 {
+        while game.phase() == Phase::WaitingCalls || game.legal_actions(game.current_seat()).contains(&Action::Draw) {
+            let (seat, actions) = next_actor(game);
+            let action = if actions.contains(&Action::Draw) { Action::Draw } else { Action::Pass };
+            events.extend(game.apply(seat, action).unwrap());
+        }
+        let seat = game.current_seat();
+        let discard = game
+            .legal_actions(seat)
+            .into_iter()
+            .find(|a| matches!(a, Action::Discard { tile, .. } if *tile == wind))?;
+        events.extend(game.apply(seat, discard).unwrap());
+    }
+    while game.phase() == Phase::WaitingCalls {
+        let (seat, _) = next_actor(game);
+        events.extend(game.apply(seat, Action::Pass).unwrap());
+    }
+    Some(events)
+}
+
+/// 测试九种九牌：宣言后产生流局事件，关闭后不能宣言
+#[test]
+fn test_nine_terminals() {
+    let nine = Action::AbortiveDraw(AbortiveDrawKind::NineTerminals);
+    let seed = (0..5000)
+        .find(|&seed| {
+            let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+            game.apply(0, Action::Draw).unwrap();
+            game.apply(0, Action::Draw).unwrap();
+            game.legal_actions(0).contains(&nine)
+        })
+        .expect("应有种子在庄家第一巡成立九种九牌");
+
+    let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+    game.apply(0, Action::Draw).unwrap();
+    game.apply(0, Action::Draw).unwrap();
+    let events = game.apply(0, nine).unwrap();
+    assert_eq!(events[0], Event::AbortiveDraw(AbortiveDrawKind::NineTerminals));
+    assert!(matches!(events[1], Event::RoundEnded { .. }));
+    assert_eq!(game.outcome(), Some(&RoundOutcome::AbortiveDraw(AbortiveDrawKind::NineTerminals)));
+
+    let options = AbortiveDrawOptions { nine_terminals: false, ..AbortiveDrawOptions::default() };
+    let mut game = game_with(options, seed);
+    game.apply(0, Action::Draw).unwrap();
+    game.apply(0, Action::Draw).unwrap();
+    assert!(!game.legal_actions(0).contains(&nine));
+}
+
+/// 测试四风连打：第一巡四家打出同一种风牌后流局，关闭后继续对局
+#[test]
+fn test_four_winds() {
+    let winds = [Wind::East, Wind::South, Wind::West, Wind::North].map(Tile::Wind);
+    let (seed, wind) = (0..200)
+        .flat_map(|seed| winds.map(|wind| (seed, wind)))
+        .find(|&(seed, wind)| discard_wind_four_times(&mut GameState::new(GameConfig::riichi(seed)).unwrap(), wind).is_some())
+        .expect("应有种子在第一巡四家都有同一种风牌");
+
+    let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+    let events = discard_wind_four_times(&mut game, wind).unwrap();
+    let index = events.iter().position(|e| *e == Event::AbortiveDraw(AbortiveDrawKind::FourWinds)).unwrap();
+    assert!(matches!(events[index - 1], Event::Discarded { seat: 3, .. }));
+    assert_eq!(game.outcome(), Some(&RoundOutcome::AbortiveDraw(AbortiveDrawKind::FourWinds)));
+
+    let options = AbortiveDrawOptions { four_winds: false, ..AbortiveDrawOptions::default() };
+    let mut game = game_with(options, seed);
+    let events = discard_wind_four_times(&mut game, wind).unwrap();
+    assert!(!events.iter().any(|e| matches!(e, Event::AbortiveDraw(_))));
+    assert_eq!(game.phase(), Phase::WaitingDiscard);
+
+    // 国标麻将没有途中流局
+    let rules: Arc<dyn RuleSet> = Arc::new(McrRules);
+    assert_eq!(rules.abortive_draws(), AbortiveDrawOptions::NONE);
+}
+
+/// 测试四家立直：第四家的宣言牌无人荣和时流局，立直棒留在场上，庄家连庄并积一本场
+#[test]
+fn test_four_riichi() {
+    let aborted = (5..10).find_map(|seed| {
+        let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+        let mut events = Vec::new();
+        while game.phase() != Phase::RoundEnd {
+            let (seat, actions) = next_actor(&game);
+            events.extend(game.apply(seat, riichi_happy(&game, seat, &actions)).unwrap());
+        }
+        let four_riichi = Some(&RoundOutcome::AbortiveDraw(AbortiveDrawKind::FourRiichi));
+        (game.outcome() == four_riichi).then_some((seed, game, events))
+    });
+    let (seed, game, events) = aborted.expect("应有种子四家都立直");
+    let declared = events.iter().rposition(|e| matches!(e, Event::Discarded { riichi: true, .. })).unwrap();
+    assert!(!events[declared..].iter().any(|e| matches!(e, Event::Won { .. })));
+    assert_eq!(events[events.len() - 2], Event::AbortiveDraw(AbortiveDrawKind::FourRiichi));
+
+    let result = RoundResult::from_game(&game, game.point_changes().to_vec()).unwrap();
+    assert!(result.abortive);
+    assert_eq!(result.riichi, [0, 1, 2, 3]);
+    let mut record = Match::new(MatchConfig::default()).unwrap();
+    record.record_round(&result).unwrap();
+    assert_eq!((record.dealer(), record.honba(), record.riichi_sticks()), (0, 1, 4));
+
+    // 关闭后四家立直继续对局
+    let options = AbortiveDrawOptions { four_riichi: false, ..AbortiveDrawOptions::default() };
+    let mut game = game_with(options, seed);
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        game.apply(seat, riichi_happy(&game, seat, &actions)).unwrap();
+    }
+    assert!(!matches!(game.outcome(), Some(RoundOutcome::AbortiveDraw(_))));
+    assert!(game.players().iter().all(|p| p.is_riichi()));
+}
@@ -0,0 +1,102 @@
+// tests/game/test_claims.rs
+//
+// 鸣牌仲裁的测试用例
+// 覆盖优先级、按座位顺序的裁决、截和与多家荣和，以及非法提交
+
+use majiang_core::action::Action;
+use majiang_core::errors::MajiangError;
+use majiang_core::game::{Claim, ClaimArbiter, RonPolicy};
+use majiang_core::meld::KanType;
+use majiang_core::tile::{Tile, Suit};
+
+fn tile() -> Tile {
+    Tile::Suit(Suit::Bamboo, 4)
+}
+
+fn ron() -> Action {
+    Action::Ron { tile: tile() }
+}
+
+fn chi() -> Action {
+    let s = |n| Tile::Suit(Suit::Bamboo, n);
+    Action::Chi { tile: tile(), combination: [s(2), s(3), s(4)] }
+}
+
+/// 测试荣和优先于碰杠，碰杠优先于吃
+#[test]
+fn test_priority_order() {
+    let mut arbiter = ClaimArbiter::new(3, 4, RonPolicy::HeadBump);
+    arbiter.submit(0, chi()).unwrap();
+    arbiter.submit(1, Action::Kan { tile: tile(), kan_type: KanType::Open }).unwrap();
+    arbiter.submit(2, ron()).unwrap();
+    assert!(arbiter.is_complete());
+
+    let resolution = arbiter.resolve();
+    assert_eq!(resolution.winners, vec![Claim { seat: 2, action: ron() }]);
+    assert_eq!(resolution.rejected.iter().map(|c| c.seat).collect::<Vec<_>>(), vec![1, 0]);
+}
+
+/// 测试截和：离打牌者最近的一家优先
+#[test]
+fn test_head_bump() {
+    // 座位2打牌，座位0和座位1都荣和，下家方向的座位3放弃
+    let mut arbiter = ClaimArbiter::new(2, 4, RonPolicy::HeadBump);
+    arbiter.submit(1, ron()).unwrap();
+    arbiter.submit(0, ron()).unwrap();
+    assert!(!arbiter.is_complete());
+    arbiter.submit(3, Action::Pass).unwrap();
+
+    let resolution = arbiter.resolve();
+    assert_eq!(resolution.winners.len(), 1);
+    assert_eq!(resolution.winners[0].seat, 0);
+    assert_eq!(resolution.rejected, vec![Claim { seat: 1, action: ron() }]);
+}
+
+/// 测试多家荣和：所有荣和都生效，较低优先级的宣言被否决
+#[test]
+fn test_multiple_ron() {
+    let mut arbiter = ClaimArbiter::new(0, 4, RonPolicy::Multiple);
+    arbiter.submit(3, ron()).unwrap();
+    arbiter.submit(2, Action::Pon { tile: tile() }).unwrap();
+    arbiter.submit(1, ron()).unwrap();
+
+    let resolution = arbiter.resolve();
+    assert_eq!(resolution.winners.iter().map(|c| c.seat).collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(resolution.rejected, vec![Claim { seat: 2, action: Action::Pon { tile: tile() } }]);
+}
+
+/// 测试无人宣言和单一宣言
+#[test]
+fn test_no_contest() {
+    let arbiter = ClaimArbiter::new(1, 4, RonPolicy::HeadBump);
+    let resolution = arbiter.resolve();
+    assert!(resolution.winners.is_empty());
+    assert!(resolution.rejected.is_empty());
+
+    // 三人麻将的座位顺序
+    let mut arbiter = ClaimArbiter::new(1, 3, RonPolicy::HeadBump);
+    arbiter.submit(0, Action::Pon { tile: tile() }).unwrap();
+    arbiter.submit(2, Action::Pass).unwrap();
+    assert!(arbiter.is_complete());
+    assert_eq!(arbiter.claims().len(), 1);
+    assert_eq!(arbiter.resolve().winners[0].seat, 0);
+}
+
+/// 测试非法提交
+#[test]
+fn test_invalid_submissions() {
+    let mut arbiter = ClaimArbiter::new(0, 4, RonPolicy::HeadBump);
+
+    // 打牌者本人和不存在的座位
+    assert!(matches!(arbiter.submit(0, ron()), Err(MajiangError::InvalidOperation(_))));
+    assert!(matches!(arbiter.submit(4, ron()), Err(MajiangError::InvalidOperation(_))));
+
+    // 不是宣言的动作
+    assert!(matches!(arbiter.submit(1, Action::Tsumo), Err(MajiangError::InvalidAction(_))));
+    let closed = Action::Kan { tile: tile(), kan_type: KanType::Closed };
+    assert!(matches!(arbiter.submit(1, closed), Err(MajiangError::InvalidAction(_))));
+
+    // 重复提交
+    arbiter.submit(1, Action::Pass).unwrap();
+    assert!(matches!(arbiter.submit(1, ron()), Err(MajiangError::InvalidOperation(_))));
+}
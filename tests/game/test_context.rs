@@ -0,0 +1,102 @@
+// tests/game/test_context.rs
+//
+// 和牌场况(WinContext)的测试用例
+// 在多局自动对局中检查GameState构建的场况与事件记录一致
+
+use majiang_core::action::Action;
+use majiang_core::game::{Event, GameConfig, GameState, Match, MatchConfig, Phase, RoundResult, WinContext};
+use majiang_core::hand::{shanten, Hand};
+use majiang_core::tile::{Tile, Suit, Wind};
+
+use crate::helpers::next_actor;
+
+/// 能和就和，能杠就杠，能立直就立直，否则摸切
+fn aggressive(actions: &[Action]) -> Action {
+    let find = |pred: fn(&Action) -> bool| actions.iter().copied().find(pred);
+    find(|a| a.is_win())
+        .or_else(|| find(|a| matches!(a, Action::Kan { .. })))
+        .or_else(|| find(|a| matches!(a, Action::Riichi { .. })))
+        .or_else(|| find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. })))
+        .unwrap_or(actions[actions.len() - 1])
+}
+
+fn play(config: GameConfig) -> (GameState, Vec<Event>) {
+    let mut game = GameState::new(config).unwrap();
+    let mut events = Vec::new();
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        events.extend(game.apply(seat, aggressive(&actions)).unwrap());
+    }
+    (game, events)
+}
+
+/// 测试手动创建的场况
+#[test]
+fn test_manual_context() {
+    let hand: Hand = "123m456p789s23m11z".parse().unwrap();
+    let context = WinContext::new(hand.clone(), Tile::Suit(Suit::Character, 1), Some(2));
+    assert!(!context.is_tsumo());
+    assert!(context.is_dealer());
+    assert_eq!(context.hand, hand);
+    assert!(context.dora_indicators.is_empty());
+    assert!(!context.riichi && !context.haitei && !context.tenhou);
+}
+
+/// 测试比赛为每一局设置庄家、场风和本场数
+#[test]
+fn test_round_config_from_match() {
+    let mut progress = Match::new(MatchConfig::default()).unwrap();
+    let result = RoundResult { winners: vec![], tenpai: vec![], abortive: false, riichi: vec![], point_changes: vec![0; 4] };
+    progress.record_round(&result).unwrap();
+
+    let config = progress.round_config(GameConfig::riichi(5));
+    assert_eq!((config.dealer, config.round_wind, config.honba), (1, Wind::East, 1));
+
+    let game = GameState::new(config).unwrap();
+    assert_eq!(game.seat_wind(1), Wind::East);
+    assert_eq!(game.seat_wind(0), Wind::North);
+}
+
+/// 测试自动构建的场况与对局记录一致
+#[test]
+fn test_contexts_match_events() {
+    let mut wins = 0;
+    for seed in 0..16 {
+        let mut config = GameConfig::riichi(seed);
+        config.dealer = (seed % 4) as u8;
+        config.honba = 2;
+        let (game, events) = play(config.clone());
+        if game.win_contexts().is_empty() {
+            continue;
+        }
+        wins += 1;
+
+        let won_at = events.iter().position(|e| matches!(e, Event::Won { .. })).unwrap();
+        for context in game.win_contexts() {
+            // 和牌前的手牌加和牌张构成和牌
+            let mut complete = context.hand.clone();
+            complete.draw(context.win_tile).unwrap();
+            assert_eq!(shanten(&complete), -1);
+
+            assert_eq!(context.seat_wind, game.seat_wind(context.seat));
+            assert_eq!(context.is_dealer(), context.seat == config.dealer);
+            assert_eq!(context.honba, 2);
+            assert_eq!(context.dora_indicators.len(), context.ura_dora_indicators.len());
+            assert!(!context.dora_indicators.is_empty());
+
+            let player = game.player(context.seat).unwrap();
+            assert_eq!(context.riichi, player.is_riichi());
+            assert!(!context.ippatsu || context.riichi);
+            assert!(!context.double_riichi || context.riichi);
+
+            // 岭上开花当且仅当和牌前最后一次摸牌是岭上牌
+            let replacement = matches!(events[won_at - 1], Event::DrewReplacement { .. });
+            assert_eq!(context.rinshan, replacement && context.is_tsumo());
+            assert_eq!(context.haitei, context.is_tsumo() && game.wall().is_empty() && !replacement);
+            assert!(!(context.tenhou && context.chiihou));
+            assert!(game.point_changes()[context.seat as usize] > 0);
+        }
+        assert_eq!(game.point_changes().iter().sum::<i32>(), 0);
+    }
+    assert!(wins > 0);
+}
@@ -0,0 +1,120 @@
+// tests/game/test_exhaustive_draw.rs
+//
+// 荒牌流局结算的测试用例
+// 覆盖听牌者亮牌、空听不算听牌、不听罚符、流局满贯、庄家连庄，以及国标麻将的无结算流局
+
+use std::sync::Arc;
+
+use majiang_core::action::Action;
+use majiang_core::game::{Event, GameConfig, GameState, Match, MatchConfig, Phase, RoundOutcome, RoundResult};
+use majiang_core::hand::{shanten, winning_tiles, Hand};
+use majiang_core::player::DiscardRecord;
+use majiang_core::rules::riichi::{is_nagashi_mangan, nagashi_payments, noten_payments, ScoreOptions};
+use majiang_core::rules::{McrRules, RiichiRules, RuleSet};
+use majiang_core::tile::{Suit, Tile, Wind};
+
+use crate::helpers::next_actor;
+
+/// 只摸切(必须补花时补花)地按规则打完一局，返回所有事件
+fn play_out(rules: Arc<dyn RuleSet>, seed: u64) -> (GameState, Vec<Event>) {
+    let mut game = GameState::with_rules(GameConfig::from_rules(rules.as_ref(), seed), rules).unwrap();
+    let mut events = Vec::new();
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        let action = actions
+            .iter()
+            .copied()
+            .find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. }))
+            .unwrap_or(actions[actions.len() - 1]);
+        events.extend(game.apply(seat, action).unwrap());
+    }
+    (game, events)
+}
+
+fn record(tile: Tile, claimed: bool) -> DiscardRecord {
+    DiscardRecord { tile, tsumogiri: true, riichi: false, claimed }
+}
+
+/// 测试不听罚符：不听者平分支付3000点，听牌者平分收取
+#[test]
+fn test_noten_payments() {
+    assert_eq!(noten_payments(&[1], 4), [-1000, 3000, -1000, -1000]);
+    assert_eq!(noten_payments(&[0, 2], 4), [1500, -1500, 1500, -1500]);
+    assert_eq!(noten_payments(&[0, 1, 3], 4), [1000, 1000, -3000, 1000]);
+    assert_eq!(noten_payments(&[0, 1, 2, 3], 4), [0; 4]);
+    assert_eq!(noten_payments(&[2], 3), [-1500, -1500, 3000]);
+}
+
+/// 测试流局满贯：河中全是幺九牌且未被鸣走，按满贯自摸收取
+#[test]
+fn test_nagashi_mangan() {
+    let terminal = Tile::Suit(Suit::Dot, 9);
+    let honor = Tile::Wind(Wind::West);
+    assert!(is_nagashi_mangan(&[record(terminal, false), record(honor, false)]));
+    assert!(!is_nagashi_mangan(&[record(terminal, false), record(honor, true)]));
+    assert!(!is_nagashi_mangan(&[record(terminal, false), record(Tile::Suit(Suit::Dot, 5), false)]));
+    assert!(!is_nagashi_mangan(&[]));
+
+    // 两家同时流局满贯时分别收取，也分别向对方支付
+    assert_eq!(nagashi_payments(&[1, 3], 0, 4), [-8000, 6000, -4000, 6000]);
+    assert!(ScoreOptions::default().nagashi_mangan);
+}
+
+/// 测试立直麻将的荒牌流局：听牌者亮牌、按不听罚符结算，庄家听牌时连庄
+#[test]
+fn test_riichi_exhaustive_draw() {
+    // 空听：向听数为0但四张东都在手中，没有和牌张，不算听牌
+    let karaten: Hand = "123m456p789s1111z".parse().unwrap();
+    assert_eq!(shanten(&karaten), 0);
+    assert!(winning_tiles(&karaten).is_empty());
+
+    let mut checked = [false; 2];
+    for seed in 0..8 {
+        let (game, events) = play_out(Arc::new(RiichiRules::default()), seed);
+        let Some(RoundOutcome::ExhaustiveDraw { tenpai }) = game.outcome() else { continue };
+
+        let revealed: Vec<u8> = events
+            .iter()
+            .filter_map(|e| match e {
+                Event::TenpaiRevealed { seat, tiles } => {
+                    assert_eq!(*tiles, game.player(*seat).unwrap().hand().concealed_tiles());
+                    Some(*seat)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(&revealed, tenpai);
+        // 听牌者正是有和牌张的玩家
+        let waiting: Vec<u8> =
+            (0..4).filter(|&seat| !winning_tiles(game.player(seat).unwrap().hand()).is_empty()).collect();
+        assert_eq!(tenpai, &waiting);
+        assert_eq!(game.point_changes().iter().sum::<i32>(), 0);
+        if !game.players().iter().any(|p| is_nagashi_mangan(p.discards())) {
+            assert_eq!(game.point_changes(), noten_payments(tenpai, 4));
+        }
+
+        let dealer_tenpai = tenpai.contains(&game.dealer());
+        let mut record = Match::new(MatchConfig::default()).unwrap();
+        record.record_round(&RoundResult::from_game(&game, game.point_changes().to_vec()).unwrap()).unwrap();
+        assert_eq!(record.dealer() == 0, dealer_tenpai);
+        assert_eq!(record.honba(), 1);
+        checked[dealer_tenpai as usize] = true;
+    }
+    assert!(checked.iter().any(|&c| c));
+
+    // 关闭流局满贯后只看听牌
+    let rules = RiichiRules { score: ScoreOptions { nagashi_mangan: false, ..ScoreOptions::default() }, ..RiichiRules::default() };
+    let (game, _) = play_out(Arc::new(rules), 3);
+    if let Some(RoundOutcome::ExhaustiveDraw { tenpai }) = game.outcome() {
+        assert_eq!(game.point_changes(), noten_payments(tenpai, 4));
+    }
+}
+
+/// 测试国标麻将的荒庄：没有点数变化
+#[test]
+fn test_mcr_exhaustive_draw() {
+    let (game, events) = play_out(Arc::new(McrRules), 2);
+    assert!(matches!(game.outcome(), Some(RoundOutcome::ExhaustiveDraw { .. })));
+    assert!(matches!(events.last(), Some(Event::RoundEnded { .. })));
+    assert_eq!(game.point_changes(), [0; 4]);
+}
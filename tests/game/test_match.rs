@@ -0,0 +1,133 @@
+// tests/game/test_match.rs
+//
+// 比赛进行(Match)的测试用例
+// 覆盖庄家轮换与连庄、本场数、立直供托、击飞、西入、和了止め以及终局顺位调整
+
+use majiang_core::errors::MajiangError;
+use majiang_core::game::{Match, MatchConfig, MatchLength, RoundResult};
+use majiang_core::tile::Wind;
+
+fn win(winners: Vec<u8>, point_changes: Vec<i32>) -> RoundResult {
+    RoundResult { winners, tenpai: vec![], abortive: false, riichi: vec![], point_changes }
+}
+
+fn draw(tenpai: Vec<u8>) -> RoundResult {
+    RoundResult { winners: vec![], tenpai, abortive: false, riichi: vec![], point_changes: vec![0; 4] }
+}
+
+/// 测试庄家轮换、连庄与本场数
+#[test]
+fn test_dealer_rotation_and_honba() {
+    let mut game = Match::new(MatchConfig::default()).unwrap();
+
+    // 庄家和牌连庄，本场数加一
+    game.record_round(&win(vec![0], vec![12000, -12000, 0, 0])).unwrap();
+    assert_eq!((game.dealer(), game.honba()), (0, 1));
+
+    // 庄家听牌流局连庄，不听下庄，本场都累积
+    game.record_round(&draw(vec![0, 2])).unwrap();
+    assert_eq!((game.dealer(), game.honba()), (0, 2));
+    game.record_round(&draw(vec![2])).unwrap();
+    assert_eq!((game.dealer(), game.honba()), (1, 3));
+    assert_eq!(game.seat_wind(1), Wind::East);
+    assert_eq!(game.seat_wind(0), Wind::North);
+
+    // 途中流局连庄
+    let abortive = RoundResult { abortive: true, ..draw(vec![]) };
+    game.record_round(&abortive).unwrap();
+    assert_eq!((game.dealer(), game.honba()), (1, 4));
+
+    // 子家和牌，本场清零
+    game.record_round(&win(vec![3], vec![0, -2000, 0, 2000])).unwrap();
+    assert_eq!((game.dealer(), game.honba(), game.round_number()), (2, 0, 3));
+}
+
+/// 测试立直供托的累积与归属
+#[test]
+fn test_riichi_deposits() {
+    let mut game = Match::new(MatchConfig::default()).unwrap();
+
+    let mut result = draw(vec![1, 2]);
+    result.riichi = vec![1, 2];
+    game.record_round(&result).unwrap();
+    assert_eq!(game.riichi_sticks(), 2);
+    assert_eq!(game.points(), &[25000, 24000, 24000, 25000]);
+
+    // 多家荣和时供托归离放铳者最近的和牌者
+    let mut result = win(vec![3, 1], vec![0, 1000, -2000, 1000]);
+    result.riichi = vec![3];
+    game.record_round(&result).unwrap();
+    assert_eq!(game.riichi_sticks(), 0);
+    assert_eq!(game.points(), &[25000, 25000, 22000, 28000]);
+}
+
+/// 测试东风战正常结束与终局顺位
+#[test]
+fn test_tonpuusen_end_and_standings() {
+    let config = MatchConfig { length: MatchLength::Tonpuusen, ..MatchConfig::default() };
+    let mut game = Match::new(config).unwrap();
+
+    game.record_round(&win(vec![1], vec![-15000, 15000, 0, 0])).unwrap();
+    game.record_round(&win(vec![2], vec![0, 0, 5000, -5000])).unwrap();
+    game.record_round(&win(vec![3], vec![0, -10000, 0, 10000])).unwrap();
+    assert!(!game.is_finished());
+    assert_eq!(game.round_number(), 4);
+    game.record_round(&win(vec![3], vec![-5000, -5000, -5000, 15000])).unwrap();
+    // 东四局庄家连庄，比赛继续
+    assert!(!game.is_finished());
+    game.record_round(&win(vec![2], vec![0, 0, 5000, -5000])).unwrap();
+    assert!(game.is_finished());
+    assert!(matches!(game.record_round(&draw(vec![])), Err(MajiangError::InvalidState(_))));
+
+    // 点数: [5000, 25000, 30000, 40000]
+    let standings = game.standings();
+    assert_eq!(standings.iter().map(|s| s.seat).collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+    assert_eq!(standings.iter().map(|s| s.score).collect::<Vec<_>>(), vec![50000, 10000, -15000, -45000]);
+    assert_eq!(standings.iter().map(|s| s.score).sum::<i32>(), 0);
+}
+
+/// 测试西入：无人达到返点时进入下一场，之后有人达到即结束
+#[test]
+fn test_west_extension() {
+    let config = MatchConfig { length: MatchLength::Tonpuusen, ..MatchConfig::default() };
+    let mut game = Match::new(config.clone()).unwrap();
+    for dealer in 0..4u8 {
+        let mut changes = vec![-1000; 4];
+        changes[((dealer + 1) % 4) as usize] = 3000;
+        game.record_round(&win(vec![(dealer + 1) % 4], changes)).unwrap();
+    }
+    assert!(!game.is_finished());
+    assert_eq!((game.round_wind(), game.round_number()), (Wind::South, 1));
+
+    game.record_round(&win(vec![1], vec![0, 5000, -5000, 0])).unwrap();
+    assert!(game.is_finished());
+
+    // 关闭西入时按时结束
+    let mut game = Match::new(MatchConfig { west_extension: false, ..config }).unwrap();
+    for _ in 0..4 {
+        game.record_round(&draw(vec![])).unwrap();
+    }
+    assert!(game.is_finished());
+}
+
+/// 测试击飞与和了止め
+#[test]
+fn test_tobi_and_agari_yame() {
+    let mut game = Match::new(MatchConfig::default()).unwrap();
+    game.record_round(&win(vec![0], vec![26000, -26000, 0, 0])).unwrap();
+    assert!(game.is_finished());
+
+    let config = MatchConfig { length: MatchLength::Tonpuusen, agari_yame: true, ..MatchConfig::default() };
+    let mut game = Match::new(config).unwrap();
+    for _ in 0..3 {
+        game.record_round(&draw(vec![])).unwrap();
+    }
+    assert_eq!(game.dealer(), 3);
+    game.record_round(&win(vec![3], vec![-4000, -4000, -4000, 12000])).unwrap();
+    assert!(game.is_finished());
+    assert_eq!(game.standings()[0].seat, 3);
+
+    // 配置检查
+    let config = MatchConfig { uma: vec![10000, -10000], ..MatchConfig::default() };
+    assert!(matches!(Match::new(config), Err(MajiangError::ConfigurationError(_))));
+}
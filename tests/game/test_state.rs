@@ -0,0 +1,133 @@
+// tests/game/test_state.rs
+//
+// 一局游戏状态机的测试用例
+// 覆盖配牌、摸打轮转、鸣牌、非法动作的拒绝，以及相同种子和动作序列的可复现性
+
+use majiang_core::action::Action;
+use majiang_core::errors::MajiangError;
+use majiang_core::game::{Event, GameConfig, GameState, Phase, RoundOutcome, RoundResult};
+
+use crate::helpers::next_actor;
+
+/// 按给定策略打完一局，返回所有事件
+fn play<F>(seed: u64, mut choose: F) -> (GameState, Vec<Event>)
+where
+    F: FnMut(&[Action]) -> Action,
+{
+    let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+    let mut events = Vec::new();
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        events.extend(game.apply(seat, choose(&actions)).unwrap());
+    }
+    (game, events)
+}
+
+/// 只摸切、从不鸣牌或和牌的策略
+fn tsumogiri_only(actions: &[Action]) -> Action {
+    actions
+        .iter()
+        .copied()
+        .find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. }))
+        .unwrap_or(actions[actions.len() - 1])
+}
+
+/// 能和就和、能碰就碰的策略
+fn greedy(actions: &[Action]) -> Action {
+    let find = |pred: fn(&Action) -> bool| actions.iter().copied().find(pred);
+    find(|a| a.is_win())
+        .or_else(|| find(|a| matches!(a, Action::Pon { .. })))
+        .unwrap_or_else(|| tsumogiri_only(actions))
+}
+
+/// 测试配牌
+#[test]
+fn test_dealing() {
+    let mut game = GameState::new(GameConfig::riichi(7)).unwrap();
+    assert_eq!(game.phase(), Phase::Dealing);
+    assert_eq!(game.legal_actions(0), vec![Action::Draw]);
+    assert!(game.legal_actions(1).is_empty());
+
+    let events = game.apply(0, Action::Draw).unwrap();
+    let dealt = events.iter().filter(|e| matches!(e, Event::Dealt { .. })).count();
+    assert_eq!(dealt, 4);
+    assert!(events.iter().any(|e| matches!(e, Event::DoraRevealed { .. })));
+    assert!(game.players().iter().all(|p| p.hand().concealed_count() == 13));
+    assert_eq!(game.wall().remaining_tiles(), 136 - 14 - 52);
+
+    // 庄家摸第一张牌后进入打牌阶段
+    assert_eq!(game.phase(), Phase::WaitingDiscard);
+    let events = game.apply(0, Action::Draw).unwrap();
+    assert!(matches!(events[0], Event::Drew { seat: 0, .. }));
+    assert_eq!(game.player(0).unwrap().hand().concealed_count(), 14);
+}
+
+/// 测试摸打轮转和非法动作
+#[test]
+fn test_turn_rotation() {
+    let mut game = GameState::new(GameConfig::riichi(11)).unwrap();
+    game.apply(0, Action::Draw).unwrap();
+
+    // 不是自己的回合
+    assert!(matches!(game.apply(1, Action::Draw), Err(MajiangError::InvalidAction(_))));
+    game.apply(0, Action::Draw).unwrap();
+
+    let discard = tsumogiri_only(&game.legal_actions(0));
+    let events = game.apply(0, discard).unwrap();
+    assert!(matches!(events[0], Event::Discarded { seat: 0, tsumogiri: true, .. }));
+    assert_eq!(game.player(0).unwrap().discards().len(), 1);
+
+    // 无人需要应对时直接轮到下家；有人可以鸣牌时等待应对
+    match game.phase() {
+        Phase::WaitingDiscard => assert_eq!(game.current_seat(), 1),
+        Phase::WaitingCalls => assert!(game.pending_tile().is_some()),
+        other => panic!("意外的阶段{:?}", other),
+    }
+}
+
+/// 测试只摸切时打到荒牌流局
+#[test]
+fn test_exhaustive_draw() {
+    let (game, events) = play(3, tsumogiri_only);
+    assert!(game.wall().is_empty());
+    assert!(matches!(game.outcome(), Some(RoundOutcome::ExhaustiveDraw { .. })));
+    assert!(matches!(events.last(), Some(Event::RoundEnded { .. })));
+
+    // 结束后可以生成交给比赛的结算结果
+    let result = RoundResult::from_game(&game, vec![0; 4]).unwrap();
+    assert!(result.winners.is_empty() && !result.abortive);
+
+    // 结束后不能再行动
+    let mut game = game;
+    assert!(matches!(game.apply(0, Action::Draw), Err(MajiangError::InvalidState(_))));
+}
+
+/// 测试鸣牌：碰后由鸣牌者打牌，被鸣走的牌在河中标记
+#[test]
+fn test_calls_change_turn() {
+    let (game, events) = (0..20)
+        .map(|seed| play(seed, greedy))
+        .find(|(_, events)| events.iter().any(|e| matches!(e, Event::Called { from: Some(_), .. })))
+        .expect("20局中应至少出现一次碰");
+
+    let index = events.iter().position(|e| matches!(e, Event::Called { from: Some(_), .. })).unwrap();
+    let Event::Called { seat, from: Some(from), .. } = events[index].clone() else { unreachable!() };
+    assert!(matches!(events[index + 1], Event::Discarded { seat: s, tsumogiri: false, .. } if s == seat));
+    assert!(game.player(from).unwrap().discards().iter().any(|d| d.claimed));
+}
+
+/// 测试相同种子和动作序列产生完全相同的事件
+#[test]
+fn test_deterministic_replay() {
+    for seed in [1, 42, 2024] {
+        let (first, a) = play(seed, greedy);
+        let (second, b) = play(seed, greedy);
+        assert_eq!(format!("{:?}", a), format!("{:?}", b));
+        assert_eq!(first.outcome(), second.outcome());
+    }
+
+    // 不同种子的配牌不同
+    let (_, a) = play(1, tsumogiri_only);
+    let (_, b) = play(2, tsumogiri_only);
+    assert_ne!(a[0], b[0]);
+}
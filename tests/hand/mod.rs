@@ -0,0 +1,10 @@
+// tests/hand/mod.rs
+//
+// 手牌模块的测试入口文件
+
+mod test_parser;
+mod test_representation;
+mod test_analysis;
+mod test_waits;
+mod test_efficiency;
+mod test_joker;
@@ -0,0 +1,78 @@
+// tests/hand/test_analysis.rs
+//
+// 手牌分析的测试用例
+// 验证三种和牌形的向听数，以及副露对计算的影响
+
+use majiang_core::hand::{
+    Hand, ShantenBreakdown, SHANTEN_COMPLETE,
+    shanten, shanten_breakdown, standard_shanten,
+};
+use majiang_core::tile::{Tile, Wind};
+
+fn hand(notation: &str) -> Hand {
+    notation.parse().unwrap()
+}
+
+/// 测试一般形的向听数
+#[test]
+fn test_standard_shanten() {
+    // 和牌、听牌
+    assert_eq!(shanten(&hand("123m456p789s11122z")), SHANTEN_COMPLETE);
+    assert_eq!(shanten(&hand("123m456p789s1122z")), 0);
+    assert_eq!(shanten(&hand("123m456p789s23m11z")), 0);
+
+    // 一向听、五向听
+    assert_eq!(shanten(&hand("123m456p78s24p1155z")), 1);
+    assert_eq!(shanten(&hand("13m46p79s123456z3m")), 5);
+
+    // 面子过多时搭子不再计入
+    assert_eq!(shanten(&hand("123456789m1357p")), 1);
+
+    // 摸牌同样参与计算
+    let mut h = hand("123m456p789s1122z");
+    h.draw(Tile::Wind(Wind::East)).unwrap();
+    assert_eq!(shanten(&h), SHANTEN_COMPLETE);
+}
+
+/// 测试七对子和国士无双
+#[test]
+fn test_special_forms() {
+    let breakdown = shanten_breakdown(&hand("1122m3344p5566s7z"));
+    assert_eq!(breakdown.chiitoitsu, Some(0));
+    assert_eq!(breakdown.min(), 0);
+
+    // 四张同种牌只算一个对子
+    assert_eq!(shanten_breakdown(&hand("1111m3344p5566s7z")).chiitoitsu, Some(2));
+
+    let breakdown = shanten_breakdown(&hand("19m19p19s1234567z"));
+    assert_eq!(breakdown.kokushi, Some(0));
+    assert_eq!(shanten(&hand("119m19p19s1234567z")), SHANTEN_COMPLETE);
+
+    // 各和牌形分别给出，最小值取三者之一
+    let breakdown = shanten_breakdown(&hand("147m258p369s1234z"));
+    assert_eq!(breakdown, ShantenBreakdown {
+        standard: 8,
+        chiitoitsu: Some(6),
+        kokushi: Some(7),
+    });
+    assert_eq!(breakdown.min(), 6);
+}
+
+/// 测试副露计为完成的面子
+#[test]
+fn test_shanten_with_melds() {
+    // 三组副露加单骑听牌
+    let breakdown = shanten_breakdown(&hand("1234m[555p][666s](7777z)"));
+    assert_eq!(breakdown.standard, 0);
+    assert_eq!(breakdown.chiitoitsu, None);
+    assert_eq!(breakdown.kokushi, None);
+
+    assert_eq!(shanten(&hand("11m[123p][456p][789p][111z]")), SHANTEN_COMPLETE);
+    assert_eq!(shanten(&hand("1m[123p][456p][789p][111z]")), 0);
+    assert_eq!(shanten(&hand("13m58p9s[123p][456p]")), 3);
+
+    // 直接使用计数数组计算
+    let counts = hand("1234m").tile_counts();
+    assert_eq!(standard_shanten(&counts, 3), 0);
+    assert_eq!(standard_shanten(&counts, 0), 6);
+}
@@ -0,0 +1,118 @@
+// tests/hand/test_efficiency.rs
+//
+// 牌效率(进张)分析的测试用例
+// 验证打牌排序、进张种类以及扣除可见牌后的未见张数
+
+use majiang_core::errors::MajiangError;
+use majiang_core::hand::{Hand, VisibleTiles, ukeire, discard_efficiency, shanten};
+use majiang_core::meld::{Meld, MeldType, MeldSource};
+use majiang_core::tile::{Tile, Suit, Wind};
+
+fn hand(notation: &str) -> Hand {
+    notation.parse().unwrap()
+}
+
+fn m(n: u8) -> Tile {
+    Tile::Suit(Suit::Character, n)
+}
+
+/// 测试13张手牌的进张
+#[test]
+fn test_ukeire() {
+    let h = hand("123m456p789s23m11z");
+    let result = ukeire(&h, &VisibleTiles::new()).unwrap();
+    assert_eq!(result.shanten, 0);
+    assert_eq!(result.tiles, vec![m(1), m(4)]);
+    assert_eq!(result.unseen, 7); // 1万已在手中一张
+
+    // 张数不对时返回错误
+    assert!(matches!(
+        ukeire(&hand("123m456p789s23m11z1s"), &VisibleTiles::new()),
+        Err(MajiangError::InvalidOperation(_))
+    ));
+}
+
+/// 测试可见牌从未见张数中扣除
+#[test]
+fn test_visible_tiles() {
+    let h = hand("123m456p789s23m11z");
+    let mut visible = VisibleTiles::new();
+
+    // 河中的4万、他家碰的1万、宝牌指示牌中的红五(计为5万，不影响结果)
+    visible.add_tiles(&[m(4), m(4), Tile::new_red_five(Suit::Character)]);
+    let pon = Meld::new(vec![m(1); 3], MeldType::Pon, vec![
+        MeldSource::Player(1), MeldSource::SelfDrawn, MeldSource::SelfDrawn,
+    ]).unwrap();
+    visible.add_melds(&[pon]);
+
+    assert_eq!(visible.count(m(4)), 2);
+    assert_eq!(visible.count(m(5)), 1);
+    assert_eq!(visible.count(Tile::Joker), 0);
+
+    let result = ukeire(&h, &visible).unwrap();
+    assert_eq!(result.tiles, vec![m(1), m(4)]);
+    assert_eq!(result.unseen, 2);
+}
+
+/// 测试打牌排序
+#[test]
+fn test_discard_ranking() {
+    // 打出孤张的东风后听牌，其他打法都会退向听
+    let h = hand("123m456p789s23m11z3z");
+    assert!(matches!(
+        discard_efficiency(&hand("123m"), &VisibleTiles::new()),
+        Err(MajiangError::InvalidOperation(_))
+    ));
+    let options = discard_efficiency(&h, &VisibleTiles::new()).unwrap();
+
+    let best = &options[0];
+    assert_eq!(best.discard, Tile::Wind(Wind::West));
+    assert_eq!(best.ukeire.shanten, 0);
+    assert_eq!(best.ukeire.tiles, vec![m(1), m(4)]);
+
+    // 每种牌只出现一次，且排序单调
+    assert_eq!(options.len(), 11);
+    for pair in options.windows(2) {
+        let (a, b) = (&pair[0].ukeire, &pair[1].ukeire);
+        assert!(a.shanten < b.shanten || (a.shanten == b.shanten && a.unseen >= b.unseen));
+    }
+
+    // 打出摸到的牌同样在候选中
+    let mut h = hand("123m456p789s23m11z");
+    h.draw(Tile::Suit(Suit::Bamboo, 1)).unwrap();
+    let options = discard_efficiency(&h, &VisibleTiles::new()).unwrap();
+    assert_eq!(options[0].discard, Tile::Suit(Suit::Bamboo, 1));
+}
+
+/// 测试红五与普通五分别列出，副露手牌同样适用
+#[test]
+fn test_discard_red_five_and_melds() {
+    let h = hand("05m456p789s[123s][111z]");
+    let options = discard_efficiency(&h, &VisibleTiles::new()).unwrap();
+    let fives: Vec<Tile> = options.iter().map(|o| o.discard).filter(|t| t.is_same_kind(&m(5))).collect();
+    assert_eq!(fives.len(), 2);
+
+    // 打出任意一张五万都是单骑听另一张五万
+    let red = options.iter().find(|o| o.discard.is_red()).unwrap();
+    assert_eq!(red.ukeire.shanten, 0);
+    assert_eq!(red.ukeire.tiles, vec![m(5)]);
+    assert_eq!(red.ukeire.unseen, 2);
+}
+
+/// 测试百搭作为万能牌计算牌效率，与`shanten`的结果一致
+#[test]
+fn test_discard_efficiency_with_joker() {
+    let h = hand("123m456p789s11z23m0z");
+    assert_eq!(shanten(&h), -1);
+
+    // 百搭可以当作任意牌，打出任何一张都仍然听牌
+    let options = discard_efficiency(&h, &VisibleTiles::new()).unwrap();
+    assert!(options.iter().all(|o| o.ukeire.shanten == 0));
+    let joker = options.iter().find(|o| o.discard.is_joker()).unwrap();
+    assert_eq!(joker.ukeire.tiles, vec![m(1), m(4)]);
+
+    // 13张手牌中的百搭同样计入向听数，但百搭本身不是进张
+    let result = ukeire(&hand("123m456p789s23m1z0z"), &VisibleTiles::new()).unwrap();
+    assert_eq!(result.shanten, 0);
+    assert!(!result.tiles.contains(&Tile::Joker));
+}
@@ -0,0 +1,113 @@
+// tests/hand/test_joker.rs
+//
+// 百搭牌手牌分析的测试用例
+// 验证百搭作为万能牌参与向听数、和牌张与拆分计算，以及地方规则限制
+
+use majiang_core::hand::{
+    Hand, JokerRules, WaitType, WinForm,
+    shanten, shanten_breakdown, winning_tiles, joker_substitutions, best_substitution,
+};
+use majiang_core::tile::{Tile, Suit, Wind, Dragon};
+
+fn hand(notation: &str) -> Hand {
+    notation.parse().unwrap()
+}
+
+fn m(n: u8) -> Tile {
+    Tile::Suit(Suit::Character, n)
+}
+
+const EAST: Tile = Tile::Wind(Wind::East);
+
+/// 测试百搭参与向听数与和牌张计算
+#[test]
+fn test_joker_shanten_and_waits() {
+    let h = hand("13m1z0z[123p][456p][789s]");
+    assert_eq!(h.joker_count(), 1);
+    assert_eq!(shanten(&h), 0);
+    assert_eq!(shanten(&hand("13m1z[123p][456p][789s]5s")), 1);
+    assert_eq!(winning_tiles(&h), vec![m(2), EAST]);
+
+    // 百搭补成七对子
+    let breakdown = shanten_breakdown(&hand("1122m3344p556s7z0z"));
+    assert_eq!(breakdown.chiitoitsu, Some(0));
+
+    // 手中有百搭即和牌
+    let mut h = hand("123m456p789s123s1z");
+    h.draw(Tile::Joker).unwrap();
+    assert_eq!(shanten(&h), -1);
+}
+
+/// 测试替代方案的枚举
+#[test]
+fn test_joker_substitutions() {
+    let h = hand("13m1z0z[123p][456p][789s]");
+    let result = joker_substitutions(&h, m(2), &JokerRules::default());
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].tiles, vec![EAST]);
+    assert_eq!(result[0].decomposition.pair, EAST);
+    assert_eq!(result[0].decomposition.wait, WaitType::Kanchan);
+
+    // 和牌张本身是百搭
+    let h = hand("13m11z[123p][456p][789s]");
+    let result = joker_substitutions(&h, Tile::Joker, &JokerRules::default());
+    assert!(result.iter().any(|s| s.tiles == vec![m(2)] && s.decomposition.wait == WaitType::Kanchan));
+
+    // 不含百搭时与普通拆分一致
+    let h = hand("123m456p789s23m11z");
+    let result = joker_substitutions(&h, m(1), &JokerRules::default());
+    assert_eq!(result.len(), 1);
+    assert!(result[0].tiles.is_empty());
+    assert_eq!(result[0].decomposition.form, WinForm::Standard);
+}
+
+/// 测试地方规则限制
+#[test]
+fn test_joker_rules() {
+    // 此手牌只能由百搭作雀头，禁止后即不能和
+    let h = hand("13m1z0z[123p][456p][789s]");
+    let no_pair = JokerRules { in_pair: false, ..JokerRules::default() };
+    assert!(joker_substitutions(&h, m(2), &no_pair).is_empty());
+
+    // 此手牌只能由百搭入顺子，禁止后即不能和
+    let h = hand("1m0z11z[123p][456p][789s]");
+    assert!(!joker_substitutions(&h, m(2), &JokerRules::default()).is_empty());
+    let no_sequence = JokerRules { in_sequence: false, ..JokerRules::default() };
+    assert!(joker_substitutions(&h, m(2), &no_sequence).is_empty());
+
+    // 刻子中的百搭不受限制
+    let h = hand("11m0z1z[123p][456p][789s]");
+    let strict = JokerRules { in_pair: false, in_sequence: false };
+    let result = joker_substitutions(&h, EAST, &strict);
+    assert_eq!(result.len(), 2);
+    assert!(result.iter().all(|s| s.decomposition.pair != s.tiles[0]));
+
+    // 七对子的对子同样受雀头限制
+    let h = hand("1122m3344p556s7z0z");
+    let seven = Tile::Dragon(Dragon::Red);
+    assert!(joker_substitutions(&h, seven, &no_pair).is_empty());
+    assert!(!joker_substitutions(&h, seven, &JokerRules::default()).is_empty());
+}
+
+/// 测试按得分选出最佳替代方案
+#[test]
+fn test_best_substitution() {
+    // 百搭可以代替1万(111万刻子 + 东风雀头)，也可以代替东风(11万雀头 + 东风刻子)
+    let h = hand("11m1z0z[123p][456p][789s]");
+    let all = joker_substitutions(&h, EAST, &JokerRules::default());
+    assert_eq!(all.len(), 2);
+
+    let prefer_honor = best_substitution(&h, EAST, &JokerRules::default(), |s| {
+        s.tiles.iter().filter(|t| t.is_honor()).count() as i64
+    }).unwrap();
+    assert_eq!(prefer_honor.tiles, vec![EAST]);
+    assert_eq!(prefer_honor.decomposition.pair, m(1));
+
+    let prefer_suit = best_substitution(&h, EAST, &JokerRules::default(), |s| {
+        s.tiles.iter().filter(|t| t.is_suit()).count() as i64
+    }).unwrap();
+    assert_eq!(prefer_suit.tiles, vec![m(1)]);
+
+    // 不能和牌时没有结果
+    assert!(best_substitution(&h, m(9), &JokerRules::default(), |_| 0).is_none());
+}
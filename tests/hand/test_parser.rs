@@ -0,0 +1,149 @@
+// tests/hand/test_parser.rs
+//
+// MPSZ手牌记法解析与格式化的测试用例
+// 覆盖普通牌、红五、字牌、花牌和百搭的扩展记法，副露括号，以及错误列号
+
+use majiang_core::errors::MajiangError;
+use majiang_core::hand::{parse_tiles, parse_hand, format_tiles, format_meld, format_hand};
+use majiang_core::meld::{MeldType, KanType, MeldSource};
+use majiang_core::tile::{Tile, Suit, Wind, Dragon, Flower};
+
+/// 从错误信息中取出列号，便于断言
+fn error_column(err: MajiangError) -> usize {
+    let MajiangError::InvalidTile(msg) = err else {
+        panic!("解析错误应为InvalidTile: {:?}", err);
+    };
+    let start = msg.find('第').expect("错误信息应包含列号") + '第'.len_utf8();
+    let end = msg.find('列').expect("错误信息应包含列号");
+    msg[start..end].parse().expect("列号应为数字")
+}
+
+/// 测试基本的数牌与字牌解析
+#[test]
+fn test_parse_basic_tiles() {
+    let tiles = parse_tiles("123m456p789s1234567z").unwrap();
+    assert_eq!(tiles.len(), 16);
+    assert_eq!(tiles[0], Tile::Suit(Suit::Character, 1));
+    assert_eq!(tiles[3], Tile::Suit(Suit::Dot, 4));
+    assert_eq!(tiles[8], Tile::Suit(Suit::Bamboo, 9));
+
+    let honors = [
+        Tile::Wind(Wind::East), Tile::Wind(Wind::South),
+        Tile::Wind(Wind::West), Tile::Wind(Wind::North),
+        Tile::Dragon(Dragon::White), Tile::Dragon(Dragon::Green),
+        Tile::Dragon(Dragon::Red),
+    ];
+    assert_eq!(&tiles[9..], &honors);
+
+    // 空白被忽略，空串得到空牌组
+    assert_eq!(parse_tiles(" 12m 3m ").unwrap().len(), 3);
+    assert!(parse_tiles("").unwrap().is_empty());
+}
+
+/// 测试红五、花牌和百搭的扩展记法
+#[test]
+fn test_parse_extensions() {
+    let tiles = parse_tiles("0m0p0s").unwrap();
+    assert_eq!(tiles, vec![
+        Tile::new_red_five(Suit::Character),
+        Tile::new_red_five(Suit::Dot),
+        Tile::new_red_five(Suit::Bamboo),
+    ]);
+
+    let tiles = parse_tiles("18f0z").unwrap();
+    assert_eq!(tiles, vec![
+        Tile::Flower(Flower::Spring),
+        Tile::Flower(Flower::Chrysanthemum),
+        Tile::Joker,
+    ]);
+}
+
+/// 测试各种副露括号
+#[test]
+fn test_parse_melds() {
+    let parsed = parse_hand("11z[555p][340m][7777z](1111s){2222m}").unwrap();
+    assert_eq!(parsed.tiles, vec![Tile::Wind(Wind::East); 2]);
+    assert_eq!(parsed.melds.len(), 5);
+
+    assert_eq!(parsed.melds[0].meld_type, MeldType::Pon);
+    assert_eq!(parsed.melds[1].meld_type, MeldType::Chi);
+    assert_eq!(parsed.melds[2].meld_type, MeldType::Kan(KanType::Open));
+    assert_eq!(parsed.melds[3].meld_type, MeldType::Kan(KanType::Closed));
+    assert_eq!(parsed.melds[4].meld_type, MeldType::Kan(KanType::Added));
+
+    // 红五保留在副露中
+    assert!(parsed.melds[1].tiles.contains(&Tile::new_red_five(Suit::Character)));
+
+    // 明副露的第一张视为被鸣的牌，暗杠全部来自自己
+    assert_eq!(parsed.melds[0].sources[0], MeldSource::Player(0));
+    assert!(parsed.melds[0].is_open());
+    assert!(parsed.melds[3].sources.iter().all(|&s| s == MeldSource::SelfDrawn));
+    assert!(!parsed.melds[3].is_open());
+}
+
+/// 测试格式化与解析的往返一致性
+#[test]
+fn test_format_round_trip() {
+    let notation = "120m456p789s10z3f";
+    let tiles = parse_tiles(notation).unwrap();
+    assert_eq!(format_tiles(&tiles), notation);
+
+    // 不同花色交错时每段各自带花色字母
+    let tiles = vec![
+        Tile::Suit(Suit::Character, 1),
+        Tile::Suit(Suit::Dot, 2),
+        Tile::Suit(Suit::Character, 3),
+    ];
+    let text = format_tiles(&tiles);
+    assert_eq!(text, "1m2p3m");
+    assert_eq!(parse_tiles(&text).unwrap(), tiles);
+
+    let notation = "23m11z[555p](1111s){7777z}";
+    let parsed = parse_hand(notation).unwrap();
+    assert_eq!(format_meld(&parsed.melds[0]), "[555p]");
+    assert_eq!(format_hand(&parsed.tiles, &parsed.melds), notation);
+    assert!(format_tiles(&[]).is_empty());
+}
+
+/// 测试解析错误及其列号
+#[test]
+fn test_parse_errors() {
+    // 字牌只有1-7，花牌只有1-8
+    assert_eq!(error_column(parse_tiles("123m8z").unwrap_err()), 5);
+    assert_eq!(error_column(parse_tiles("9f").unwrap_err()), 1);
+    assert_eq!(error_column(parse_tiles("0f").unwrap_err()), 1);
+
+    // 未知字符
+    assert_eq!(error_column(parse_tiles("12x").unwrap_err()), 3);
+
+    // 数字缺少花色字母，报告第一个悬空数字的位置
+    assert_eq!(error_column(parse_tiles("1m23").unwrap_err()), 3);
+    assert_eq!(error_column(parse_hand("12[555p]").unwrap_err()), 1);
+
+    // 花色字母前没有数字
+    assert_eq!(error_column(parse_tiles("1mp").unwrap_err()), 3);
+
+    // parse_tiles不接受副露
+    assert_eq!(error_column(parse_tiles("11z[555p]").unwrap_err()), 4);
+}
+
+/// 测试非法副露的错误
+#[test]
+fn test_parse_invalid_melds() {
+    // 不成顺子也不成刻子，错误位置为左括号
+    assert_eq!(error_column(parse_hand("11z[135m]").unwrap_err()), 4);
+
+    // 暗杠必须为四张相同的牌
+    assert_eq!(error_column(parse_hand("(1234s)").unwrap_err()), 1);
+    assert_eq!(error_column(parse_hand("(111s)").unwrap_err()), 1);
+
+    // 字牌不能组成顺子
+    assert_eq!(error_column(parse_hand("[123z]").unwrap_err()), 1);
+
+    // 缺少右括号或括号内缺少花色
+    assert_eq!(error_column(parse_hand("1z[555p").unwrap_err()), 3);
+    assert_eq!(error_column(parse_hand("[555]").unwrap_err()), 2);
+
+    // 括号种类必须配对
+    assert!(parse_hand("[555p)").is_err());
+}
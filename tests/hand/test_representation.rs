@@ -0,0 +1,168 @@
+// tests/hand/test_representation.rs
+//
+// 手牌表示(Hand)的测试用例
+// 覆盖加牌、摸打、吃碰杠，以及对不可能出现的牌数的拒绝
+
+use majiang_core::errors::MajiangError;
+use majiang_core::hand::Hand;
+use majiang_core::meld::{KanType, MeldType, MeldSource};
+use majiang_core::tile::{Tile, Suit, Wind, Dragon, Flower};
+
+fn m(n: u8) -> Tile {
+    Tile::Suit(Suit::Character, n)
+}
+
+fn p(n: u8) -> Tile {
+    Tile::Suit(Suit::Dot, n)
+}
+
+/// 测试从记法创建手牌及计数
+#[test]
+fn test_hand_from_notation() {
+    let hand: Hand = "1123m0p55p1z".parse().unwrap();
+
+    assert_eq!(hand.concealed_count(), 8);
+    assert_eq!(hand.counts()[0], 2);
+    assert_eq!(hand.count_of(p(5)), 3);
+    assert_eq!(hand.count_of(Tile::new_red_five(Suit::Dot)), 3);
+    assert_eq!(hand.red_five_count(), 1);
+    assert!(hand.drawn().is_none());
+    assert!(hand.is_closed());
+
+    // 暗手按种类排序，红五排在普通五之前
+    let tiles = hand.concealed_tiles();
+    assert_eq!(tiles[4], Tile::new_red_five(Suit::Dot));
+    assert_eq!(tiles[5], p(5));
+    assert_eq!(tiles[7], Tile::Wind(Wind::East));
+
+    // 显示为MPSZ记法并可以解析回来
+    assert_eq!(hand.to_string(), "1123m055p1z");
+    assert_eq!(hand.to_string().parse::<Hand>().unwrap(), hand);
+}
+
+/// 测试摸牌与打牌
+#[test]
+fn test_draw_and_discard() {
+    let mut hand: Hand = "123m456p789s1122z".parse().unwrap();
+
+    hand.draw(m(9)).unwrap();
+    assert_eq!(hand.drawn(), Some(m(9)));
+    assert_eq!(hand.concealed_count(), 14);
+    assert_eq!(hand.tile_counts()[8], 1);
+    assert_eq!(hand.counts()[8], 0);
+
+    // 不能连续摸两张
+    assert!(matches!(hand.draw(m(8)), Err(MajiangError::InvalidOperation(_))));
+
+    // 手切：摸牌并入暗手
+    assert!(!hand.discard(m(1)).unwrap());
+    assert!(hand.drawn().is_none());
+    assert_eq!(hand.counts()[8], 1);
+
+    // 打出不存在的牌
+    assert_eq!(hand.discard(m(1)), Err(MajiangError::TileNotFound));
+
+    // 红五与普通五区分对待
+    let mut hand: Hand = "0m".parse().unwrap();
+    assert_eq!(hand.remove_tile(m(5)), Err(MajiangError::TileNotFound));
+    hand.remove_tile(Tile::new_red_five(Suit::Character)).unwrap();
+    assert_eq!(hand.concealed_count(), 0);
+}
+
+/// 测试拒绝不可能出现的牌数
+#[test]
+fn test_reject_impossible_counts() {
+    // 同种牌不能超过4张
+    let mut hand: Hand = "1111m".parse().unwrap();
+    assert!(hand.add_tile(m(1)).is_err());
+    assert!(hand.draw(m(1)).is_err());
+    assert!("11111m".parse::<Hand>().is_err());
+
+    // 暗手加摸牌最多14张
+    let mut hand: Hand = "11122233344455m".parse().unwrap();
+    assert!(hand.draw(m(6)).is_err());
+
+    // 有副露时上限相应减少
+    let mut hand: Hand = "1112223334m[555p]".parse().unwrap();
+    assert_eq!(hand.concealed_count(), 10);
+    hand.draw(m(9)).unwrap();
+    assert!(hand.add_tile(m(8)).is_err());
+
+    // 副露中的牌也计入同种牌的数量
+    assert!("55p[555p]".parse::<Hand>().is_err());
+
+    // 花牌不进入暗手，不能重复亮出
+    let mut hand = Hand::from_tiles(&[Tile::Flower(Flower::Plum), m(1)]).unwrap();
+    assert_eq!(hand.flowers(), &[Tile::Flower(Flower::Plum)]);
+    assert_eq!(hand.concealed_count(), 1);
+    assert!(hand.add_flower(Tile::Flower(Flower::Plum)).is_err());
+    assert!(hand.add_tile(Tile::Flower(Flower::Spring)).is_err());
+}
+
+/// 测试吃和碰
+#[test]
+fn test_declare_chi_and_pon() {
+    let mut hand: Hand = "24m0556p1z".parse().unwrap();
+
+    hand.declare_chi(m(3), [m(2), m(4)], 3).unwrap();
+    assert_eq!(hand.melds().len(), 1);
+    assert_eq!(hand.melds()[0].meld_type, MeldType::Chi);
+    assert_eq!(hand.melds()[0].sources[0], MeldSource::Player(3));
+    assert_eq!(hand.concealed_count(), 5);
+    assert!(!hand.is_closed());
+
+    // 碰时优先使用普通五，红五留在手中
+    hand.declare_pon(p(5), 1).unwrap();
+    assert_eq!(hand.melds()[1].meld_type, MeldType::Pon);
+    assert!(hand.melds()[1].tiles.iter().all(|t| !t.is_red()));
+    assert_eq!(hand.concealed_tiles(), vec![Tile::new_red_five(Suit::Dot), p(6), Tile::Wind(Wind::East)]);
+
+    // 手中没有对应的牌
+    assert!(hand.declare_pon(m(9), 0).is_err());
+    assert!(hand.declare_chi(p(7), [p(8), p(9)], 0).is_err());
+    // 字牌不能吃
+    assert!(hand.declare_chi(Tile::Wind(Wind::South), [Tile::Wind(Wind::East), p(6)], 0).is_err());
+
+    // 持有摸牌时不能鸣牌
+    let mut hand: Hand = "55m".parse().unwrap();
+    hand.draw(m(1)).unwrap();
+    assert!(matches!(hand.declare_pon(m(5), 0), Err(MajiangError::InvalidMeld(_))));
+}
+
+/// 测试三种杠
+#[test]
+fn test_declare_kan() {
+    // 明杠需要打出者座位
+    let mut hand: Hand = "999m1z".parse().unwrap();
+    assert!(hand.declare_kan(m(9), KanType::Open, None).is_err());
+    hand.declare_kan(m(9), KanType::Open, Some(2)).unwrap();
+    assert_eq!(hand.melds()[0].meld_type, MeldType::Kan(KanType::Open));
+    assert_eq!(hand.concealed_count(), 1);
+
+    // 暗杠可以使用摸牌，未用到的摸牌并入暗手
+    let mut hand: Hand = "111s23m".parse().unwrap();
+    hand.draw(Tile::Suit(Suit::Bamboo, 1)).unwrap();
+    hand.declare_kan(Tile::Suit(Suit::Bamboo, 1), KanType::Closed, None).unwrap();
+    assert!(hand.is_closed());
+    assert!(hand.drawn().is_none());
+    assert_eq!(hand.concealed_count(), 2);
+
+    let mut hand: Hand = "1111s2m".parse().unwrap();
+    hand.draw(m(3)).unwrap();
+    hand.declare_kan(Tile::Suit(Suit::Bamboo, 1), KanType::Closed, None).unwrap();
+    assert_eq!(hand.concealed_tiles(), vec![m(2), m(3)]);
+
+    // 加杠把碰升级为杠
+    let mut hand: Hand = "7p[777z]".parse().unwrap();
+    hand.draw(Tile::Dragon(Dragon::Red)).unwrap();
+    hand.declare_kan(Tile::Dragon(Dragon::Red), KanType::Added, None).unwrap();
+    assert_eq!(hand.melds().len(), 1);
+    assert_eq!(hand.melds()[0].meld_type, MeldType::Kan(KanType::Added));
+    assert_eq!(hand.melds()[0].tiles.len(), 4);
+    assert_eq!(hand.concealed_tiles(), vec![p(7)]);
+
+    // 没有碰时不能加杠，不足四张时不能暗杠
+    let mut hand: Hand = "222m".parse().unwrap();
+    assert!(hand.declare_kan(m(2), KanType::Added, None).is_err());
+    assert!(hand.declare_kan(m(2), KanType::Closed, None).is_err());
+}
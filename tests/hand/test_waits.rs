@@ -0,0 +1,125 @@
+// tests/hand/test_waits.rs
+//
+// 和牌张与和牌拆分的测试用例
+// 验证听牌形状的分类、多种拆分的枚举以及空听的排除
+
+use majiang_core::hand::{
+    Hand, Mentsu, WaitType, WinForm, Decomposition,
+    winning_tiles, decompositions,
+};
+use majiang_core::tile::{Tile, Suit, Wind, Dragon};
+
+fn hand(notation: &str) -> Hand {
+    notation.parse().unwrap()
+}
+
+fn m(n: u8) -> Tile {
+    Tile::Suit(Suit::Character, n)
+}
+
+/// 只取听牌形状，便于断言
+fn waits(hand: &Hand, win_tile: Tile) -> Vec<WaitType> {
+    decompositions(hand, win_tile).iter().map(|d| d.wait).collect()
+}
+
+/// 测试五种听牌形状
+#[test]
+fn test_wait_types() {
+    let ryanmen = hand("123p456p789s23m11z");
+    assert_eq!(winning_tiles(&ryanmen), vec![m(1), m(4)]);
+    assert_eq!(waits(&ryanmen, m(1)), vec![WaitType::Ryanmen]);
+    assert_eq!(waits(&ryanmen, m(4)), vec![WaitType::Ryanmen]);
+
+    let kanchan = hand("123p456p789s13m11z");
+    assert_eq!(winning_tiles(&kanchan), vec![m(2)]);
+    assert_eq!(waits(&kanchan, m(2)), vec![WaitType::Kanchan]);
+
+    let penchan = hand("123p456p789s89m11z");
+    assert_eq!(winning_tiles(&penchan), vec![m(7)]);
+    assert_eq!(waits(&penchan, m(7)), vec![WaitType::Penchan]);
+
+    let shanpon = hand("123p456p789s11m11z");
+    assert_eq!(winning_tiles(&shanpon), vec![m(1), Tile::Wind(Wind::East)]);
+    let result = decompositions(&shanpon, m(1));
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].wait, WaitType::Shanpon);
+    assert_eq!(result[0].pair, Tile::Wind(Wind::East));
+    assert_eq!(result[0].sets[result[0].win_block.unwrap()], Mentsu::Triplet(m(1)));
+
+    let tanki = hand("123p456p789s123m1z");
+    assert_eq!(winning_tiles(&tanki), vec![Tile::Wind(Wind::East)]);
+    assert_eq!(waits(&tanki, Tile::Wind(Wind::East)), vec![WaitType::Tanki]);
+
+    // 不是和牌张时没有拆分
+    assert!(decompositions(&tanki, m(9)).is_empty());
+}
+
+/// 测试同一和牌张的多种拆分
+#[test]
+fn test_multiple_decompositions() {
+    // 4万既可以是单骑(234万 + 4万单骑)，也可以是两面(44万雀头 + 23万等4万)
+    let h = hand("123p456p789s2344m");
+    assert_eq!(winning_tiles(&h), vec![m(1), m(4)]);
+    let result = decompositions(&h, m(4));
+    assert!(result.iter().any(|d| d.wait == WaitType::Tanki && d.win_block.is_none()));
+    assert!(result.iter().any(|d| d.wait == WaitType::Ryanmen && d.pair == m(4)));
+
+    // 九莲宝灯听全部九种
+    let nine_gates = hand("1112345678999m");
+    assert_eq!(winning_tiles(&nine_gates), (1..=9).map(m).collect::<Vec<_>>());
+
+    // 一杯口的两个相同顺子不会给出重复拆分
+    let h = hand("112233m456p789s1z");
+    assert_eq!(decompositions(&h, Tile::Wind(Wind::East)).len(), 1);
+    let h = hand("123m456p789s23m11z");
+    assert_eq!(decompositions(&h, m(1)).len(), 1);
+
+    // 红五和牌张按种类处理
+    let h = hand("123p456p789s34m11z");
+    let red = decompositions(&h, Tile::new_red_five(Suit::Character));
+    assert_eq!(red.len(), 1);
+    assert_eq!(red[0].sets[red[0].win_block.unwrap()], Mentsu::Sequence(m(3)));
+}
+
+/// 测试七对子和国士无双的拆分
+#[test]
+fn test_special_form_decompositions() {
+    let h = hand("1122m3344p5566s7z");
+    let result = decompositions(&h, Tile::Wind(Wind::South));
+    assert!(result.is_empty());
+    let seven = Tile::Dragon(Dragon::Red);
+    assert_eq!(decompositions(&h, seven), vec![Decomposition {
+        form: WinForm::Chiitoitsu,
+        pair: seven,
+        sets: Vec::new(),
+        wait: WaitType::Tanki,
+        win_block: None,
+    }]);
+
+    // 二杯口同时是七对子和一般形
+    let h = hand("112233m445566p7z");
+    let forms: Vec<WinForm> = decompositions(&h, seven).iter().map(|d| d.form).collect();
+    assert!(forms.contains(&WinForm::Standard));
+    assert!(forms.contains(&WinForm::Chiitoitsu));
+
+    // 国士无双十三面
+    let h = hand("19m19p19s1234567z");
+    assert_eq!(winning_tiles(&h).len(), 13);
+    assert_eq!(decompositions(&h, m(1))[0].form, WinForm::Kokushi);
+}
+
+/// 测试副露与空听
+#[test]
+fn test_melds_and_karaten() {
+    let h = hand("23m11z[123p][456p][789s]");
+    assert_eq!(winning_tiles(&h), vec![m(1), m(4)]);
+    assert_eq!(decompositions(&h, m(1))[0].sets.len(), 1);
+
+    // 单骑的牌已全部在手中，无法和牌
+    let h = hand("123p456p789s1111z");
+    assert!(winning_tiles(&h).is_empty());
+
+    // 副露中的牌同样计入
+    let h = hand("1z[111z][123p][456p][789s]");
+    assert!(winning_tiles(&h).is_empty());
+}
@@ -0,0 +1,27 @@
+// tests/helpers/mod.rs
+//
+// 各测试模块共用的辅助函数：解析单张牌、创建和牌场况、找出需要行动的玩家
+
+use majiang_core::action::Action;
+use majiang_core::game::{GameState, WinContext};
+use majiang_core::hand::{parse_tiles, Hand};
+use majiang_core::tile::Tile;
+
+/// 解析一张牌的记法
+pub fn tile(notation: &str) -> Tile {
+    parse_tiles(notation).unwrap()[0]
+}
+
+/// 由手牌记法和和牌张创建场况，荣和时放铳者为座位1
+pub fn context(hand: &str, win: &str, tsumo: bool) -> WinContext {
+    let hand: Hand = hand.parse().unwrap();
+    WinContext::new(hand, tile(win), if tsumo { None } else { Some(1) })
+}
+
+/// 找出此刻需要行动的玩家及其合法动作，本局未结束时总有玩家需要行动
+pub fn next_actor(game: &GameState) -> (u8, Vec<Action>) {
+    (0..game.players().len() as u8)
+        .map(|seat| (seat, game.legal_actions(seat)))
+        .find(|(_, actions)| !actions.is_empty())
+        .expect("未结束的局面中总有玩家需要行动")
+}
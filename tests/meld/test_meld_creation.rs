@@ -171,7 +171,7 @@ fn test_create_invalid_meld_wrong_tile_count() {
     
     // 检查错误类型
     match result {
-        Err(MajiangError::InvalidMeld(_)) => assert!(true),
+        Err(MajiangError::InvalidMeld(_)) => {}
         _ => panic!("期望InvalidMeld错误，但得到了不同的结果"),
     }
 }
@@ -192,7 +192,35 @@ fn test_create_invalid_meld_wrong_source_count() {
     
     // 检查错误类型
     match result {
-        Err(MajiangError::InvalidMeld(_)) => assert!(true),
+        Err(MajiangError::InvalidMeld(_)) => {}
         _ => panic!("期望InvalidMeld错误，但得到了不同的结果"),
     }
-}
\ No newline at end of file
+}
+/// 测试由实体牌创建副露，排序后实体牌随牌面一起移动
+#[test]
+fn test_create_meld_from_instances() {
+    use majiang_core::meld::sort_tiles_in_meld;
+    use majiang_core::tile::PhysicalTile;
+    
+    let instances = vec![
+        PhysicalTile::new(Tile::new_suit(Suit::Dot, 6).unwrap(), 2).unwrap(),
+        PhysicalTile::new(Tile::new_red_five(Suit::Dot), 0).unwrap(),
+        PhysicalTile::new(Tile::new_suit(Suit::Dot, 4).unwrap(), 1).unwrap(),
+    ];
+    let sources = vec![MeldSource::SelfDrawn, MeldSource::Player(3), MeldSource::SelfDrawn];
+    
+    let meld = Meld::from_instances(instances.clone(), MeldType::Chi, sources).unwrap();
+    assert_eq!(meld.tiles[1], Tile::new_red_five(Suit::Dot));
+    assert_eq!(meld.instances, instances);
+    
+    // 排序后4筒、红5筒、6筒，实体编号与牌面保持对应
+    let sorted = sort_tiles_in_meld(&meld);
+    for (tile, instance) in sorted.tiles.iter().zip(sorted.instances.iter()) {
+        assert_eq!(*tile, instance.tile());
+    }
+    assert_eq!(sorted.instances[0].id(), instances[2].id());
+    
+    // 由牌面创建的副露没有实体牌
+    let pon = Meld::new(vec![Tile::new_suit(Suit::Dot, 1).unwrap(); 3], MeldType::Pon, vec![MeldSource::SelfDrawn, MeldSource::SelfDrawn, MeldSource::Player(1)]).unwrap();
+    assert!(pon.instances.is_empty());
+}
@@ -281,4 +281,27 @@ fn test_get_key_tile() {
     let kan = Meld::new(kan_tiles, MeldType::Kan(KanType::Closed), kan_sources).unwrap();
     
     assert_eq!(kan.get_key_tile(), kan_tile);
-}
\ No newline at end of file
+}
+/// 测试红五参与吃碰杠判断时按普通五处理
+#[test]
+fn test_can_form_melds_with_red_five() {
+    let red = Tile::new_red_five(Suit::Bamboo);
+    let five = Tile::new_suit(Suit::Bamboo, 5).unwrap();
+    
+    // 手中一张红5索一张普通5索，可以碰5索
+    let hand = vec![red, five, Tile::new_suit(Suit::Bamboo, 4).unwrap()];
+    assert!(can_form_pon(&hand, five));
+    assert!(can_form_pon(&hand, red));
+    
+    // 手中4索和红5索，可以吃3索和6索
+    assert!(can_form_chi(&hand, Tile::new_suit(Suit::Bamboo, 3).unwrap()));
+    assert!(can_form_chi(&hand, Tile::new_suit(Suit::Bamboo, 6).unwrap()));
+    
+    // 吃牌组合中出现的是手中实际的红五
+    let combos = get_possible_chi_combinations(&[red, Tile::new_suit(Suit::Bamboo, 4).unwrap()], Tile::new_suit(Suit::Bamboo, 3).unwrap());
+    assert_eq!(combos.len(), 1);
+    assert!(combos[0].contains(&red));
+    
+    // 三张5索(含红五)可以杠第四张
+    assert!(can_form_kan(&[red, five, five], five, false));
+}
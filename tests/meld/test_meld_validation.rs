@@ -200,4 +200,24 @@ fn test_validate_valid_added_kan() {
     
     let result = validate_kan(&tiles, KanType::Added, &sources);
     assert!(result.is_ok());
-}
\ No newline at end of file
+}
+/// 测试红五与普通五可以组成同一个吃、碰、杠
+#[test]
+fn test_validate_melds_with_red_five() {
+    let red = Tile::new_red_five(Suit::Dot);
+    let five = Tile::new_suit(Suit::Dot, 5).unwrap();
+    
+    // 吃：4筒、红5筒、6筒
+    let chi = vec![Tile::new_suit(Suit::Dot, 4).unwrap(), red, Tile::new_suit(Suit::Dot, 6).unwrap()];
+    assert!(validate_chi(&chi).is_ok());
+    
+    // 碰：红5筒与两张普通5筒
+    assert!(validate_pon(&[red, five, five]).is_ok());
+    
+    // 杠：红5筒与三张普通5筒
+    let sources = vec![MeldSource::SelfDrawn; 4];
+    assert!(validate_kan(&[five, red, five, five], KanType::Closed, &sources).is_ok());
+    
+    // 不同花色的红五不能与5筒碰
+    assert!(validate_pon(&[Tile::new_red_five(Suit::Bamboo), five, five]).is_err());
+}
@@ -2,7 +2,14 @@
 //
 // 注册所有测试模块
 
+// 各测试模块共用的辅助函数
+mod helpers;
+
 // 导入tile测试模块
 mod tile;
 mod wall;
-mod meld;
\ No newline at end of file
+mod meld;
+mod hand;
+mod action;
+mod game;
+mod rules;
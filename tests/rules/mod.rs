@@ -0,0 +1,14 @@
+// tests/rules/mod.rs
+//
+// 规则模块的测试入口文件
+
+mod test_ruleset;
+mod test_yaku;
+mod test_score;
+mod test_mcr_scoring;
+mod test_mcr_win_check;
+mod test_flower;
+mod test_shanghai;
+mod test_riichi_specific;
+mod test_sanma;
+mod test_sichuan;
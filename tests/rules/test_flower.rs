@@ -0,0 +1,128 @@
+// tests/rules/test_flower.rs
+//
+// 国标麻将和上海麻将补花流程的测试用例
+// 覆盖配牌后的补花顺序、连续补花、对局中摸到花牌以及补牌来源的配置
+
+use std::sync::Arc;
+
+use majiang_core::action::Action;
+use majiang_core::game::{Event, GameConfig, GameState, Phase};
+use majiang_core::hand::{parse_tiles, Hand};
+use majiang_core::rules::common::flower::declare_flower;
+use majiang_core::rules::{McrRules, RuleSet, ShanghaiRules};
+use majiang_core::wall::{DeadWallConfig, ReplacementSource, Wall, WallConfig};
+
+use crate::helpers::next_actor;
+
+/// 按规则创建一局并发完配牌，返回配牌阶段的事件
+fn deal(rules: Arc<dyn RuleSet>, seed: u64, source: ReplacementSource) -> (GameState, Vec<Event>) {
+    let mut config = GameConfig::from_rules(rules.as_ref(), seed);
+    config.replacement_source = source;
+    let mut game = GameState::with_rules(config, rules).unwrap();
+    let events = game.apply(0, Action::Draw).unwrap();
+    (game, events)
+}
+
+fn flower_count(events: &[Event]) -> usize {
+    events.iter().filter(|e| matches!(e, Event::FlowerDeclared { .. })).count()
+}
+
+/// 测试配牌后的补花：从庄家起按座位顺序亮花，每亮一张花补一张牌
+#[test]
+fn test_dealt_flowers_are_replaced() {
+    for seed in 0..20 {
+        let (game, events) = deal(Arc::new(McrRules), seed, ReplacementSource::DeadWall);
+        for player in game.players() {
+            assert_eq!(player.hand().concealed_count(), 13);
+            assert!(player.hand().concealed_tiles().iter().all(|t| !t.is_flower()));
+        }
+
+        let flowers: Vec<(usize, u8)> = events
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| match e {
+                Event::FlowerDeclared { seat, .. } => Some((i, *seat)),
+                _ => None,
+            })
+            .collect();
+        assert!(flowers.windows(2).all(|w| w[0].1 <= w[1].1));
+        for &(i, seat) in &flowers {
+            assert!(matches!(events[i + 1], Event::DrewReplacement { seat: s, .. } if s == seat));
+        }
+        let shown: usize = game.players().iter().map(|p| p.hand().flowers().len()).sum();
+        assert_eq!(shown, flowers.len());
+    }
+}
+
+/// 测试补到花牌时继续亮花补牌
+#[test]
+fn test_chained_replacement() {
+    let chained = (0..200).find_map(|seed| {
+        let (_, events) = deal(Arc::new(McrRules), seed, ReplacementSource::DeadWall);
+        events.windows(2).find_map(|w| match (&w[0], &w[1]) {
+            (Event::DrewReplacement { seat, tile }, Event::FlowerDeclared { seat: next, tile: flower })
+                if tile.is_flower() =>
+            {
+                Some((*seat, *tile, *next, *flower))
+            }
+            _ => None,
+        })
+    });
+    let (seat, tile, next, flower) = chained.expect("应有种子在配牌时连续补花");
+    assert_eq!((seat, tile), (next, flower));
+
+    // 对局中亮出摸到的花牌，补到花牌时继续补
+    let mut wall = Wall::new(WallConfig::MCR, Some(DeadWallConfig::MCR { replacement_count: 8 }), Some(1)).unwrap();
+    wall.start_game();
+    let mut hand: Hand = "123456789m1234p".parse().unwrap();
+    let flower = parse_tiles("1f").unwrap()[0];
+    hand.draw(flower).unwrap();
+    let mut events = Vec::new();
+    assert!(declare_flower(&mut wall, &mut hand, 2, flower, ReplacementSource::DeadWall, &mut events).unwrap());
+    assert_eq!(flower_count(&events), hand.flowers().len());
+    assert!(matches!(events.last(), Some(Event::DrewReplacement { seat: 2, tile }) if !tile.is_flower()));
+    assert_eq!(hand.concealed_count(), 14);
+    assert!(hand.concealed_tiles().iter().all(|t| !t.is_flower()));
+}
+
+/// 测试补牌来源：从牌墙末尾补牌时补花区不变
+#[test]
+fn test_replacement_source() {
+    assert_eq!(GameConfig::from_rules(&McrRules, 1).replacement_source, ReplacementSource::DeadWall);
+    for seed in 0..10 {
+        let (game, events) = deal(Arc::new(McrRules), seed, ReplacementSource::LiveWallBack);
+        let replacements = events.iter().filter(|e| matches!(e, Event::DrewReplacement { .. })).count();
+        assert_eq!(game.wall().dead_wall().unwrap().remaining_replacement_tiles(), 8);
+        assert_eq!(game.wall().remaining_tiles(), 144 - 16 - 52 - replacements);
+    }
+}
+
+/// 测试对局中摸到花牌：只能亮花，上海麻将使用相同的流程
+#[test]
+fn test_flower_drawn_during_play() {
+    let rules: Arc<dyn RuleSet> = Arc::new(ShanghaiRules { with_joker: false, ..ShanghaiRules::default() });
+    let (mut game, _) = deal(rules, 5, ReplacementSource::DeadWall);
+    let mut declared = 0;
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        let action = match actions.iter().find(|a| matches!(a, Action::DeclareFlower { .. })) {
+            Some(&action) => {
+                assert_eq!(actions, [action]);
+                declared += 1;
+                action
+            }
+            None => actions
+                .iter()
+                .copied()
+                .find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. }))
+                .unwrap_or(actions[actions.len() - 1]),
+        };
+        let events = game.apply(seat, action).unwrap();
+        if matches!(action, Action::DeclareFlower { .. }) {
+            assert!(matches!(events[0], Event::FlowerDeclared { seat: s, .. } if s == seat));
+            assert!(flower_count(&events) >= 1);
+        }
+    }
+    assert!(declared > 0);
+    assert!(game.players().iter().all(|p| p.hand().concealed_tiles().iter().all(|t| !t.is_flower())));
+}
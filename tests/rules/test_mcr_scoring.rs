@@ -0,0 +1,155 @@
+// tests/rules/test_mcr_scoring.rs
+//
+// 国标麻将番种计算的测试用例
+// 覆盖番种表、不重复原则、套算一次原则、起和番数、花牌和点数支付
+
+use majiang_core::rules::mcr::{evaluate_fan, Fan, FanResult, McrRules};
+use majiang_core::rules::{RuleSet, ScoreItem};
+
+use crate::helpers::context;
+
+fn evaluate(hand: &str, win: &str, tsumo: bool) -> FanResult {
+    evaluate_fan(&context(hand, win, tsumo)).unwrap()
+}
+
+fn has(result: &FanResult, fan: Fan) -> bool {
+    result.fans.iter().any(|&(f, _)| f == fan)
+}
+
+/// 测试番种表：81个番种名称互不相同，各番值的番种数量符合规则
+#[test]
+fn test_fan_table() {
+    let mut names: Vec<&str> = Fan::ALL.iter().map(|f| f.name()).collect();
+    names.sort_unstable();
+    names.dedup();
+    assert_eq!(names.len(), 81);
+
+    let count = |points: u32| Fan::ALL.iter().filter(|f| f.points() == points).count();
+    let counts: Vec<usize> = [88, 64, 48, 32, 24, 16, 12, 8, 6, 4, 2, 1].iter().map(|&p| count(p)).collect();
+    assert_eq!(counts, [7, 6, 2, 3, 9, 6, 5, 9, 7, 4, 10, 13]);
+    assert!(Fan::ALL.windows(2).all(|w| w[0].points() >= w[1].points()));
+    assert_eq!(Fan::FullFlush.to_string(), "清一色");
+}
+
+/// 测试不重复原则：清一色不计无字和缺一门，清龙不计连六和老少副
+#[test]
+fn test_exclusion() {
+    let result = evaluate("123456789m123m5m", "5m", false);
+    assert!(has(&result, Fan::FullFlush));
+    assert!(has(&result, Fan::PureStraight));
+    assert!(has(&result, Fan::ConcealedHand));
+    assert!(!has(&result, Fan::NoHonors));
+    assert!(!has(&result, Fan::OneVoidedSuit));
+    assert!(!has(&result, Fan::ShortStraight));
+    assert!(!has(&result, Fan::TwoTerminalChows));
+    // 第四组顺子与清龙中的一组再组合一次
+    assert!(result.fans.contains(&(Fan::PureDoubleChow, 1)));
+
+    // 大四喜不计三风刻、碰碰和、圈风刻、门风刻和幺九刻；字一色不计混幺九
+    let result = evaluate("111z222z333z444z5z", "5z", false);
+    assert_eq!(
+        result.fans,
+        [(Fan::BigFourWinds, 1), (Fan::AllHonors, 1), (Fan::FourConcealedPungs, 1), (Fan::SingleWait, 1)]
+    );
+    assert_eq!(result.total(), 88 + 64 + 64 + 1);
+}
+
+/// 测试套算一次原则：四组顺子两两组合最多计三次
+#[test]
+fn test_account_once() {
+    let result = evaluate("[123m]123m123123p5s", "5s", false);
+    assert!(result.fans.contains(&(Fan::PureDoubleChow, 2)));
+    assert!(result.fans.contains(&(Fan::MixedDoubleChow, 1)));
+
+    // 三同刻不再计双同刻
+    let result = evaluate("111m111p111s23m55s", "4m", false);
+    assert!(has(&result, Fan::TriplePung));
+    assert!(has(&result, Fan::ThreeConcealedPungs));
+    assert!(!has(&result, Fan::DoublePung));
+    assert!(!has(&result, Fan::TwoConcealedPungs));
+}
+
+/// 测试风刻：圈风刻和门风刻不再计幺九刻，其他风刻计幺九刻
+#[test]
+fn test_wind_pungs() {
+    let result = evaluate("[111z][333z]234m567p9s", "9s", false);
+    assert!(has(&result, Fan::PrevalentWind));
+    assert!(has(&result, Fan::SeatWind));
+    assert!(result.fans.contains(&(Fan::PungOfTerminalsOrHonors, 1)));
+    assert!(has(&result, Fan::SingleWait));
+    assert_eq!(result.total(), 6);
+    assert!(!result.is_valid());
+}
+
+/// 测试七对、连七对和十三幺
+#[test]
+fn test_special_forms() {
+    let result = evaluate("1122334455667m", "7m", false);
+    assert!(has(&result, Fan::SevenShiftedPairs));
+    assert!(!has(&result, Fan::SevenPairs));
+    assert!(!has(&result, Fan::FullFlush));
+    assert!(!has(&result, Fan::ConcealedHand));
+
+    let result = evaluate("1133m5577p99s114z", "4z", true);
+    assert!(has(&result, Fan::SevenPairs));
+    assert!(has(&result, Fan::SelfDrawn));
+    assert!(!has(&result, Fan::FullyConcealedHand));
+
+    let result = evaluate("19m19p19s1234567z", "1m", false);
+    assert_eq!(result.fans, [(Fan::ThirteenOrphans, 1)]);
+}
+
+/// 测试和牌方式：不求人不计自摸和门前清，杠上开花不计自摸
+#[test]
+fn test_situational_fans() {
+    let result = evaluate("123456789m234p5p", "5p", true);
+    assert!(has(&result, Fan::FullyConcealedHand));
+    assert!(!has(&result, Fan::SelfDrawn));
+    assert!(!has(&result, Fan::ConcealedHand));
+
+    let mut rinshan = context("[123m]456789m234p5p", "5p", true);
+    rinshan.rinshan = true;
+    let result = evaluate_fan(&rinshan).unwrap();
+    assert!(has(&result, Fan::OutWithReplacementTile));
+    assert!(!has(&result, Fan::SelfDrawn));
+
+    let mut last = context("[123m]456789m234p5p", "5p", false);
+    last.last_of_kind = true;
+    assert!(has(&evaluate_fan(&last).unwrap(), Fan::LastTile));
+}
+
+/// 测试8番起和，花牌不计入起和番数
+#[test]
+fn test_minimum_and_flowers() {
+    // 碰碰和6番，两张花牌后仍不能和牌
+    let hand = "[222m][888p][333s]44s55z12f";
+    let result = evaluate(hand, "4s", false);
+    assert_eq!(result.flower_fan(), 2);
+    assert_eq!(result.total(), 8);
+    assert!(!result.is_valid());
+    let rules = McrRules;
+    assert!(!rules.is_valid_win(&context(hand, "4s", false)));
+    assert!(rules.score_win(&context(hand, "4s", false)).is_none());
+
+    // 没有其他番种时计无番和
+    let result = evaluate("[123m][567p][789s]34s55z", "2s", false);
+    assert_eq!(result.fans, [(Fan::ChickenHand, 1)]);
+    assert!(result.is_valid());
+}
+
+/// 测试计番明细和点数支付：底分8分，放铳者另付番数，自摸时三家各付番数加底分
+#[test]
+fn test_items_and_payments() {
+    let rules = McrRules;
+    let hand = "[123m]456789m234p5p12f";
+    let score = rules.score_win(&context(hand, "5p", false)).unwrap();
+    assert!(score.items.contains(&ScoreItem::new("清龙", 16)));
+    assert!(score.items.contains(&ScoreItem::new("花牌×2", 2)));
+    let total: u32 = score.items.iter().map(|item| item.value).sum();
+    let total = total as i32;
+    assert_eq!(score.payments, [total + 24, -(total + 8), -8, -8]);
+
+    let score = rules.score_win(&context(hand, "5p", true)).unwrap();
+    let total = score.items.iter().map(|item| item.value).sum::<u32>() as i32;
+    assert_eq!(score.payments, [3 * (total + 8), -(total + 8), -(total + 8), -(total + 8)]);
+}
@@ -0,0 +1,165 @@
+// tests/rules/test_mcr_win_check.rs
+//
+// 国标麻将和牌判定的测试用例
+// 覆盖一般形、十三幺、允许四张相同牌的七对、全不靠(七星不靠)和组合龙，
+// 以及对局中按国标麻将的牌形给出自摸和荣和
+
+use std::sync::Arc;
+
+use majiang_core::action::Action;
+use majiang_core::game::{GameConfig, GameState, Phase, WinContext};
+use majiang_core::hand::{is_winning_shape, Hand, WaitType, WinForm};
+use majiang_core::rules::common::BlockKind;
+use majiang_core::rules::mcr::win_check::winning_tiles;
+use majiang_core::rules::mcr::{evaluate_fan, is_winning_hand, winning_decompositions, Fan, McrRules};
+use majiang_core::rules::RuleSet;
+use majiang_core::tile::{Suit, Tile};
+
+use crate::helpers::{next_actor, tile};
+
+fn forms(hand: &str, win: &str) -> Vec<WinForm> {
+    let hand: Hand = hand.parse().unwrap();
+    winning_decompositions(&hand, tile(win), false).iter().map(|p| p.form).collect()
+}
+
+fn fans(hand: &str, win: &str) -> Vec<Fan> {
+    let hand: Hand = hand.parse().unwrap();
+    let result = evaluate_fan(&WinContext::new(hand, tile(win), Some(1))).unwrap();
+    result.fans.iter().map(|&(fan, _)| fan).collect()
+}
+
+/// 组合龙中三种花色的所有排列，依次对应147、258、369
+const KNITTED_ORDERS: [[Suit; 3]; 6] = [
+    [Suit::Character, Suit::Dot, Suit::Bamboo],
+    [Suit::Character, Suit::Bamboo, Suit::Dot],
+    [Suit::Dot, Suit::Character, Suit::Bamboo],
+    [Suit::Dot, Suit::Bamboo, Suit::Character],
+    [Suit::Bamboo, Suit::Character, Suit::Dot],
+    [Suit::Bamboo, Suit::Dot, Suit::Character],
+];
+
+/// 牌是否可以用于按`order`组成的全不靠
+fn fits_knitted(order: &[Suit; 3], tile: Tile) -> bool {
+    match tile {
+        Tile::Suit(suit, number) => order.iter().position(|&s| s == suit) == Some((number as usize - 1) % 3),
+        _ => tile.is_honor(),
+    }
+}
+
+/// 做全不靠的打法：选定最接近的组合龙排列，先打不合的牌，再打重复的牌
+fn knitted_discard(hand: &Hand) -> Tile {
+    let mut tiles = hand.concealed_tiles();
+    tiles.extend(hand.drawn());
+    let distinct = |order: &[Suit; 3]| {
+        let mut fitting: Vec<Tile> = tiles.iter().copied().filter(|&t| fits_knitted(order, t)).collect();
+        fitting.dedup();
+        fitting.len()
+    };
+    let order = KNITTED_ORDERS.iter().max_by_key(|order| distinct(order)).unwrap();
+    tiles
+        .iter()
+        .copied()
+        .find(|&t| !fits_knitted(order, t))
+        .or_else(|| tiles.windows(2).find(|w| w[0] == w[1]).map(|w| w[0]))
+        .unwrap_or(tiles[0])
+}
+
+/// 四家都做全不靠、能和就和、从不鸣牌，打完一局
+fn play_knitted(seed: u64) -> GameState {
+    let rules: Arc<dyn RuleSet> = Arc::new(McrRules);
+    let mut game = GameState::with_rules(GameConfig::from_rules(rules.as_ref(), seed), rules).unwrap();
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        let action = actions
+            .iter()
+            .copied()
+            .find(|a| {
+                matches!(a, Action::Tsumo | Action::Ron { .. } | Action::Draw | Action::DeclareFlower { .. } | Action::Pass)
+            })
+            .unwrap_or_else(|| {
+                let tile = knitted_discard(game.players()[seat as usize].hand());
+                Action::Discard { tile, tsumogiri: game.players()[seat as usize].hand().drawn() == Some(tile) }
+            });
+        game.apply(seat, action).unwrap();
+    }
+    game
+}
+
+/// 测试一般形、十三幺和不能和牌的手牌
+#[test]
+fn test_standard_forms() {
+    let hand: Hand = "123m456p789s23m55p".parse().unwrap();
+    assert!(is_winning_hand(&hand, tile("1m")));
+    assert!(!is_winning_hand(&hand, tile("5m")));
+    assert_eq!(forms("19m19p19s1234567z", "1z"), [WinForm::Kokushi]);
+}
+
+/// 测试七对：四张相同的牌可以当作两个对子，并计四归一
+#[test]
+fn test_seven_pairs_with_four_of_a_kind() {
+    let hand: Hand = "1111m3355p77s22z4z".parse().unwrap();
+    assert!(majiang_core::hand::decompositions(&hand, tile("4z")).is_empty());
+    assert_eq!(forms("1111m3355p77s22z4z", "4z"), [WinForm::Chiitoitsu]);
+    let fans = fans("1111m3355p77s22z4z", "4z");
+    assert!(fans.contains(&Fan::SevenPairs));
+    assert!(fans.contains(&Fan::TileHog));
+}
+
+/// 测试全不靠和七星不靠，数牌为完整组合龙时另计组合龙
+#[test]
+fn test_honors_and_knitted() {
+    assert_eq!(forms("147m258p36s12345z", "6z"), [WinForm::HonorsAndKnitted]);
+    assert_eq!(fans("147m258p36s12345z", "6z"), [Fan::LesserHonorsAndKnittedTiles]);
+    assert_eq!(fans("147m258p1234567z", "3s"), [Fan::GreaterHonorsAndKnittedTiles]);
+    assert_eq!(fans("147m258p369s1234z", "5z"), [Fan::LesserHonorsAndKnittedTiles, Fan::KnittedStraight]);
+
+    // 数牌不属于同一组组合龙时不能和牌
+    let hand: Hand = "147m258p35s12345z".parse().unwrap();
+    assert!(!is_winning_hand(&hand, tile("6z")));
+    assert!(McrRules.is_valid_win(&WinContext::new("147m258p36s12345z".parse().unwrap(), tile("6z"), Some(1))));
+}
+
+/// 测试组合龙加一组面子一个雀头
+#[test]
+fn test_knitted_straight() {
+    let hand: Hand = "147m258p369s23m55z".parse().unwrap();
+    // 和牌的4万既可以完成234万，也可以看作组合龙中的一张
+    let patterns = winning_decompositions(&hand, tile("4m"), false);
+    let waits: Vec<WaitType> = patterns.iter().map(|p| p.wait).collect();
+    assert_eq!(waits, [WaitType::Ryanmen, WaitType::Kanchan]);
+    let kinds: Vec<BlockKind> = patterns[0].blocks.iter().map(|b| b.kind).collect();
+    assert_eq!(kinds, [BlockKind::Sequence, BlockKind::Knitted, BlockKind::Knitted, BlockKind::Knitted]);
+    assert!(fans("147m258p369s23m55z", "4m").contains(&Fan::KnittedStraight));
+
+    // 听牌同时包括一般形以外的牌形
+    assert_eq!(winning_tiles(&hand), [tile("1m"), tile("4m")]);
+    assert!(majiang_core::hand::winning_tiles(&hand).is_empty());
+
+    // 和牌张落在组合龙中时记为嵌张，但不计坎张
+    let hand: Hand = "147m25p369s123m55z".parse().unwrap();
+    let patterns = winning_decompositions(&hand, tile("8p"), false);
+    assert_eq!(patterns.len(), 1);
+    assert_eq!(patterns[0].wait, WaitType::Kanchan);
+    let win_block = patterns[0].win_block.unwrap();
+    assert_eq!(patterns[0].blocks[win_block].kind, BlockKind::Knitted);
+    assert!(!fans("147m25p369s123m55z", "8p").contains(&Fan::ClosedWait));
+
+    // 组合龙可以带一组副露
+    assert!(forms("[123m]147m258p369s5z", "5z").contains(&WinForm::Standard));
+    let hand: Hand = "[123m][456m]147m258p5z".parse().unwrap();
+    assert!(!is_winning_hand(&hand, tile("5z")));
+}
+
+/// 测试对局中以全不靠自摸和荣和：这些牌形不是一般形，按国标麻将的牌形判定才会给出和牌动作
+#[test]
+fn test_knitted_wins_in_game() {
+    // 种子2自摸，种子4荣和
+    for (seed, tsumo) in [(2, true), (4, false)] {
+        let game = play_knitted(seed);
+        let context = &game.win_contexts()[0];
+        assert_eq!(context.from.is_none(), tsumo);
+        assert!(!is_winning_shape(&context.hand, context.win_tile));
+        assert!(is_winning_hand(&context.hand, context.win_tile));
+        assert!(game.win_scores()[0].items.iter().any(|item| item.name == Fan::LesserHonorsAndKnittedTiles.name()));
+    }
+}
@@ -0,0 +1,266 @@
+// tests/rules/test_riichi_specific.rs
+//
+// 立直麻将特有机制的测试用例
+// 覆盖立直宣言条件、宣言牌被荣和时立直不成立、立直后的暗杠、振听、杠宝牌的翻开时机和杠后岭上牌区的补充
+
+use std::sync::Arc;
+
+use majiang_core::action::{is_legal, legal_actions, Action, PlayerView, Situation};
+use majiang_core::game::{Event, GameConfig, GameState, Match, MatchConfig, Phase, RoundResult};
+use majiang_core::hand::{discard_efficiency, winning_tiles, Hand, VisibleTiles};
+use majiang_core::meld::{KanType, MeldType};
+use majiang_core::rules::riichi::riichi_specific::kan_dora_timing;
+use majiang_core::rules::riichi::{DoraTiming, Furiten};
+use majiang_core::rules::{McrRules, RiichiRules, RuleSet};
+
+use crate::helpers::{next_actor, tile};
+
+/// 解析13张手牌并摸入一张牌
+fn drawn_hand(notation: &str, drawn: &str) -> Hand {
+    let mut hand: Hand = notation.parse().unwrap();
+    hand.draw(tile(drawn)).unwrap();
+    hand
+}
+
+/// 立直麻将规则下自己回合的视角
+fn turn_view(hand: &Hand, wall_remaining: usize) -> PlayerView<'_> {
+    let mut view = PlayerView::new(0, hand, Situation::Turn { after_call: false }, wall_remaining);
+    view.options = RiichiRules::default().action_options();
+    view
+}
+
+/// 可以作为立直宣言牌打出的牌
+fn riichi_tiles(view: &PlayerView) -> Vec<majiang_core::tile::Tile> {
+    legal_actions(view)
+        .into_iter()
+        .filter_map(|a| if let Action::Riichi { tile, .. } = a { Some(tile) } else { None })
+        .collect()
+}
+
+/// 能杠就杠、能碰就碰，否则摸切的策略
+fn kan_happy(actions: &[Action]) -> Action {
+    let find = |pred: fn(&Action) -> bool| actions.iter().copied().find(pred);
+    find(|a| matches!(a, Action::Kan { .. }))
+        .or_else(|| find(|a| matches!(a, Action::Pon { .. })))
+        .or_else(|| find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. })))
+        .unwrap_or(actions[actions.len() - 1])
+}
+
+/// 双数座位能立直就立直，否则打出牌效率最好的牌，不和牌也不鸣牌
+fn efficient(game: &GameState, seat: u8, actions: &[Action]) -> Action {
+    let find = |pred: &dyn Fn(&Action) -> bool| actions.iter().copied().find(|a| pred(a));
+    if let Some(riichi) = find(&|a| matches!(a, Action::Riichi { .. })).filter(|_| seat.is_multiple_of(2)) {
+        return riichi;
+    }
+    let hand = game.player(seat).unwrap().hand();
+    let best = discard_efficiency(hand, &VisibleTiles::new()).ok().map(|options| options[0].discard);
+    find(&|a| matches!(a, Action::Discard { tile, .. } if Some(*tile) == best))
+        .or_else(|| find(&|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. })))
+        .unwrap_or(actions[actions.len() - 1])
+}
+
+/// 测试立直宣言的条件
+#[test]
+fn test_riichi_declaration() {
+    let hand = drawn_hand("123m456p789s11z23m", "5z");
+    assert_eq!(riichi_tiles(&turn_view(&hand, 4)), [tile("5z")]);
+    assert!(riichi_tiles(&turn_view(&hand, 3)).is_empty());
+
+    // 副露后或不听牌时不能立直
+    assert!(riichi_tiles(&turn_view(&drawn_hand("456p789s23m11z[123m]", "5z"), 50)).is_empty());
+    assert!(riichi_tiles(&turn_view(&drawn_hand("159m159p159s1234z", "5z"), 50)).is_empty());
+
+    // 打出东后只听自己已有四张的东(空听)，不能立直
+    assert!(riichi_tiles(&turn_view(&drawn_hand("123m456p789s111z", "1z"), 50)).is_empty());
+
+    assert_eq!(RiichiRules::default().riichi_deposit(), 1000);
+    assert_eq!(McrRules.riichi_deposit(), 0);
+
+    // 点数不足1000点的玩家在对局中没有立直选项
+    let mut config = GameConfig::riichi(3);
+    config.points = Some(vec![900, 25000, 25000, 25000]);
+    let game = GameState::new(config.clone()).unwrap();
+    assert_eq!(game.config().points.as_ref().unwrap()[0], 900);
+
+    // 点数的个数必须与玩家人数一致
+    config.points = Some(vec![25000; 3]);
+    assert!(GameState::new(config).is_err());
+}
+
+/// 测试立直宣言牌被荣和：立直不成立，不支付供托
+#[test]
+fn test_riichi_tile_ronned() {
+    // 能和就和，能立直就立直，否则按牌效率打牌
+    let mut game = GameState::new(GameConfig::riichi(5)).unwrap();
+    let mut events = Vec::new();
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        let action = actions
+            .iter()
+            .copied()
+            .find(|a| a.is_win())
+            .or_else(|| actions.iter().copied().find(|a| matches!(a, Action::Riichi { .. })))
+            .unwrap_or_else(|| efficient(&game, seat, &actions));
+        events.extend(game.apply(seat, action).unwrap());
+    }
+
+    let discard = events.iter().rposition(|e| matches!(e, Event::Discarded { .. })).unwrap();
+    let Event::Discarded { seat, riichi: true, .. } = events[discard] else { panic!("最后打出的应为立直宣言牌") };
+    assert!(events[discard..].iter().any(|e| matches!(e, Event::Won { from: Some(from), .. } if *from == seat)));
+    assert!(!game.player(seat).unwrap().is_riichi());
+
+    let result = RoundResult::from_game(&game, game.point_changes().to_vec()).unwrap();
+    assert!(!result.riichi.contains(&seat));
+    let mut record = Match::new(MatchConfig::default()).unwrap();
+    record.record_round(&result).unwrap();
+    assert_eq!(record.points()[seat as usize], 25000 + game.point_changes()[seat as usize]);
+}
+
+/// 测试立直后的暗杠：只能杠摸到的牌，且不改变听牌和面子构成
+#[test]
+fn test_kan_after_riichi() {
+    let can_kan = |hand: &Hand| {
+        let mut view = turn_view(hand, 50);
+        view.in_riichi = true;
+        is_legal(&view, &Action::Kan { tile: tile("1m"), kan_type: KanType::Closed })
+    };
+    assert!(can_kan(&drawn_hand("111m234p567s789s5z", "1m")));
+    // 手中原有的四张不是刚摸到的牌
+    assert!(!can_kan(&drawn_hand("1111m234p567s789s", "5z")));
+    // 1112m的1万参与了听牌，杠后听牌改变
+    assert!(!can_kan(&drawn_hand("11123m456p789s55z", "1m")));
+}
+
+/// 测试振听：放过和牌张后不能荣和，同巡振听在自己打牌后解除，立直振听不解除
+#[test]
+fn test_furiten_in_play() {
+    // 按牌效率打牌，双数座位能立直就立直，所有玩家都不和牌也不鸣牌
+    let (mut missed, mut riichi_missed) = (0, 0);
+    for seed in 2..12 {
+        if missed > 0 && riichi_missed > 0 {
+            break;
+        }
+        let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+        // 刚放过和牌张、等待应对结束的玩家，以及应对结束后等待自己打牌的玩家
+        let mut passing: Vec<(u8, bool)> = Vec::new();
+        let mut watching: Vec<(u8, bool)> = Vec::new();
+        while game.phase() != Phase::RoundEnd {
+            let (seat, actions) = next_actor(&game);
+            if game.furiten(seat).is_some() {
+                assert!(!actions.iter().any(|a| matches!(a, Action::Ron { .. })));
+            }
+            let action = efficient(&game, seat, &actions);
+            if action.is_discard() {
+                let tile = action.tile().unwrap();
+                passing = (0..4)
+                    .filter(|&s| s != seat)
+                    .map(|s| game.player(s).unwrap())
+                    .filter(|p| winning_tiles(p.hand()).iter().any(|w| w.is_same_kind(&tile)))
+                    .map(|p| (p.seat(), p.is_riichi()))
+                    .collect();
+            }
+            game.apply(seat, action).unwrap();
+
+            if game.phase() == Phase::RoundEnd {
+                break;
+            }
+            if game.phase() != Phase::WaitingCalls {
+                for (s, in_riichi) in passing.drain(..) {
+                    assert!(game.furiten(s).is_some());
+                    watching.retain(|&(w, _)| w != s);
+                    watching.push((s, in_riichi));
+                }
+            }
+            if !action.is_discard() {
+                continue;
+            }
+            if let Some(index) = watching.iter().position(|&(s, _)| s == seat) {
+                if watching.remove(index).1 {
+                    assert_eq!(game.furiten(seat), Some(Furiten::Riichi));
+                    riichi_missed += 1;
+                } else {
+                    assert_ne!(game.furiten(seat), Some(Furiten::Temporary));
+                    missed += 1;
+                }
+            }
+        }
+    }
+    assert!(missed > 0 && riichi_missed > 0, "{} {}", missed, riichi_missed);
+
+    // 国标麻将没有振听
+    let rules: Arc<dyn RuleSet> = Arc::new(McrRules);
+    let game = GameState::with_rules(GameConfig::from_rules(rules.as_ref(), 1), rules).unwrap();
+    assert_eq!(game.furiten(0), None);
+}
+
+/// 测试杠宝牌：暗杠立即翻开，明杠和加杠在杠后打牌时翻开
+#[test]
+fn test_kan_dora_timing() {
+    assert_eq!(kan_dora_timing(KanType::Closed), DoraTiming::Immediate);
+    assert_eq!(kan_dora_timing(KanType::Open), DoraTiming::AfterDiscard);
+    assert_eq!(RiichiRules::default().kan_dora_timing(KanType::Added), DoraTiming::AfterDiscard);
+    assert_eq!(McrRules.kan_dora_timing(KanType::Open), DoraTiming::Immediate);
+
+    let mut checked = [0; 2];
+    for seed in 0..200 {
+        if checked.iter().all(|&count| count > 0) {
+            break;
+        }
+        let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+        let mut events = Vec::new();
+        while game.phase() != Phase::RoundEnd {
+            let (seat, actions) = next_actor(&game);
+            events.extend(game.apply(seat, kan_happy(&actions)).unwrap());
+        }
+        for (i, event) in events.iter().enumerate() {
+            let Event::Called { meld, .. } = event else { continue };
+            match meld.meld_type {
+                MeldType::Kan(KanType::Closed) => {
+                    assert!(matches!(events[i + 1], Event::DoraRevealed { .. }));
+                    checked[0] += 1;
+                }
+                MeldType::Kan(_) => {
+                    assert!(matches!(events[i + 1], Event::DrewReplacement { .. }));
+                    let rest = &events[i + 2..];
+                    let Some(next) = rest.iter().position(|e| matches!(e, Event::Discarded { .. } | Event::Called { .. }))
+                    else {
+                        continue;
+                    };
+                    assert!(!rest[..next].iter().any(|e| matches!(e, Event::DoraRevealed { .. })));
+                    if matches!(rest[next], Event::Discarded { .. }) {
+                        assert!(matches!(rest[next + 1], Event::DoraRevealed { .. }));
+                        checked[1] += 1;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    assert!(checked.iter().all(|&count| count > 0), "{:?}", checked);
+}
+
+/// 测试杠后补充岭上牌区：岭上牌摸走后从主牌墙末尾移入一张，每次杠主牌墙减少一张
+#[test]
+fn test_kan_replenishes_dead_wall() {
+    let mut kans = 0;
+    for seed in 0..20 {
+        if kans >= 4 {
+            break;
+        }
+        let mut game = GameState::new(GameConfig::riichi(seed)).unwrap();
+        game.apply(0, Action::Draw).unwrap();
+        while game.phase() != Phase::RoundEnd {
+            let (seat, actions) = next_actor(&game);
+            let before = game.wall().remaining_tiles();
+            let events = game.apply(seat, kan_happy(&actions)).unwrap();
+            let draws = events.iter().filter(|e| matches!(e, Event::Drew { .. })).count();
+            let kan_count = events
+                .iter()
+                .filter(|e| matches!(e, Event::Called { meld, .. } if matches!(meld.meld_type, MeldType::Kan(_))))
+                .count();
+            assert_eq!(before - game.wall().remaining_tiles(), draws + kan_count);
+            kans += kan_count;
+        }
+    }
+    assert!(kans > 0);
+}
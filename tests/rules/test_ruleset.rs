@@ -0,0 +1,136 @@
+// tests/rules/test_ruleset.rs
+//
+// RuleSet的测试用例
+// 覆盖各规则生成的配置，以及起和条件、计分和流局结算对GameState的影响
+
+use std::sync::Arc;
+
+use majiang_core::action::{Action, ActionOptions};
+use majiang_core::game::{GameConfig, GameState, Phase, RonPolicy, RoundOutcome, WinContext};
+use majiang_core::rules::{FlowerHandling, McrRules, RiichiRules, RuleSet, ScoreItem, ShanghaiRules, WinScore};
+use majiang_core::wall::{DeadWallConfig, WallConfig};
+
+use crate::helpers::next_actor;
+
+/// 在立直麻将的基础上替换起和条件和结算的测试规则
+#[derive(Debug)]
+struct TestRules {
+    allow_wins: bool,
+}
+
+impl RuleSet for TestRules {
+    fn name(&self) -> &'static str {
+        "测试规则"
+    }
+
+    fn wall_config(&self) -> WallConfig {
+        RiichiRules::default().wall_config()
+    }
+
+    fn dead_wall_config(&self) -> Option<DeadWallConfig> {
+        RiichiRules::default().dead_wall_config()
+    }
+
+    fn action_options(&self) -> ActionOptions {
+        ActionOptions::default()
+    }
+
+    fn is_valid_win(&self, _context: &WinContext) -> bool {
+        self.allow_wins
+    }
+
+    /// 和牌者从每位支付者处得到1000点，自摸时其他三家支付
+    fn score_win(&self, context: &WinContext) -> Option<WinScore> {
+        let mut score = WinScore::empty(context.player_count);
+        score.items.push(ScoreItem::new("和牌", 1));
+        for seat in 0..context.player_count {
+            if seat != context.seat && context.from.is_none_or(|from| from == seat) {
+                score.payments[seat as usize] -= 1000;
+                score.payments[context.seat as usize] += 1000;
+            }
+        }
+        Some(score)
+    }
+
+    fn exhaustive_draw_payments(&self, _game: &GameState) -> Vec<i32> {
+        vec![300, -100, -100, -100]
+    }
+}
+
+/// 能和就和、能立直就立直、否则摸切地打完一局
+fn play(rules: Arc<dyn RuleSet>, seed: u64) -> GameState {
+    let mut game = GameState::with_rules(GameConfig::from_rules(rules.as_ref(), seed), rules).unwrap();
+    while game.phase() != Phase::RoundEnd {
+        let (seat, actions) = next_actor(&game);
+        let find = |pred: fn(&Action) -> bool| actions.iter().copied().find(pred);
+        let action = find(|a| a.is_win())
+            .or_else(|| find(|a| matches!(a, Action::Riichi { .. })))
+            .or_else(|| find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. })))
+            .unwrap_or(actions[actions.len() - 1]);
+        game.apply(seat, action).unwrap();
+    }
+    game
+}
+
+/// 测试各规则生成的配置
+#[test]
+fn test_config_from_rules() {
+    let riichi = GameConfig::from_rules(&RiichiRules::default(), 1);
+    assert_eq!(riichi, GameConfig::riichi(1));
+    assert_eq!(riichi.ron_policy, RonPolicy::Multiple);
+    assert!(riichi.options.allow_riichi);
+
+    let mcr = GameConfig::from_rules(&McrRules, 1);
+    assert_eq!(mcr.wall, WallConfig::MCR);
+    assert_eq!(mcr.ron_policy, RonPolicy::HeadBump);
+    assert!(!mcr.options.allow_riichi && !mcr.options.allow_abortive_draws);
+    assert_eq!(McrRules.flower_handling(), FlowerHandling::Replace);
+
+    let shanghai = ShanghaiRules { with_joker: false, ..ShanghaiRules::default() };
+    assert_eq!(GameConfig::from_rules(&shanghai, 1).wall, WallConfig::Shanghai { with_joker: false });
+    assert_eq!(RiichiRules::default().flower_handling(), FlowerHandling::None);
+}
+
+/// 测试按规则选择牌墙：国标麻将144张牌，16张补花区
+#[test]
+fn test_game_uses_rule_wall() {
+    let rules: Arc<dyn RuleSet> = Arc::new(McrRules);
+    let mut game = GameState::with_rules(GameConfig::from_rules(rules.as_ref(), 3), rules).unwrap();
+    game.apply(0, Action::Draw).unwrap();
+    assert_eq!(game.wall().remaining_tiles(), 144 - 16 - 52);
+    assert_eq!(game.rules().name(), "国标麻将");
+}
+
+/// 测试不满足起和条件时不提供自摸和荣和
+#[test]
+fn test_invalid_wins_are_not_offered() {
+    for seed in 0..8 {
+        let game = play(Arc::new(TestRules { allow_wins: false }), seed);
+        assert!(matches!(game.outcome(), Some(RoundOutcome::ExhaustiveDraw { .. })));
+        assert!(game.win_scores().is_empty());
+        assert_eq!(game.point_changes(), [300, -100, -100, -100]);
+    }
+}
+
+/// 测试和牌时按规则计分并汇总点数变化
+#[test]
+fn test_win_scores_and_point_changes() {
+    let mut wins = 0;
+    for seed in 0..16 {
+        let game = play(Arc::new(TestRules { allow_wins: true }), seed);
+        let changes = game.point_changes();
+        assert_eq!(changes.iter().sum::<i32>(), 0);
+        if let Some(RoundOutcome::Win { winners, from, .. }) = game.outcome() {
+            wins += 1;
+            assert_eq!(game.win_scores().len(), winners.len());
+            assert_eq!(game.win_scores()[0].items, [ScoreItem::new("和牌", 1)]);
+            for &seat in winners {
+                assert!(changes[seat as usize] > 0);
+            }
+            if let Some(from) = from {
+                assert_eq!(changes[*from as usize], -1000 * winners.len() as i32);
+            }
+        }
+    }
+    assert!(wins > 0);
+}
@@ -0,0 +1,147 @@
+// tests/rules/test_sanma.rs
+//
+// 三人立直麻将的测试用例
+// 覆盖去掉二至八万的牌墙、拔北与补牌、拔北宝牌和一万指示九万、自摸损与北家折半，以及三人对局
+
+use std::sync::Arc;
+
+use majiang_core::action::Action;
+use majiang_core::game::{Event, GameConfig, GameState, Match, MatchConfig, Phase, RoundResult, WinContext};
+use majiang_core::hand::{parse_tiles, Hand};
+use majiang_core::rules::riichi::{evaluate_yaku, sanma_payments, TsumoPayment, YakuOptions};
+use majiang_core::rules::{RuleSet, SanmaRules};
+use majiang_core::tile::{Suit, Tile, Wind};
+use majiang_core::wall::{builder, DeadWallConfig, Wall, WallConfig};
+
+use crate::helpers::next_actor;
+
+const NORTH: Tile = Tile::Wind(Wind::North);
+
+/// 副露和拔北前的13张手牌摸入一张北后拔北
+fn hand_with_nuki(notation: &str) -> Hand {
+    let mut hand: Hand = notation.parse().unwrap();
+    hand.draw(NORTH).unwrap();
+    hand.declare_nuki(NORTH).unwrap();
+    hand
+}
+
+/// 座位`seat`的三人麻将和牌场况，庄家为座位0
+fn context(seat: u8, from: Option<u8>) -> WinContext {
+    let hand: Hand = "123p456p789s11z23s".parse().unwrap();
+    let mut context = WinContext::new(hand, parse_tiles("1s").unwrap()[0], from);
+    context.seat = seat;
+    context.seat_wind = [Wind::East, Wind::South, Wind::West][seat as usize];
+    context.player_count = 3;
+    context
+}
+
+/// 能拔北就拔北，否则摸切，不和牌也不鸣牌
+fn kita_happy(actions: &[Action]) -> Action {
+    let find = |pred: fn(&Action) -> bool| actions.iter().copied().find(pred);
+    find(|a| *a == Action::Kita)
+        .or_else(|| find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. })))
+        .unwrap_or(actions[actions.len() - 1])
+}
+
+fn sanma_game(seed: u64) -> GameState {
+    let rules: Arc<dyn RuleSet> = Arc::new(SanmaRules::default());
+    GameState::with_rules(GameConfig::from_rules(rules.as_ref(), seed), rules).unwrap()
+}
+
+/// 测试三麻牌墙：108张，没有二万至八万
+#[test]
+fn test_sanma_wall() {
+    let tiles = builder::build_tiles(WallConfig::Sanma).unwrap();
+    assert_eq!(tiles.len(), 108);
+    assert!(!tiles.iter().any(|t| matches!(t.suit_and_number(), Some((Suit::Character, 2..=8)))));
+    assert_eq!(tiles.iter().filter(|&&t| t == Tile::Suit(Suit::Character, 9)).count(), 4);
+
+    // 拔北补牌后岭上牌区仍有14张，海底前移一张
+    let dead_wall = DeadWallConfig::Riichi { dora_indicators: 5, uradora_indicators: 5 };
+    let mut wall = Wall::new(WallConfig::Sanma, Some(dead_wall), Some(7)).unwrap();
+    wall.start_game();
+    assert_eq!(wall.remaining_tiles(), 94);
+    let indicators: Vec<Tile> = wall.get_dora_indicators().unwrap().into_iter().copied().collect();
+    wall.draw_kita_replacement().unwrap();
+    assert_eq!(wall.remaining_tiles(), 93);
+    assert_eq!(wall.dead_wall().unwrap().get_all_tiles().len(), 14);
+    let after: Vec<Tile> = wall.get_dora_indicators().unwrap().into_iter().copied().collect();
+    assert_eq!(after, indicators);
+}
+
+/// 测试拔北宝牌和三麻的一万指示九万
+#[test]
+fn test_nuki_dora() {
+    let hand = hand_with_nuki("123p456p789s99m23s");
+    assert_eq!(hand.nuki(), [NORTH]);
+    assert_eq!(hand.concealed_tiles().len(), 13);
+
+    let mut context = WinContext::new(hand, parse_tiles("1s").unwrap()[0], Some(0));
+    context.riichi = true;
+    context.player_count = 3;
+    context.dora_indicators = parse_tiles("1m3z").unwrap();
+    let result = evaluate_yaku(&context, &YakuOptions::default()).unwrap();
+    // 两张九万和拔出的北各算宝牌，北另算一张拔北宝牌
+    assert_eq!((result.dora, result.nuki_dora), (3, 1));
+
+    context.player_count = 4;
+    let result = evaluate_yaku(&context, &YakuOptions::default()).unwrap();
+    assert_eq!((result.dora, result.nuki_dora), (1, 1));
+}
+
+/// 测试自摸时缺少北家支付的两种处理方式，荣和与四人麻将相同
+#[test]
+fn test_sanma_payments() {
+    use TsumoPayment::{NorthBisection, TsumoLoss};
+
+    // 庄家满贯
+    assert_eq!(sanma_payments(&context(0, None), 2000, TsumoLoss), [8000, -4000, -4000]);
+    assert_eq!(sanma_payments(&context(0, None), 2000, NorthBisection), [12000, -6000, -6000]);
+    assert_eq!(sanma_payments(&context(0, Some(2)), 2000, TsumoLoss), [12000, 0, -12000]);
+
+    // 子家满贯：庄家付一半，另一家付四分之一
+    assert_eq!(sanma_payments(&context(1, None), 2000, TsumoLoss), [-4000, 6000, -2000]);
+    assert_eq!(sanma_payments(&context(1, None), 2000, NorthBisection), [-5000, 8000, -3000]);
+    assert_eq!(sanma_payments(&context(1, Some(0)), 2000, NorthBisection), [-8000, 8000, 0]);
+
+    // 本场棒：自摸损每家100点，北家折半每家150点
+    let mut honba = context(2, None);
+    honba.honba = 2;
+    assert_eq!(sanma_payments(&honba, 320, TsumoLoss), [-900, -600, 1500]);
+    assert_eq!(sanma_payments(&honba, 320, NorthBisection), [-1100, -800, 1900]);
+    assert_eq!(SanmaRules::default().tsumo_payment, TsumoLoss);
+}
+
+/// 测试三人对局：没有吃，拔北后从岭上牌区补牌，结算在三家之间进行
+#[test]
+fn test_sanma_round() {
+    let mut kita = 0;
+    let mut record = Match::new(MatchConfig::sanma()).unwrap();
+    for seed in 0..3 {
+        let mut game = sanma_game(seed);
+        assert_eq!(game.players().len(), 3);
+        let mut events = Vec::new();
+        while game.phase() != Phase::RoundEnd {
+            let (seat, actions) = next_actor(&game);
+            assert!(!actions.iter().any(|a| matches!(a, Action::Chi { .. })));
+            let action = kita_happy(&actions);
+            let (remaining, nuki) = (game.wall().remaining_tiles(), game.player(seat).unwrap().hand().nuki().len());
+            let new_events = game.apply(seat, action).unwrap();
+            if action == Action::Kita && game.phase() != Phase::RoundEnd {
+                assert_eq!(new_events[0], Event::KitaDeclared { seat });
+                assert!(matches!(new_events[1], Event::DrewReplacement { seat: s, .. } if s == seat));
+                assert_eq!(game.wall().remaining_tiles(), remaining - 1);
+                assert_eq!(game.player(seat).unwrap().hand().nuki().len(), nuki + 1);
+                kita += 1;
+            }
+            assert_eq!(game.wall().dead_wall().unwrap().get_all_tiles().len(), 14);
+            events.extend(new_events);
+        }
+        assert_eq!(game.point_changes().len(), 3);
+        assert_eq!(game.point_changes().iter().sum::<i32>(), 0);
+        record.record_round(&RoundResult::from_game(&game, game.point_changes().to_vec()).unwrap()).unwrap();
+    }
+    assert!(kita > 0);
+    assert_eq!(record.points().len(), 3);
+    assert_eq!(record.points().iter().sum::<i32>(), 105000 - 1000 * record.riichi_sticks() as i32);
+}
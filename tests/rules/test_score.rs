@@ -0,0 +1,141 @@
+// tests/rules/test_score.rs
+//
+// 立直麻将符数与点数支付的测试用例
+// 覆盖各项符、进位、封顶等级、切上满贯与累计役满选项、庄闲和本场棒的支付
+
+use majiang_core::game::WinContext;
+use majiang_core::hand::{parse_tiles, Hand};
+use majiang_core::rules::riichi::{base_points, score_hand, Limit, ScoreBreakdown, ScoreOptions, YakuOptions};
+use majiang_core::rules::ScoreItem;
+use majiang_core::tile::Wind;
+
+use crate::helpers;
+
+/// 南家(座位1)的和牌场况，荣和时放铳者为座位0(庄家)
+fn context(hand: &str, win: &str, tsumo: bool) -> WinContext {
+    let mut context = helpers::context(hand, win, tsumo);
+    context.from = context.from.and(Some(0));
+    context.seat = 1;
+    context.seat_wind = Wind::South;
+    context
+}
+
+fn score(context: &WinContext) -> ScoreBreakdown {
+    score_hand(context, &YakuOptions::default(), &ScoreOptions::default()).unwrap()
+}
+
+/// 测试平和的固定符数：自摸20符，荣和30符
+#[test]
+fn test_pinfu_fu() {
+    let tsumo = score(&context("123m456p789s23m55p", "4m", true));
+    assert_eq!((tsumo.han, tsumo.fu), (2, 20));
+    assert_eq!(tsumo.fu_items, [ScoreItem::new("副底", 20)]);
+    // 基本点320：庄家支付700，闲家各支付400
+    assert_eq!(tsumo.payments, [-700, 1500, -400, -400]);
+
+    let ron = score(&context("123m456p789s23m55p", "4m", false));
+    assert_eq!((ron.han, ron.fu), (1, 30));
+    assert_eq!(ron.payments, [-1000, 1000, 0, 0]);
+}
+
+/// 测试听牌形状和面子的符，以及进位
+#[test]
+fn test_wait_and_meld_fu() {
+    // 20 + 门前清荣和10 + 嵌张2 + 暗刻白8 = 40符
+    let closed = score(&context("123m789p11s35m555z", "4m", false));
+    assert_eq!(closed.fu, 40);
+    assert!(closed.fu_items.contains(&ScoreItem::new("嵌张", 2)));
+    assert!(closed.fu_items.contains(&ScoreItem::new("暗刻白", 8)));
+    assert_eq!(closed.payments[1], 1300);
+
+    // 20 + 嵌张2 + 明刻白4 = 26符，进位为30符
+    let open = score(&context("[555z]123m789p11s35m", "4m", false));
+    assert_eq!((open.han, open.fu), (1, 30));
+    assert_eq!(open.payments[1], 1000);
+
+    // 荣和完成的刻子按明刻计算
+    let shanpon = score(&context("234m567p22s44s555z", "4s", false));
+    assert!(shanpon.fu_items.contains(&ScoreItem::new("明刻4条", 2)));
+}
+
+/// 测试副露平和形荣和按30符计算
+#[test]
+fn test_open_pinfu_shape() {
+    let result = score(&context("[234m]456p678s23p55s", "4p", false));
+    assert_eq!(result.fu, 30);
+    assert_eq!(result.fu_items.last(), Some(&ScoreItem::new("副露平和形", 10)));
+}
+
+/// 测试杠子和连风雀头的符
+#[test]
+fn test_kan_and_double_wind_fu() {
+    // 庄家暗杠东：20 + 门前清荣和10 + 暗杠东32 = 62符，进位为70符
+    let hand: Hand = "(1111z)123m456p78s99s".parse().unwrap();
+    let context_east = WinContext::new(hand, parse_tiles("6s").unwrap()[0], Some(2));
+    let result = score(&context_east);
+    assert_eq!((result.han, result.fu), (2, 70));
+    assert!(result.fu_items.contains(&ScoreItem::new("暗杠东", 32)));
+    // 庄家荣和：基本点1120 × 6 = 6720，进位为6800
+    assert_eq!(result.payments, [6800, 0, -6800, 0]);
+
+    let hand: Hand = "123m456p789s24m11z".parse().unwrap();
+    let mut double_wind = WinContext::new(hand, parse_tiles("3m").unwrap()[0], None);
+    double_wind.riichi = true;
+    assert!(score(&double_wind).fu_items.contains(&ScoreItem::new("雀头东", 4)));
+    let options = ScoreOptions { double_wind_pair_4_fu: false, ..ScoreOptions::default() };
+    let result = score_hand(&double_wind, &YakuOptions::default(), &options).unwrap();
+    assert!(result.fu_items.contains(&ScoreItem::new("雀头东", 2)));
+}
+
+/// 测试七对子固定25符
+#[test]
+fn test_chiitoitsu_fu() {
+    let result = score(&context("1133m2255p88s66z7z", "7z", false));
+    assert_eq!((result.han, result.fu), (2, 25));
+    assert_eq!(result.payments[1], 1600);
+}
+
+/// 测试封顶等级与切上满贯、累计役满选项
+#[test]
+fn test_limits() {
+    let options = ScoreOptions::default();
+    assert_eq!(base_points(1, 30, &options), (None, 240));
+    assert_eq!(base_points(4, 30, &options), (None, 1920));
+    assert_eq!(base_points(3, 70, &options), (Some(Limit::Mangan), 2000));
+    assert_eq!(base_points(5, 20, &options), (Some(Limit::Mangan), 2000));
+    assert_eq!(base_points(7, 30, &options), (Some(Limit::Haneman), 3000));
+    assert_eq!(base_points(10, 30, &options), (Some(Limit::Baiman), 4000));
+    assert_eq!(base_points(12, 30, &options), (Some(Limit::Sanbaiman), 6000));
+    assert_eq!(base_points(13, 30, &options), (Some(Limit::KazoeYakuman), 8000));
+
+    let kiriage = ScoreOptions { kiriage_mangan: true, counted_yakuman: false, ..options };
+    assert_eq!(base_points(4, 30, &kiriage), (Some(Limit::Mangan), 2000));
+    assert_eq!(base_points(3, 60, &kiriage), (Some(Limit::Mangan), 2000));
+    assert_eq!(base_points(14, 30, &kiriage), (Some(Limit::Sanbaiman), 6000));
+}
+
+/// 测试役满的支付：庄家自摸每家16000，闲家荣和双倍役满64000
+#[test]
+fn test_yakuman_payments() {
+    let hand: Hand = "555z666z777z123m4p".parse().unwrap();
+    let dealer = WinContext::new(hand, parse_tiles("4p").unwrap()[0], None);
+    let result = score(&dealer);
+    assert_eq!(result.limit, Some(Limit::Yakuman(1)));
+    assert_eq!(result.payments, [48000, -16000, -16000, -16000]);
+
+    let result = score(&context("111m222p333s444s5m", "5m", false));
+    assert_eq!(result.limit, Some(Limit::Yakuman(2)));
+    assert_eq!(result.payments, [-64000, 64000, 0, 0]);
+}
+
+/// 测试本场棒：荣和每本300点由放铳者支付，自摸时各家每本100点
+#[test]
+fn test_honba() {
+    let mut ron = context("123m456p789s23m55p", "4m", false);
+    ron.honba = 2;
+    assert_eq!(score(&ron).payments, [-1600, 1600, 0, 0]);
+
+    let mut tsumo = context("123m456p789s23m55p", "4m", true);
+    tsumo.honba = 1;
+    assert_eq!(score(&tsumo).payments, [-800, 1800, -500, -500]);
+}
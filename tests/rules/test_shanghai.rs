@@ -0,0 +1,164 @@
+// tests/rules/test_shanghai.rs
+//
+// 上海麻将规则的测试用例
+// 覆盖花的计算、大胡、百搭替代、起和条件、勒子封顶和敲
+
+use std::sync::Arc;
+
+use majiang_core::action::Action;
+use majiang_core::game::{GameConfig, GameState, Phase, RoundOutcome};
+use majiang_core::rules::common::BlockKind;
+use majiang_core::rules::shanghai::{evaluate_hua, payments, BigWin, Hua, HuaResult};
+use majiang_core::rules::{RuleSet, ScoreItem, ShanghaiRules};
+use majiang_core::tile::{Suit, Tile};
+
+use crate::helpers::{context, next_actor};
+
+/// 不使用百搭的规则
+const PLAIN: ShanghaiRules = ShanghaiRules { with_joker: false, cap: 50, knocked: false };
+
+fn evaluate(hand: &str, win: &str, tsumo: bool) -> HuaResult {
+    evaluate_hua(&context(hand, win, tsumo), &PLAIN).unwrap()
+}
+
+/// 测试花的计算：底花、花牌、字牌刻子和杠子
+#[test]
+fn test_hua_counting() {
+    let result = evaluate("[111z]222z123m456p7s12f", "7s", false);
+    assert_eq!(result.hua, [(Hua::Base, 1), (Hua::Flower, 2), (Hua::ConcealedHonorPung, 1), (Hua::HonorPung, 1)]);
+    assert_eq!(result.total_hua(), 7);
+    assert!(result.big_wins.is_empty());
+    assert_eq!(result.value(), 7);
+
+    // 荣和完成的字牌刻子算明刻
+    let result = evaluate("[111z]22z123m456p77s", "2z", false);
+    assert_eq!(result.hua, [(Hua::Base, 1), (Hua::HonorPung, 2)]);
+
+    let result = evaluate("(5555z)[7777m]123p456s1z", "1z", false);
+    assert_eq!(result.hua, [(Hua::Base, 1), (Hua::ConcealedHonorKong, 1), (Hua::Kong, 1)]);
+    assert_eq!(result.total_hua(), 7);
+}
+
+/// 测试大胡：每项翻一番
+#[test]
+fn test_big_wins() {
+    let result = evaluate("1112345678999m", "5m", false);
+    assert_eq!(result.big_wins, [BigWin::FullFlush, BigWin::ConcealedHand]);
+    assert_eq!(result.value(), 8);
+
+    let result = evaluate("[111m][999m]222m555z7z", "7z", false);
+    assert_eq!(result.big_wins, [BigWin::HalfFlush, BigWin::AllPungs]);
+    assert_eq!(result.value(), 4 << 2);
+
+    let result = evaluate("1133m5577p99s114z", "4z", true);
+    assert_eq!(result.big_wins, [BigWin::SevenPairs, BigWin::ConcealedHand]);
+
+    let result = evaluate("[123m][456p][789s][111z]5z", "5z", false);
+    assert_eq!(result.big_wins, [BigWin::AllMelded]);
+    assert_eq!(result.value(), 3 << 1);
+
+    // 十三幺不是上海麻将的和牌形
+    assert!(evaluate_hua(&context("19m19p19s1234567z", "1z", false), &PLAIN).is_none());
+}
+
+/// 测试杠开、海底捞月和抢杠
+#[test]
+fn test_situational_wins() {
+    let mut rinshan = context("[111z]222z123m456p7s", "7s", true);
+    rinshan.rinshan = true;
+    assert!(evaluate_hua(&rinshan, &PLAIN).unwrap().big_wins.contains(&BigWin::KongBloom));
+
+    let mut haitei = context("[111z]222z123m456p7s", "7s", true);
+    haitei.haitei = true;
+    assert!(evaluate_hua(&haitei, &PLAIN).unwrap().big_wins.contains(&BigWin::LastTileDraw));
+
+    let mut chankan = context("[111z]222z123m456p7s", "7s", false);
+    chankan.chankan = true;
+    assert_eq!(evaluate_hua(&chankan, &PLAIN).unwrap().big_wins, [BigWin::RobbingKong]);
+}
+
+/// 测试百搭：可以代替任意一张牌，打出的百搭不能和，没有百搭时计无百搭
+#[test]
+fn test_joker() {
+    let rules = ShanghaiRules::default();
+    let result = evaluate_hua(&context("12m0z456p789s1112z", "2z", false), &rules).unwrap();
+    assert!(result.pattern.blocks.iter().any(|b| b.kind == BlockKind::Sequence && b.tile == Tile::Suit(Suit::Character, 1)));
+    assert!(!result.big_wins.contains(&BigWin::NoJoker));
+
+    // 摸到百搭可以自摸，别人打出的百搭不能荣和
+    let hand = "123m456p789s1112z";
+    assert!(rules.score_win(&context(hand, "0z", true)).is_some());
+    assert!(rules.score_win(&context(hand, "0z", false)).is_none());
+    assert!(!rules.is_valid_win(&context(hand, "0z", false)));
+
+    // 百搭代替的牌参与清一色的判定
+    let result = evaluate_hua(&context("111234567899m0z", "9m", false), &rules).unwrap();
+    assert!(result.big_wins.contains(&BigWin::FullFlush));
+
+    let result = evaluate_hua(&context(hand, "2z", false), &rules).unwrap();
+    assert!(result.big_wins.contains(&BigWin::NoJoker));
+}
+
+/// 测试起和条件：没有大胡时除底花外至少要有一花，门清和无百搭不算
+#[test]
+fn test_minimum_hua() {
+    let result = evaluate("[123m]456p789s234s5s", "5s", false);
+    assert_eq!(result.total_hua(), 2);
+    assert!(!result.is_valid());
+    assert!(PLAIN.score_win(&context("[123m]456p789s234s5s", "5s", false)).is_none());
+
+    let score = PLAIN.score_win(&context("[123m]456p789s234s5s1f", "5s", false)).unwrap();
+    assert_eq!(score.items, [ScoreItem::new("底花", 2), ScoreItem::new("花牌", 1)]);
+    assert_eq!(score.payments, [3, -3, 0, 0]);
+
+    // 默认规则下门清且无百搭的平和只有底花，不能和牌
+    let rules = ShanghaiRules::default();
+    let result = evaluate_hua(&context("123m456p789s234s5s", "5s", false), &rules).unwrap();
+    assert_eq!(result.big_wins, [BigWin::ConcealedHand, BigWin::NoJoker]);
+    assert!(!result.is_valid());
+    assert!(!rules.is_valid_win(&context("123m456p789s234s5s", "5s", false)));
+
+    // 有一花后可以和牌，门清和无百搭照常翻番
+    let score = rules.score_win(&context("123m456p789s234s5s1f", "5s", false)).unwrap();
+    assert_eq!(score.payments, [3 << 2, -(3 << 2), 0, 0]);
+}
+
+/// 测试点数支付：放铳者一家付，自摸三家付，每家以勒子封顶，敲时翻倍
+#[test]
+fn test_payments_cap_and_knock() {
+    let ron = context("1112345678999m", "5m", false);
+    let tsumo = context("1112345678999m", "5m", true);
+    assert_eq!(payments(&ron, 8, &PLAIN), [8, -8, 0, 0]);
+    assert_eq!(payments(&tsumo, 8, &PLAIN), [24, -8, -8, -8]);
+    assert_eq!(payments(&ron, 64, &PLAIN), [50, -50, 0, 0]);
+
+    let knocked = PLAIN.next_round(&RoundOutcome::ExhaustiveDraw { tenpai: vec![] });
+    assert!(knocked.knocked);
+    assert_eq!(payments(&ron, 64, &knocked), [100, -100, 0, 0]);
+    assert_eq!(payments(&tsumo, 8, &knocked), [48, -16, -16, -16]);
+
+    let win = RoundOutcome::Win { winners: vec![0], from: None, tile: Tile::Suit(Suit::Character, 5) };
+    assert!(!knocked.next_round(&win).knocked);
+}
+
+/// 测试按上海麻将规则打完整局：和牌时点数变化之和为零
+#[test]
+fn test_full_rounds() {
+    for seed in 0..6 {
+        let rules: Arc<dyn RuleSet> = Arc::new(ShanghaiRules::default());
+        let mut game = GameState::with_rules(GameConfig::from_rules(rules.as_ref(), seed), rules).unwrap();
+        while game.phase() != Phase::RoundEnd {
+            let (seat, actions) = next_actor(&game);
+            let find = |pred: fn(&Action) -> bool| actions.iter().copied().find(pred);
+            let action = find(|a| a.is_win())
+                .or_else(|| find(|a| matches!(a, Action::Pon { .. } | Action::DeclareFlower { .. })))
+                .or_else(|| find(|a| matches!(a, Action::Draw | Action::Pass | Action::Discard { tsumogiri: true, .. })))
+                .unwrap_or(actions[actions.len() - 1]);
+            game.apply(seat, action).unwrap();
+        }
+        assert_eq!(game.point_changes().iter().sum::<i32>(), 0);
+        for score in game.win_scores() {
+            assert!(score.items.iter().any(|item| item.name == "底花"));
+        }
+    }
+}
@@ -0,0 +1,195 @@
+// tests/rules/test_sichuan.rs
+//
+// 四川麻将(血战到底)的测试用例
+// 覆盖108张牌墙、换三张、定缺的限制、番型与支付、刮风下雨、查花猪查大叫，以及和牌后继续的对局
+
+use std::sync::Arc;
+
+use majiang_core::action::{legal_actions, Action, PlayerView, Situation};
+use majiang_core::game::{Event, GameConfig, GameState, Phase, RoundOutcome};
+use majiang_core::hand::{shanten, Hand};
+use majiang_core::meld::KanType;
+use majiang_core::rules::sichuan::{
+    draw_payments, evaluate_fan, exchange_candidates, is_flower_pig, kan_payments, DrawStatus, ExchangeDirection, Fan,
+};
+use majiang_core::rules::{RuleSet, SichuanRules};
+use majiang_core::tile::{Suit, Tile};
+use majiang_core::wall::{builder, WallConfig};
+
+use crate::helpers::{context, next_actor, tile};
+
+fn sichuan_game(seed: u64) -> GameState {
+    let rules: Arc<dyn RuleSet> = Arc::new(SichuanRules::default());
+    GameState::with_rules(GameConfig::from_rules(rules.as_ref(), seed), rules).unwrap()
+}
+
+/// 能和就和；换出第一组候选，定缺最少的花色；打牌时选向听数最小的牌
+fn greedy(hand: &Hand, actions: &[Action]) -> Action {
+    if let Some(&win) = actions.iter().find(|a| a.is_win()) {
+        return win;
+    }
+    let suit_count = |suit: Suit| {
+        hand.all_concealed_tiles().iter().filter(|t| matches!(t.suit_and_number(), Some((s, _)) if s == suit)).count()
+    };
+    let voids = actions.iter().filter_map(|&a| match a {
+        Action::ChooseVoid { suit } => Some((suit_count(suit), a)),
+        _ => None,
+    });
+    if let Some((_, void)) = voids.min_by_key(|&(count, _)| count) {
+        return void;
+    }
+    let discards = actions.iter().filter_map(|&a| match a {
+        Action::Discard { tile, .. } => {
+            let mut after = hand.clone();
+            after.discard(tile).unwrap();
+            Some((shanten(&after), a))
+        }
+        _ => None,
+    });
+    discards.min_by_key(|&(s, _)| s).map(|(_, a)| a).unwrap_or(actions[actions.len() - 1])
+}
+
+/// 测试四川麻将牌墙：108张数牌，没有字牌和花牌
+#[test]
+fn test_sichuan_wall() {
+    let tiles = builder::build_tiles(WallConfig::Sichuan).unwrap();
+    assert_eq!(tiles.len(), 108);
+    assert!(tiles.iter().all(|t| t.suit_and_number().is_some()));
+    assert_eq!(tiles.iter().filter(|&&t| t == Tile::Suit(Suit::Bamboo, 5)).count(), 4);
+    assert!(SichuanRules::default().dead_wall_config().is_none());
+}
+
+/// 测试换三张的候选与方向，以及对局中同时交换
+#[test]
+fn test_tile_exchange() {
+    let hand: Hand = "1112m3p456789s".parse().unwrap();
+    let candidates = exchange_candidates(&hand);
+    // 万子4张取3张(2种)，条子6张取3张(20种)，筒子不足3张
+    assert_eq!(candidates.len(), 22);
+    assert!(candidates.iter().all(|c| c[0].suit_and_number().unwrap().0 != Suit::Dot));
+
+    assert_eq!(ExchangeDirection::ToOpposite.recipient(0, 4), 2);
+    assert_eq!(ExchangeDirection::ToPrevious.recipient(2, 4), 1);
+
+    let mut game = sichuan_game(3);
+    game.apply(0, Action::Draw).unwrap();
+    assert_eq!(game.phase(), Phase::Exchanging);
+    assert_eq!(game.legal_actions(0).first().map(|a| matches!(a, Action::ExchangeTiles { .. })), Some(true));
+    let mut chosen = Vec::new();
+    let mut events = Vec::new();
+    for seat in 0..4 {
+        let Action::ExchangeTiles { tiles } = game.legal_actions(seat)[0] else { panic!("应当换三张") };
+        chosen.push(tiles);
+        events = game.apply(seat, Action::ExchangeTiles { tiles }).unwrap();
+        if seat < 3 {
+            // 选好后等待其他玩家，不能再选
+            assert!(events.is_empty() && game.legal_actions(seat).is_empty());
+        }
+    }
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[0], Event::TilesExchanged { from: 0, to: 1, tiles: chosen[0].to_vec() });
+    assert_eq!(game.phase(), Phase::ChoosingVoid);
+    assert!(game.players().iter().all(|p| p.hand().concealed_count() == 13));
+
+    for seat in 0..4 {
+        assert_eq!(game.legal_actions(seat).len(), 3);
+        events = game.apply(seat, Action::ChooseVoid { suit: Suit::Bamboo }).unwrap();
+    }
+    assert_eq!(events[3], Event::VoidChosen { seat: 3, suit: Suit::Bamboo });
+    assert!(game.players().iter().all(|p| p.void_suit() == Some(Suit::Bamboo)));
+    assert_eq!((game.phase(), game.legal_actions(0)), (Phase::WaitingDiscard, vec![Action::Draw]));
+}
+
+/// 测试定缺：不能碰、杠、和缺门的牌，手中还有缺门的牌时不能和牌
+#[test]
+fn test_void_suit_restrictions() {
+    let hand: Hand = "222s456m789m1133p".parse().unwrap();
+    let mut view = PlayerView::new(0, &hand, Situation::Discard { tile: tile("2s"), from: 1 }, 30);
+    assert!(legal_actions(&view).iter().any(|a| matches!(a, Action::Pon { .. })));
+    view.void_suit = Some(Suit::Bamboo);
+    assert_eq!(legal_actions(&view), [Action::Pass]);
+
+    // 听1p3p，但手中还有缺门的条子
+    view.situation = Situation::Discard { tile: tile("1p"), from: 1 };
+    assert!(!legal_actions(&view).iter().any(|a| a.is_win()));
+    view.void_suit = Some(Suit::Dot);
+    assert!(!legal_actions(&view).iter().any(|a| a.is_win()));
+    view.void_suit = None;
+    assert!(legal_actions(&view).iter().any(|a| a.is_win()));
+
+    assert!(is_flower_pig(&hand, Some(Suit::Bamboo)));
+    assert!(is_flower_pig(&hand, None));
+}
+
+/// 测试番型和支付：点炮由放铳者支付，自摸时其他玩家各付得分加一底
+#[test]
+fn test_fan_and_payments() {
+    let result = evaluate_fan(&context("1122334455667m", "7m", false)).unwrap();
+    assert_eq!(result.fans, [(Fan::FullFlush, 1), (Fan::SevenPairs, 1)]);
+
+    let result = evaluate_fan(&context("111m123m456p789p5p", "5p", false)).unwrap();
+    assert_eq!(result.fans, [(Fan::Root, 1)]);
+
+    let rules = SichuanRules::default();
+    let score = rules.score_win(&context("111m123m456p789p5p", "5p", false)).unwrap();
+    assert_eq!(score.payments, [2, -2, 0, 0]);
+    let score = rules.score_win(&context("111m123m456p789p5p", "5p", true)).unwrap();
+    assert_eq!(score.payments, [9, -3, -3, -3]);
+
+    // 平胡
+    let score = rules.score_win(&context("123m456m789p1155p", "5p", false)).unwrap();
+    assert_eq!((score.items[0].name.as_str(), score.payments[0]), ("平胡", 1));
+
+    // 三门都有不能和牌
+    assert!(rules.score_win(&context("123m456p789s1155p", "5p", false)).is_none());
+}
+
+/// 测试刮风下雨和荒牌时的查花猪、查大叫
+#[test]
+fn test_kan_and_draw_payments() {
+    let all = [true; 4];
+    assert_eq!(kan_payments(1, KanType::Open, Some(2), &all, 2), [0, 4, -4, 0]);
+    assert_eq!(kan_payments(1, KanType::Added, None, &all, 1), [-1, 3, -1, -1]);
+    assert_eq!(kan_payments(1, KanType::Closed, None, &all, 1), [-2, 6, -2, -2]);
+
+    let statuses = [DrawStatus::Ready { value: 8 }, DrawStatus::NotReady, DrawStatus::NotReady, DrawStatus::Ready { value: 2 }];
+    assert_eq!(draw_payments(&statuses, 16), [16, -10, -10, 4]);
+
+    let statuses = [DrawStatus::FlowerPig, DrawStatus::Won, DrawStatus::Ready { value: 1 }, DrawStatus::FlowerPig];
+    assert_eq!(draw_payments(&statuses, 16), [-16, 0, 32, -16]);
+}
+
+/// 测试血战到底：和牌者退出后本局继续，直到三家和牌或荒牌，点数变化之和为零
+#[test]
+fn test_bloody_battle_round() {
+    let mut continued = 0;
+    for seed in 0..4 {
+        let mut game = sichuan_game(seed);
+        let mut events = Vec::new();
+        while game.phase() != Phase::RoundEnd {
+            let (seat, actions) = next_actor(&game);
+            assert!(!game.player(seat).unwrap().has_won());
+            let action = greedy(game.player(seat).unwrap().hand(), &actions);
+            let new_events = game.apply(seat, action).unwrap();
+            if action.is_win() && game.phase() != Phase::RoundEnd {
+                continued += 1;
+            }
+            events.extend(new_events);
+        }
+        assert_eq!(events.iter().filter(|e| matches!(e, Event::TilesExchanged { .. })).count(), 4);
+        assert_eq!(events.iter().filter(|e| matches!(e, Event::VoidChosen { .. })).count(), 4);
+
+        let won: Vec<u8> = events
+            .iter()
+            .filter_map(|e| if let Event::Won { seat, .. } = e { Some(*seat) } else { None })
+            .collect();
+        assert_eq!(game.win_contexts().iter().map(|c| c.seat).collect::<Vec<_>>(), won);
+        match game.outcome().unwrap() {
+            RoundOutcome::Win { winners, .. } => assert_eq!((winners, winners.len()), (&won, 3)),
+            RoundOutcome::ExhaustiveDraw { tenpai } => assert!(tenpai.iter().all(|seat| !won.contains(seat))),
+            RoundOutcome::AbortiveDraw(kind) => panic!("四川麻将没有途中流局: {:?}", kind),
+        }
+        assert_eq!(game.point_changes().iter().sum::<i32>(), 0);
+    }
+    assert!(continued > 0);
+}
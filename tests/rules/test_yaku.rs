@@ -0,0 +1,133 @@
+// tests/rules/test_yaku.rs
+//
+// 立直麻将役种判定的测试用例
+// 覆盖门前役、副露减番、多种拆分取最高、宝牌计数和役满
+
+use majiang_core::game::WinContext;
+use majiang_core::hand::parse_tiles;
+use majiang_core::rules::riichi::{evaluate_yaku, RiichiRules, Yaku, YakuOptions, YakuResult};
+use majiang_core::rules::{RuleSet, ScoreItem};
+
+use crate::helpers::context;
+
+fn evaluate(context: &WinContext) -> Option<YakuResult> {
+    evaluate_yaku(context, &YakuOptions::default())
+}
+
+fn yaku(hand: &str, win: &str, tsumo: bool) -> Vec<(Yaku, u8)> {
+    evaluate(&context(hand, win, tsumo)).map(|r| r.yaku).unwrap_or_default()
+}
+
+/// 测试平和与没有役的手牌
+#[test]
+fn test_pinfu_and_no_yaku() {
+    assert_eq!(yaku("123m456p789s23m55p", "4m", false), [(Yaku::Pinfu, 1)]);
+    // 嵌张荣和没有役，自摸只有门前清自摸和
+    assert!(yaku("123m456p789s24m55p", "3m", false).is_empty());
+    assert_eq!(yaku("123m456p789s24m55p", "3m", true), [(Yaku::MenzenTsumo, 1)]);
+    // 副露后没有役
+    assert!(yaku("[123m]456p789s23m55p", "4m", false).is_empty());
+}
+
+/// 测试副露时一气通贯和混一色减一番，役牌刻子按自风和场风分别计算
+#[test]
+fn test_open_hand_reduction() {
+    let result = yaku("[123m][456m]789m11z22z", "1z", false);
+    assert_eq!(result, [(Yaku::SeatWind, 1), (Yaku::RoundWind, 1), (Yaku::Ittsu, 1), (Yaku::Honitsu, 2)]);
+
+    let closed = yaku("123456789m11z22z", "1z", false);
+    assert!(closed.contains(&(Yaku::Ittsu, 2)) && closed.contains(&(Yaku::Honitsu, 3)));
+
+    let mut south = context("[123m][456m]789m11z22z", "1z", false);
+    south.seat_wind = majiang_core::tile::Wind::South;
+    let result = evaluate(&south).unwrap();
+    assert_eq!(result.yaku[0], (Yaku::RoundWind, 1));
+    assert_eq!(result.han(), 4);
+}
+
+/// 测试不允许食断时副露的断幺九不成立
+#[test]
+fn test_open_tanyao_option() {
+    let context = context("[234m]567p345s66s22p", "2p", false);
+    assert_eq!(evaluate(&context).unwrap().yaku, [(Yaku::Tanyao, 1)]);
+    let options = YakuOptions { open_tanyao: false, ..YakuOptions::default() };
+    assert!(evaluate_yaku(&context, &options).is_none());
+}
+
+/// 测试多种拆分时取番数最高的一种
+#[test]
+fn test_best_decomposition() {
+    // 三暗刻(2番)优于一杯口(1番)
+    assert_eq!(yaku("111222333m456p7s", "7s", false), [(Yaku::Sanankou, 2)]);
+    // 两杯口(3番)优于七对子(2番)
+    assert_eq!(yaku("112233m556677p8s", "8s", false), [(Yaku::Ryanpeikou, 3)]);
+    // 荣和完成的刻子是明刻：对对和加三暗刻，不是四暗刻
+    assert_eq!(yaku("111m222p333s44s55m", "5m", false), [(Yaku::Toitoi, 2), (Yaku::Sanankou, 2)]);
+}
+
+/// 测试混全带幺九与纯全带幺九
+#[test]
+fn test_outside_hands() {
+    assert_eq!(yaku("123m789p123s999s1z", "1z", false), [(Yaku::Chanta, 2)]);
+    assert_eq!(yaku("123m789p123s999s1m", "1m", false), [(Yaku::Junchan, 3)]);
+    assert_eq!(yaku("[123m]789p123s999s1m", "1m", false), [(Yaku::Junchan, 2)]);
+}
+
+/// 测试宝牌、赤宝牌和里宝牌(里宝牌仅立直时计算)
+#[test]
+fn test_dora_counting() {
+    let mut chiitoitsu = context("1133m2255p88s66z7z", "7z", false);
+    chiitoitsu.dora_indicators = parse_tiles("4p").unwrap();
+    chiitoitsu.ura_dora_indicators = parse_tiles("7s").unwrap();
+    let result = evaluate(&chiitoitsu).unwrap();
+    assert_eq!(result.yaku, [(Yaku::Chiitoitsu, 2)]);
+    assert_eq!((result.dora, result.ura_dora), (2, 0));
+    assert_eq!(result.han(), 4);
+
+    chiitoitsu.riichi = true;
+    let result = evaluate(&chiitoitsu).unwrap();
+    assert_eq!((result.dora, result.ura_dora), (2, 2));
+    assert_eq!(result.han(), 7);
+
+    let red = evaluate(&context("234m0p67p345s2266s", "2s", false)).unwrap();
+    assert_eq!(red.aka_dora, 1);
+}
+
+/// 测试役满与双倍役满
+#[test]
+fn test_yakuman() {
+    assert_eq!(yaku("19m19p19s1234567z", "1m", true), [(Yaku::Kokushi, 2)]);
+    assert_eq!(yaku("19m19p19s1234566z", "7z", false), [(Yaku::Kokushi, 1)]);
+    assert_eq!(yaku("555z666z777z123m4p", "4p", false), [(Yaku::Daisangen, 1)]);
+    assert_eq!(yaku("111m222p333s444s5m", "5m", false), [(Yaku::Suuankou, 2)]);
+    assert_eq!(yaku("1112345678999m", "5m", false), [(Yaku::Chuuren, 2)]);
+    assert_eq!(yaku("1112345678999m", "1m", false), [(Yaku::Chuuren, 2)]);
+    assert_eq!(yaku("1112345678899m", "9m", true), [(Yaku::Chuuren, 1)]);
+    assert_eq!(yaku("223344666888s6z", "6z", false), [(Yaku::Ryuuiisou, 1)]);
+    assert_eq!(yaku("111222333z4555z", "4z", false), [(Yaku::Suuankou, 2), (Yaku::Shousuushii, 1), (Yaku::Tsuuiisou, 1)]);
+    assert_eq!(yaku("[1111z][2222z]333z444z5z", "5z", true), [(Yaku::Daisuushii, 2), (Yaku::Tsuuiisou, 1)]);
+
+    let single = YakuOptions { double_yakuman: false, ..YakuOptions::default() };
+    let kokushi = context("19m19p19s1234567z", "1m", true);
+    assert_eq!(evaluate_yaku(&kokushi, &single).unwrap().yaku, [(Yaku::Kokushi, 1)]);
+
+    let mut tenhou = context("123m456p789s23m55p", "4m", true);
+    tenhou.tenhou = true;
+    let result = evaluate(&tenhou).unwrap();
+    assert_eq!(result.yaku, [(Yaku::Tenhou, 1)]);
+    assert_eq!((result.yakuman(), result.han()), (1, 0));
+}
+
+/// 测试立直规则要求有役，并列出役种和宝牌
+#[test]
+fn test_riichi_rules_require_yaku() {
+    let rules = RiichiRules::default();
+    assert!(!rules.is_valid_win(&context("123m456p789s24m55p", "3m", false)));
+
+    let mut context = context("123m456p789s23m55p", "4m", false);
+    context.riichi = true;
+    context.dora_indicators = parse_tiles("4p").unwrap();
+    assert!(rules.is_valid_win(&context));
+    let score = rules.score_win(&context).unwrap();
+    assert_eq!(score.items, [ScoreItem::new("立直", 1), ScoreItem::new("平和", 1), ScoreItem::new("宝牌", 3)]);
+}
@@ -5,3 +5,5 @@
 // 导入各个测试模块
 mod test_create;
 mod test_serialization;
+mod test_red_five;
+mod test_physical;
@@ -161,6 +161,15 @@ fn test_tile_type_checks() {
     assert!(!Tile::Flower(Flower::Spring).is_honor());
     assert!(!Tile::Joker.is_honor());
     
+    // 测试is_terminal和is_terminal_or_honor方法(幺九牌)
+    assert!(Tile::Suit(Suit::Dot, 9).is_terminal());
+    assert!(!Tile::Wind(Wind::East).is_terminal());
+    assert!(Tile::Suit(Suit::Bamboo, 1).is_terminal_or_honor());
+    assert!(Tile::Dragon(Dragon::Green).is_terminal_or_honor());
+    assert!(!Tile::Suit(Suit::Character, 2).is_terminal_or_honor());
+    assert!(!Tile::Flower(Flower::Spring).is_terminal_or_honor());
+    assert!(!Tile::Joker.is_terminal_or_honor());
+    
     // 测试is_joker方法
     assert!(Tile::Joker.is_joker());
     assert!(!Tile::Suit(Suit::Character, 1).is_joker());
@@ -172,17 +181,15 @@ fn test_tile_type_checks() {
 /// 测试红宝牌(红五)判断
 #[test]
 fn test_red_tiles() {
-    // 五万是红五
-    let man5 = Tile::Suit(Suit::Character, 5);
-    assert!(man5.is_red());
-    
-    // 五筒是红五
-    let pin5 = Tile::Suit(Suit::Dot, 5);
-    assert!(pin5.is_red());
-    
-    // 五索是红五
-    let sou5 = Tile::Suit(Suit::Bamboo, 5);
-    assert!(sou5.is_red());
+    // 只有红五变体是红牌
+    assert!(Tile::new_red_five(Suit::Character).is_red());
+    assert!(Tile::new_red_five(Suit::Dot).is_red());
+    assert!(Tile::new_red_five(Suit::Bamboo).is_red());
+    
+    // 普通的五不是红牌
+    assert!(!Tile::Suit(Suit::Character, 5).is_red());
+    assert!(!Tile::Suit(Suit::Dot, 5).is_red());
+    assert!(!Tile::Suit(Suit::Bamboo, 5).is_red());
     
     // 其他五以外的数牌不是红牌
     assert!(!Tile::Suit(Suit::Character, 1).is_red());
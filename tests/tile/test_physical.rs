@@ -0,0 +1,93 @@
+// tests/tile/test_physical.rs
+//
+// 实体牌编号的测试用例
+// 验证实体编号与牌面之间的相互转换，以及天凤编码约定
+
+use majiang_core::tile::{
+    Tile, Suit, Wind, Dragon, Flower, PhysicalTile,
+    physical_to_tiles, physical_to_ids,
+};
+
+/// 测试数牌和字牌的实体编号为 种类ID*4+副本序号
+#[test]
+fn test_instance_id_encoding() {
+    let man1 = PhysicalTile::new(Tile::Suit(Suit::Character, 1), 0).unwrap();
+    assert_eq!(man1.id(), 0);
+    
+    let man3_copy2 = PhysicalTile::new(Tile::Suit(Suit::Character, 3), 2).unwrap();
+    assert_eq!(man3_copy2.id(), 10);
+    assert_eq!(man3_copy2.kind_id(), 2);
+    assert_eq!(man3_copy2.copy_index(), 2);
+    
+    let red_dragon = PhysicalTile::new(Tile::Dragon(Dragon::Red), 3).unwrap();
+    assert_eq!(red_dragon.id(), 135);
+    
+    // 花牌和百搭紧随其后
+    assert_eq!(PhysicalTile::new(Tile::Flower(Flower::Spring), 0).unwrap().id(), 136);
+    assert_eq!(PhysicalTile::new(Tile::Flower(Flower::Chrysanthemum), 0).unwrap().id(), 143);
+    assert_eq!(PhysicalTile::new(Tile::Joker, 0).unwrap().id(), 144);
+}
+
+/// 测试非法副本序号
+#[test]
+fn test_invalid_copy_index() {
+    assert!(PhysicalTile::new(Tile::Wind(Wind::East), 4).is_err());
+    assert!(PhysicalTile::new(Tile::Flower(Flower::Plum), 1).is_err());
+    assert!(PhysicalTile::new(Tile::Joker, 1).is_err());
+}
+
+/// 测试从实体编号还原实体牌
+#[test]
+fn test_from_instance_id() {
+    for id in 0..=144u8 {
+        let physical = PhysicalTile::from_instance_id(id, false).unwrap();
+        assert_eq!(physical.id(), id);
+        
+        // 重新编码得到同一编号
+        let again = PhysicalTile::new(physical.tile(), physical.copy_index()).unwrap();
+        assert_eq!(again, physical);
+    }
+    
+    assert!(PhysicalTile::from_instance_id(145, false).is_none());
+    assert!(PhysicalTile::from_instance_id(255, true).is_none());
+}
+
+/// 测试红五的实体编号(天凤约定16/52/88)
+#[test]
+fn test_red_five_instances() {
+    let red_ids = [16u8, 52, 88];
+    let suits = [Suit::Character, Suit::Dot, Suit::Bamboo];
+    
+    for (&id, &suit) in red_ids.iter().zip(suits.iter()) {
+        // 使用红五的牌组中，第0号副本还原为红五
+        let red = PhysicalTile::from_instance_id(id, true).unwrap();
+        assert!(red.is_red());
+        assert_eq!(red.tile(), Tile::new_red_five(suit));
+        
+        // 不使用红五的牌组中，同一编号是普通五
+        let normal = PhysicalTile::from_instance_id(id, false).unwrap();
+        assert!(!normal.is_red());
+        assert_eq!(normal.kind_id(), red.kind_id());
+        
+        // 其他副本永远不是红五
+        assert!(!PhysicalTile::from_instance_id(id + 1, true).unwrap().is_red());
+    }
+}
+
+/// 测试实体牌与牌面之间的转换
+#[test]
+fn test_physical_to_tile_conversion() {
+    let tiles = vec![
+        PhysicalTile::new(Tile::Suit(Suit::Bamboo, 9), 1).unwrap(),
+        PhysicalTile::new(Tile::new_red_five(Suit::Dot), 0).unwrap(),
+        PhysicalTile::new(Tile::Wind(Wind::North), 3).unwrap(),
+    ];
+    
+    assert_eq!(Tile::from(tiles[0]), Tile::Suit(Suit::Bamboo, 9));
+    assert_eq!(
+        physical_to_tiles(&tiles),
+        vec![Tile::Suit(Suit::Bamboo, 9), Tile::new_red_five(Suit::Dot), Tile::Wind(Wind::North)]
+    );
+    assert_eq!(physical_to_ids(&tiles), vec![105, 52, 123]);
+    assert_eq!(tiles[1].to_string(), "红5筒#52");
+}
@@ -0,0 +1,94 @@
+// tests/tile/test_red_five.rs
+//
+// 红宝牌(赤五)的测试用例
+// 验证红五作为独立的牌变体，在种类比较、显示、序列化和牌墙构建中的行为
+
+use majiang_core::tile::{
+    Tile, Suit, Wind, DisplayStyle, TileDisplay,
+    to_id, to_data, from_data,
+};
+use majiang_core::wall::{WallConfig, builder};
+
+/// 测试红五与普通五属于同一种类但不是同一张牌
+#[test]
+fn test_red_five_kind() {
+    let red = Tile::new_red_five(Suit::Dot);
+    let normal = Tile::Suit(Suit::Dot, 5);
+    
+    // 两者不相等，但种类相同
+    assert_ne!(red, normal);
+    assert!(red.is_same_kind(&normal));
+    assert_eq!(to_id(&red), to_id(&normal));
+    
+    // 不同花色的红五不是同一种类
+    assert!(!red.is_same_kind(&Tile::new_red_five(Suit::Bamboo)));
+    
+    // 红五是数牌，点数为5
+    assert!(red.is_suit());
+    assert_eq!(red.suit_and_number(), Some((Suit::Dot, 5)));
+    assert_eq!(red.to_normal(), normal);
+    
+    // 非红牌去红后不变
+    assert_eq!(normal.to_normal(), normal);
+    assert_eq!(Tile::Wind(Wind::East).to_normal(), Tile::Wind(Wind::East));
+    assert_eq!(Tile::Wind(Wind::East).suit_and_number(), None);
+}
+
+/// 测试红五的各种显示风格
+#[test]
+fn test_red_five_display() {
+    let red_man = Tile::new_red_five(Suit::Character);
+    
+    assert_eq!(red_man.to_string(), "红5万");
+    assert_eq!(red_man.display(DisplayStyle::Compact), "0m");
+    assert_eq!(Tile::new_red_five(Suit::Dot).display(DisplayStyle::Compact), "0p");
+    assert_eq!(Tile::new_red_five(Suit::Bamboo).display(DisplayStyle::Ascii), "|0s|");
+    
+    // Unicode没有红五专用符号，与普通五共用牌面
+    assert_eq!(
+        red_man.display(DisplayStyle::Unicode),
+        Tile::Suit(Suit::Character, 5).display(DisplayStyle::Unicode)
+    );
+}
+
+/// 测试红五的序列化数据可以无损还原
+#[test]
+fn test_red_five_data_round_trip() {
+    for suit in [Suit::Character, Suit::Dot, Suit::Bamboo] {
+        let red = Tile::new_red_five(suit);
+        let data = to_data(&red);
+        
+        assert!(data.is_red);
+        assert_eq!(data.value, "5");
+        assert_eq!(data.id, to_id(&Tile::Suit(suit, 5)));
+        assert_eq!(from_data(&data), Some(red));
+        
+        // 普通五的数据仍还原为普通五
+        let normal_data = to_data(&Tile::Suit(suit, 5));
+        assert!(!normal_data.is_red);
+        assert_eq!(from_data(&normal_data), Some(Tile::Suit(suit, 5)));
+    }
+    
+    // 非五的牌即使带有红标记也不会变成红五
+    let mut data = to_data(&Tile::Suit(Suit::Character, 3));
+    data.is_red = true;
+    assert_eq!(from_data(&data), Some(Tile::Suit(Suit::Character, 3)));
+}
+
+/// 测试日麻牌组中每种花色恰好有一张红五
+#[test]
+fn test_riichi_wall_has_three_red_fives() {
+    let tiles = builder::build_tiles(WallConfig::Riichi).unwrap();
+    
+    for suit in [Suit::Character, Suit::Dot, Suit::Bamboo] {
+        let red_count = tiles.iter().filter(|t| **t == Tile::new_red_five(suit)).count();
+        let kind_count = tiles.iter().filter(|t| t.is_same_kind(&Tile::Suit(suit, 5))).count();
+        
+        assert_eq!(red_count, 1);
+        assert_eq!(kind_count, 4);
+    }
+    
+    // 中式规则的牌组没有红五
+    let mcr_tiles = builder::build_tiles(WallConfig::MCR).unwrap();
+    assert!(!mcr_tiles.iter().any(|t| t.is_red()));
+}
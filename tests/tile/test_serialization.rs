@@ -44,7 +44,7 @@ fn test_tile_data_conversion() {
     assert_eq!(data.id, 4);
     assert_eq!(data.kind, "万子");
     assert_eq!(data.value, "5");
-    assert!(data.is_red); // 五万是红牌
+    assert!(!data.is_red); // 普通五万不是红牌
     
     let recovered = from_data(&data);
     assert!(recovered.is_some());
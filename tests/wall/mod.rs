@@ -6,4 +6,6 @@
 // 导入各个测试子模块
 mod test_basic;
 mod test_deadwall;
-mod test_config;
\ No newline at end of file
+mod test_config;
+mod test_physical;
+
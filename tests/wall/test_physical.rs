@@ -0,0 +1,97 @@
+// tests/wall/test_physical.rs
+//
+// 牌墙实体牌测试
+// 验证牌墙构建、摸牌、岭上牌区在实体牌层面保留了每张牌的唯一编号
+
+use std::collections::HashSet;
+use majiang_core::tile::{Tile, PhysicalTile};
+use majiang_core::wall::{Wall, WallConfig, DeadWallConfig, builder};
+
+/// 测试构建的实体牌组编号唯一且与牌面一致
+#[test]
+fn test_physical_tiles_unique_ids() {
+    for config in [WallConfig::Riichi, WallConfig::MCR, WallConfig::Shanghai { with_joker: true }] {
+        let physical = builder::build_physical_tiles(config).unwrap();
+        let tiles = builder::build_tiles(config).unwrap();
+        
+        // 实体牌与牌面一一对应
+        assert_eq!(physical.len(), tiles.len());
+        for (p, t) in physical.iter().zip(tiles.iter()) {
+            assert_eq!(p.tile(), *t);
+        }
+        
+        // 实体编号互不相同
+        let ids: HashSet<u8> = physical.iter().map(|p| p.id()).collect();
+        assert_eq!(ids.len(), physical.len());
+    }
+}
+
+/// 测试日麻实体牌组中的红五与天凤编码一致
+#[test]
+fn test_riichi_red_fives_follow_instance_convention() {
+    let physical = builder::build_physical_tiles(WallConfig::Riichi).unwrap();
+    
+    for p in &physical {
+        // 按编号还原得到同一张牌(含红五标记)
+        assert_eq!(PhysicalTile::from_instance_id(p.id(), true), Some(*p));
+    }
+    
+    let red_ids: Vec<u8> = physical.iter().filter(|p| p.is_red()).map(|p| p.id()).collect();
+    assert_eq!(red_ids.len(), 3);
+    assert!(red_ids.contains(&16) && red_ids.contains(&52) && red_ids.contains(&88));
+}
+
+/// 测试自定义牌组超过4副时仍可生成牌面，但无法编号为实体牌
+#[test]
+fn test_custom_sets_limited_to_four() {
+    let config = WallConfig::Custom { flowers: 0, with_joker: false, suit_sets: 5, honor_sets: 4 };
+    assert_eq!(builder::build_tiles(config).unwrap().len(), 5 * 27 + 4 * 7);
+    assert_eq!(builder::create_shuffled_tiles(config, Some(3)).unwrap().len(), 5 * 27 + 4 * 7);
+    assert!(builder::build_physical_tiles(config).is_err());
+    assert!(Wall::new(config, None, Some(3)).is_err());
+}
+
+/// 测试相同种子下实体牌洗牌与牌面洗牌顺序一致
+#[test]
+fn test_shuffled_physical_matches_tiles() {
+    let physical = builder::create_shuffled_physical_tiles(WallConfig::Riichi, Some(7)).unwrap();
+    let tiles = builder::create_shuffled_tiles(WallConfig::Riichi, Some(7)).unwrap();
+    
+    let faces: Vec<Tile> = physical.iter().map(|p| p.tile()).collect();
+    assert_eq!(faces, tiles);
+}
+
+/// 测试摸牌、发牌和岭上补牌都能取回实体编号
+#[test]
+fn test_wall_preserves_instances() {
+    let mut wall = Wall::new(
+        WallConfig::Riichi,
+        Some(DeadWallConfig::Riichi { dora_indicators: 5, uradora_indicators: 5 }),
+        Some(99)
+    ).unwrap();
+    
+    let hand = wall.deal_initial_physical_hand(13).unwrap();
+    wall.start_game();
+    let drawn = wall.draw_physical_tile().unwrap();
+    let replacement = wall.draw_physical_replacement_tile().unwrap();
+    
+    let mut seen: HashSet<u8> = hand.iter().map(|p| p.id()).collect();
+    assert!(seen.insert(drawn.id()));
+    assert!(seen.insert(replacement.id()));
+    
+    // 岭上牌区的宝牌指示牌也有实体编号，且与牌面一致
+    let dead_wall = wall.dead_wall().unwrap();
+    let indicators = dead_wall.get_dora_indicator_instances();
+    assert_eq!(indicators.len(), 1);
+    assert_eq!(indicators[0].tile(), *wall.get_dora_indicators().unwrap()[0]);
+    
+    // 岭上牌区的牌与已摸出的牌不重复
+    for p in dead_wall.get_all_tiles() {
+        assert!(!seen.contains(&p.id()));
+    }
+    
+    // 摸出的实体牌可以按编号还原，红五标记不丢失
+    for p in hand.iter().chain([drawn, replacement].iter()) {
+        assert_eq!(PhysicalTile::from_instance_id(p.id(), true), Some(*p));
+    }
+}